@@ -0,0 +1,16 @@
+-- migrate:up
+CREATE TABLE users (
+  id SERIAL PRIMARY KEY,
+  name VARCHAR(256) NOT NULL,
+  username VARCHAR(50) NOT NULL,
+  password VARCHAR(256) NOT NULL,
+  role VARCHAR(20) NOT NULL,
+  created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP,
+  updated_at TIMESTAMPTZ DEFAULT NULL,
+  deleted_at TIMESTAMPTZ DEFAULT NULL
+);
+
+CREATE UNIQUE INDEX users_username_unique ON users (username) WHERE deleted_at IS NULL;
+
+-- migrate:down
+DROP TABLE IF EXISTS users;
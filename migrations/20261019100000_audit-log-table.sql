@@ -0,0 +1,18 @@
+-- migrate:up
+CREATE TABLE audit_log (
+  id SERIAL PRIMARY KEY,
+  actor_id INTEGER DEFAULT NULL,
+  entity VARCHAR(50) NOT NULL,
+  entity_id INTEGER NOT NULL,
+  action VARCHAR(20) NOT NULL,
+  before JSONB DEFAULT NULL,
+  after JSONB DEFAULT NULL,
+  changes JSONB DEFAULT NULL,
+  created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX audit_log_entity_idx ON audit_log (entity, entity_id);
+CREATE INDEX audit_log_actor_idx ON audit_log (actor_id);
+
+-- migrate:down
+DROP TABLE IF EXISTS audit_log;
@@ -0,0 +1,22 @@
+-- migrate:up
+CREATE TABLE product_prices (
+  id SERIAL PRIMARY KEY,
+  product_id INTEGER NOT NULL REFERENCES products(id) ON DELETE CASCADE,
+  hpp_amount INTEGER DEFAULT NULL,
+  selling_amount INTEGER DEFAULT NULL,
+  effective_from TIMESTAMPTZ NOT NULL,
+  applied_at TIMESTAMPTZ DEFAULT NULL,
+  created_by INTEGER DEFAULT NULL,
+  created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX product_prices_product_idx ON product_prices (product_id, effective_from);
+CREATE INDEX product_prices_pending_idx ON product_prices (effective_from) WHERE applied_at IS NULL;
+
+-- Seed the history with current prices
+INSERT INTO product_prices (product_id, hpp_amount, selling_amount, effective_from, applied_at)
+SELECT id, hpp_amount, selling_amount, COALESCE(created_at, CURRENT_TIMESTAMP), COALESCE(created_at, CURRENT_TIMESTAMP)
+FROM products;
+
+-- migrate:down
+DROP TABLE IF EXISTS product_prices;
@@ -0,0 +1,19 @@
+-- migrate:up
+CREATE TABLE categories (
+  id SERIAL PRIMARY KEY,
+  name VARCHAR(256) NOT NULL,
+  parent_id INTEGER DEFAULT NULL REFERENCES categories(id),
+  created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP,
+  updated_at TIMESTAMPTZ DEFAULT NULL,
+  deleted_at TIMESTAMPTZ DEFAULT NULL
+);
+
+CREATE INDEX categories_parent_idx ON categories (parent_id);
+
+ALTER TABLE products ADD COLUMN category_id INTEGER DEFAULT NULL REFERENCES categories(id);
+
+CREATE INDEX products_category_idx ON products (category_id);
+
+-- migrate:down
+ALTER TABLE products DROP COLUMN IF EXISTS category_id;
+DROP TABLE IF EXISTS categories;
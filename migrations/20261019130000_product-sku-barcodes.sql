@@ -0,0 +1,19 @@
+-- migrate:up
+ALTER TABLE products ADD COLUMN sku VARCHAR(64) DEFAULT NULL;
+
+CREATE UNIQUE INDEX products_sku_unique ON products (sku) WHERE deleted_at IS NULL AND sku IS NOT NULL;
+
+CREATE TABLE product_barcodes (
+  id SERIAL PRIMARY KEY,
+  product_id INTEGER NOT NULL REFERENCES products(id) ON DELETE CASCADE,
+  code VARCHAR(14) NOT NULL,
+  created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP
+);
+
+-- Uniqueness among live products is checked by the API so deleted products keep their codes
+CREATE INDEX product_barcodes_code_idx ON product_barcodes (code);
+CREATE INDEX product_barcodes_product_idx ON product_barcodes (product_id);
+
+-- migrate:down
+DROP TABLE IF EXISTS product_barcodes;
+ALTER TABLE products DROP COLUMN IF EXISTS sku;
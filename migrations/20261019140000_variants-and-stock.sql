@@ -0,0 +1,43 @@
+-- migrate:up
+ALTER TABLE products ADD COLUMN stock INTEGER NOT NULL DEFAULT 0;
+
+CREATE TABLE product_variants (
+  id SERIAL PRIMARY KEY,
+  product_id INTEGER NOT NULL REFERENCES products(id) ON DELETE CASCADE,
+  name VARCHAR(100) NOT NULL,
+  sku VARCHAR(64) DEFAULT NULL,
+  hpp_amount INTEGER NOT NULL,
+  selling_amount INTEGER NOT NULL,
+  stock INTEGER NOT NULL DEFAULT 0,
+  created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP,
+  updated_at TIMESTAMPTZ DEFAULT NULL,
+  deleted_at TIMESTAMPTZ DEFAULT NULL
+);
+
+CREATE INDEX product_variants_product_idx ON product_variants (product_id);
+CREATE UNIQUE INDEX product_variants_sku_unique ON product_variants (sku) WHERE deleted_at IS NULL AND sku IS NOT NULL;
+
+ALTER TABLE transactions ADD COLUMN variant_id INTEGER DEFAULT NULL REFERENCES product_variants(id);
+
+-- Every stock change; products.stock / product_variants.stock is the running sum
+CREATE TABLE stock_movements (
+  id SERIAL PRIMARY KEY,
+  product_id INTEGER NOT NULL REFERENCES products(id) ON DELETE CASCADE,
+  variant_id INTEGER DEFAULT NULL REFERENCES product_variants(id) ON DELETE CASCADE,
+  qty INTEGER NOT NULL,
+  type VARCHAR(30) NOT NULL,
+  reference_type VARCHAR(30) DEFAULT NULL,
+  reference_id INTEGER DEFAULT NULL,
+  note VARCHAR(256) DEFAULT NULL,
+  created_by INTEGER DEFAULT NULL,
+  created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX stock_movements_product_idx ON stock_movements (product_id, created_at);
+CREATE INDEX stock_movements_reference_idx ON stock_movements (reference_type, reference_id);
+
+-- migrate:down
+DROP TABLE IF EXISTS stock_movements;
+ALTER TABLE transactions DROP COLUMN IF EXISTS variant_id;
+DROP TABLE IF EXISTS product_variants;
+ALTER TABLE products DROP COLUMN IF EXISTS stock;
@@ -0,0 +1,56 @@
+-- migrate:up
+CREATE TABLE modifier_groups (
+  id SERIAL PRIMARY KEY,
+  name VARCHAR(100) NOT NULL,
+  required BOOLEAN NOT NULL DEFAULT FALSE,
+  min_select INTEGER NOT NULL DEFAULT 0,
+  max_select INTEGER DEFAULT NULL,
+  created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP,
+  updated_at TIMESTAMPTZ DEFAULT NULL,
+  deleted_at TIMESTAMPTZ DEFAULT NULL
+);
+
+CREATE TABLE modifiers (
+  id SERIAL PRIMARY KEY,
+  group_id INTEGER NOT NULL REFERENCES modifier_groups(id) ON DELETE CASCADE,
+  name VARCHAR(100) NOT NULL,
+  hpp_amount INTEGER NOT NULL DEFAULT 0,
+  selling_amount INTEGER NOT NULL DEFAULT 0,
+  created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP,
+  updated_at TIMESTAMPTZ DEFAULT NULL,
+  deleted_at TIMESTAMPTZ DEFAULT NULL
+);
+
+CREATE INDEX modifiers_group_idx ON modifiers (group_id);
+
+CREATE TABLE product_modifier_groups (
+  product_id INTEGER NOT NULL REFERENCES products(id) ON DELETE CASCADE,
+  group_id INTEGER NOT NULL REFERENCES modifier_groups(id) ON DELETE CASCADE,
+  PRIMARY KEY (product_id, group_id)
+);
+
+-- Per unit sums of the chosen modifiers, kept next to the line prices for reporting
+ALTER TABLE transactions ADD COLUMN modifier_hpp_amount INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE transactions ADD COLUMN modifier_selling_amount INTEGER NOT NULL DEFAULT 0;
+
+-- Snapshot of each chosen modifier at the time of sale
+CREATE TABLE transaction_modifiers (
+  id SERIAL PRIMARY KEY,
+  transaction_id INTEGER NOT NULL REFERENCES transactions(id) ON DELETE CASCADE,
+  modifier_id INTEGER DEFAULT NULL REFERENCES modifiers(id) ON DELETE SET NULL,
+  group_id INTEGER DEFAULT NULL REFERENCES modifier_groups(id) ON DELETE SET NULL,
+  name VARCHAR(100) NOT NULL,
+  hpp_amount INTEGER NOT NULL,
+  selling_amount INTEGER NOT NULL,
+  created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX transaction_modifiers_transaction_idx ON transaction_modifiers (transaction_id);
+
+-- migrate:down
+DROP TABLE IF EXISTS transaction_modifiers;
+ALTER TABLE transactions DROP COLUMN IF EXISTS modifier_selling_amount;
+ALTER TABLE transactions DROP COLUMN IF EXISTS modifier_hpp_amount;
+DROP TABLE IF EXISTS product_modifier_groups;
+DROP TABLE IF EXISTS modifiers;
+DROP TABLE IF EXISTS modifier_groups;
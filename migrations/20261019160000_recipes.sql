@@ -0,0 +1,16 @@
+-- migrate:up
+-- Ingredients consumed by one unit of a composite product
+CREATE TABLE recipe_items (
+  id SERIAL PRIMARY KEY,
+  product_id INTEGER NOT NULL REFERENCES products(id) ON DELETE CASCADE,
+  ingredient_id INTEGER NOT NULL REFERENCES products(id) ON DELETE CASCADE,
+  qty INTEGER NOT NULL CHECK (qty > 0),
+  unit VARCHAR(20) DEFAULT NULL,
+  created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP,
+  UNIQUE (product_id, ingredient_id)
+);
+
+CREATE INDEX recipe_items_ingredient_idx ON recipe_items (ingredient_id);
+
+-- migrate:down
+DROP TABLE IF EXISTS recipe_items;
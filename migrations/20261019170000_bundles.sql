@@ -0,0 +1,31 @@
+-- migrate:up
+-- Components sold as part of one unit of a bundle product
+CREATE TABLE bundle_items (
+  id SERIAL PRIMARY KEY,
+  bundle_id INTEGER NOT NULL REFERENCES products(id) ON DELETE CASCADE,
+  component_id INTEGER NOT NULL REFERENCES products(id) ON DELETE CASCADE,
+  variant_id INTEGER DEFAULT NULL REFERENCES product_variants(id) ON DELETE CASCADE,
+  qty INTEGER NOT NULL CHECK (qty > 0),
+  created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE UNIQUE INDEX bundle_items_component_unique ON bundle_items (bundle_id, component_id, COALESCE(variant_id, 0));
+CREATE INDEX bundle_items_component_idx ON bundle_items (component_id);
+
+-- Revenue and HPP of a bundle sale line split across its components
+CREATE TABLE transaction_allocations (
+  id SERIAL PRIMARY KEY,
+  transaction_id INTEGER NOT NULL REFERENCES transactions(id) ON DELETE CASCADE,
+  product_id INTEGER NOT NULL REFERENCES products(id),
+  variant_id INTEGER DEFAULT NULL REFERENCES product_variants(id) ON DELETE SET NULL,
+  qty INTEGER NOT NULL,
+  revenue_amount BIGINT NOT NULL,
+  hpp_amount BIGINT NOT NULL,
+  created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX transaction_allocations_transaction_idx ON transaction_allocations (transaction_id);
+
+-- migrate:down
+DROP TABLE IF EXISTS transaction_allocations;
+DROP TABLE IF EXISTS bundle_items;
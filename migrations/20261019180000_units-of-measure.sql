@@ -0,0 +1,40 @@
+-- migrate:up
+-- Stock is counted in the product's base unit; other units convert into it
+ALTER TABLE products ADD COLUMN base_unit VARCHAR(20) NOT NULL DEFAULT 'pcs';
+
+CREATE TABLE product_units (
+  id SERIAL PRIMARY KEY,
+  product_id INTEGER NOT NULL REFERENCES products(id) ON DELETE CASCADE,
+  name VARCHAR(20) NOT NULL,
+  factor NUMERIC(14,4) NOT NULL CHECK (factor > 0),
+  selling_amount INTEGER DEFAULT NULL CHECK (selling_amount >= 0),
+  created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP,
+  updated_at TIMESTAMPTZ DEFAULT NULL
+);
+
+CREATE UNIQUE INDEX product_units_name_unique ON product_units (product_id, name);
+
+ALTER TABLE products ALTER COLUMN stock TYPE NUMERIC(14,3);
+ALTER TABLE product_variants ALTER COLUMN stock TYPE NUMERIC(14,3);
+ALTER TABLE stock_movements ALTER COLUMN qty TYPE NUMERIC(14,3);
+ALTER TABLE recipe_items ALTER COLUMN qty TYPE NUMERIC(14,3);
+ALTER TABLE recipe_items ADD COLUMN unit_factor NUMERIC(14,4) NOT NULL DEFAULT 1;
+ALTER TABLE transaction_allocations ALTER COLUMN qty TYPE NUMERIC(14,3);
+
+-- Sale lines keep the unit they were sold in; prices are per that unit
+ALTER TABLE transactions ALTER COLUMN qty TYPE NUMERIC(14,3);
+ALTER TABLE transactions ADD COLUMN unit VARCHAR(20) NOT NULL DEFAULT 'pcs';
+ALTER TABLE transactions ADD COLUMN unit_factor NUMERIC(14,4) NOT NULL DEFAULT 1;
+
+-- migrate:down
+ALTER TABLE transactions DROP COLUMN IF EXISTS unit_factor;
+ALTER TABLE transactions DROP COLUMN IF EXISTS unit;
+ALTER TABLE transactions ALTER COLUMN qty TYPE INTEGER USING ROUND(qty);
+ALTER TABLE transaction_allocations ALTER COLUMN qty TYPE INTEGER USING ROUND(qty);
+ALTER TABLE recipe_items DROP COLUMN IF EXISTS unit_factor;
+ALTER TABLE recipe_items ALTER COLUMN qty TYPE INTEGER USING CEIL(qty);
+ALTER TABLE stock_movements ALTER COLUMN qty TYPE INTEGER USING ROUND(qty);
+ALTER TABLE product_variants ALTER COLUMN stock TYPE INTEGER USING ROUND(stock);
+ALTER TABLE products ALTER COLUMN stock TYPE INTEGER USING ROUND(stock);
+DROP TABLE IF EXISTS product_units;
+ALTER TABLE products DROP COLUMN IF EXISTS base_unit;
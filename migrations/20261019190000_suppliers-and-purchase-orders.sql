@@ -0,0 +1,61 @@
+-- migrate:up
+CREATE TABLE suppliers (
+  id SERIAL PRIMARY KEY,
+  name VARCHAR(255) NOT NULL,
+  phone VARCHAR(30) DEFAULT NULL,
+  email VARCHAR(255) DEFAULT NULL,
+  address VARCHAR(500) DEFAULT NULL,
+  note VARCHAR(500) DEFAULT NULL,
+  created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP,
+  updated_at TIMESTAMPTZ DEFAULT NULL,
+  deleted_at TIMESTAMPTZ DEFAULT NULL
+);
+
+CREATE UNIQUE INDEX suppliers_name_unique ON suppliers (name) WHERE deleted_at IS NULL;
+
+-- draft -> ordered -> received, or cancelled before receipt
+CREATE TABLE purchase_orders (
+  id SERIAL PRIMARY KEY,
+  supplier_id INTEGER NOT NULL REFERENCES suppliers(id),
+  status VARCHAR(20) NOT NULL DEFAULT 'draft',
+  note VARCHAR(500) DEFAULT NULL,
+  total_amount BIGINT NOT NULL DEFAULT 0,
+  -- cash posts a cashflow entry on receipt, payable waits until paid
+  payment_method VARCHAR(10) DEFAULT NULL,
+  cashflow_id INTEGER DEFAULT NULL REFERENCES cashflow(id),
+  ordered_at TIMESTAMPTZ DEFAULT NULL,
+  received_at TIMESTAMPTZ DEFAULT NULL,
+  paid_at TIMESTAMPTZ DEFAULT NULL,
+  cancelled_at TIMESTAMPTZ DEFAULT NULL,
+  created_by INTEGER DEFAULT NULL,
+  created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP,
+  updated_at TIMESTAMPTZ DEFAULT NULL,
+  deleted_at TIMESTAMPTZ DEFAULT NULL
+);
+
+CREATE INDEX purchase_orders_supplier_idx ON purchase_orders (supplier_id);
+CREATE INDEX purchase_orders_status_idx ON purchase_orders (status);
+
+CREATE TABLE purchase_order_items (
+  id SERIAL PRIMARY KEY,
+  purchase_order_id INTEGER NOT NULL REFERENCES purchase_orders(id) ON DELETE CASCADE,
+  product_id INTEGER NOT NULL REFERENCES products(id),
+  variant_id INTEGER DEFAULT NULL REFERENCES product_variants(id),
+  qty NUMERIC(14,3) NOT NULL CHECK (qty > 0),
+  unit VARCHAR(20) NOT NULL,
+  unit_factor NUMERIC(14,4) NOT NULL DEFAULT 1,
+  -- Per `unit`
+  unit_cost INTEGER NOT NULL CHECK (unit_cost >= 0),
+  created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX purchase_order_items_order_idx ON purchase_order_items (purchase_order_id);
+
+-- Total cost of stock brought in, for movements that carry one
+ALTER TABLE stock_movements ADD COLUMN cost_amount BIGINT DEFAULT NULL;
+
+-- migrate:down
+ALTER TABLE stock_movements DROP COLUMN IF EXISTS cost_amount;
+DROP TABLE IF EXISTS purchase_order_items;
+DROP TABLE IF EXISTS purchase_orders;
+DROP TABLE IF EXISTS suppliers;
@@ -0,0 +1,9 @@
+-- migrate:up
+-- Quantity of a costed receipt not yet consumed by outgoing movements, oldest first
+ALTER TABLE stock_movements ADD COLUMN remaining_qty NUMERIC(14,3) DEFAULT NULL;
+
+CREATE INDEX stock_movements_open_layers_idx ON stock_movements (product_id, variant_id, id) WHERE remaining_qty > 0;
+
+-- migrate:down
+DROP INDEX IF EXISTS stock_movements_open_layers_idx;
+ALTER TABLE stock_movements DROP COLUMN IF EXISTS remaining_qty;
@@ -0,0 +1,35 @@
+-- migrate:up
+-- open -> approved, or cancelled; approval posts the variances as stock movements
+CREATE TABLE stock_counts (
+  id SERIAL PRIMARY KEY,
+  status VARCHAR(20) NOT NULL DEFAULT 'open',
+  note VARCHAR(500) DEFAULT NULL,
+  created_by INTEGER DEFAULT NULL,
+  approved_by INTEGER DEFAULT NULL,
+  approved_at TIMESTAMPTZ DEFAULT NULL,
+  cancelled_at TIMESTAMPTZ DEFAULT NULL,
+  created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP,
+  updated_at TIMESTAMPTZ DEFAULT NULL,
+  deleted_at TIMESTAMPTZ DEFAULT NULL
+);
+
+CREATE INDEX stock_counts_status_idx ON stock_counts (status);
+
+-- Expected stock and HPP are taken when the line is counted
+CREATE TABLE stock_count_items (
+  id SERIAL PRIMARY KEY,
+  stock_count_id INTEGER NOT NULL REFERENCES stock_counts(id) ON DELETE CASCADE,
+  product_id INTEGER NOT NULL REFERENCES products(id),
+  variant_id INTEGER DEFAULT NULL REFERENCES product_variants(id),
+  expected_qty NUMERIC(14,3) NOT NULL,
+  counted_qty NUMERIC(14,3) NOT NULL CHECK (counted_qty >= 0),
+  hpp_amount INTEGER NOT NULL,
+  counted_by INTEGER DEFAULT NULL,
+  counted_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE UNIQUE INDEX stock_count_items_item_unique ON stock_count_items (stock_count_id, product_id, COALESCE(variant_id, 0));
+
+-- migrate:down
+DROP TABLE IF EXISTS stock_count_items;
+DROP TABLE IF EXISTS stock_counts;
@@ -0,0 +1,15 @@
+-- migrate:up
+-- Stock at or below the reorder point is reported as low; NULL turns the alert off
+ALTER TABLE products ADD COLUMN reorder_point NUMERIC(14,3) DEFAULT NULL CHECK (reorder_point >= 0);
+ALTER TABLE product_variants ADD COLUMN reorder_point NUMERIC(14,3) DEFAULT NULL CHECK (reorder_point >= 0);
+
+-- Supplier the product is usually bought from
+ALTER TABLE products ADD COLUMN supplier_id INTEGER DEFAULT NULL REFERENCES suppliers(id) ON DELETE SET NULL;
+
+ALTER TABLE suppliers ADD COLUMN lead_time_days INTEGER NOT NULL DEFAULT 0 CHECK (lead_time_days >= 0);
+
+-- migrate:down
+ALTER TABLE suppliers DROP COLUMN IF EXISTS lead_time_days;
+ALTER TABLE products DROP COLUMN IF EXISTS supplier_id;
+ALTER TABLE product_variants DROP COLUMN IF EXISTS reorder_point;
+ALTER TABLE products DROP COLUMN IF EXISTS reorder_point;
@@ -0,0 +1,23 @@
+-- migrate:up
+-- Received quantity of a perishable product or variant sharing one expiry date; outgoing stock takes the earliest expiry first
+CREATE TABLE stock_lots (
+  id SERIAL PRIMARY KEY,
+  product_id INTEGER NOT NULL REFERENCES products(id),
+  variant_id INTEGER DEFAULT NULL REFERENCES product_variants(id),
+  lot_code VARCHAR(64) DEFAULT NULL,
+  expires_at DATE NOT NULL,
+  qty NUMERIC(14,3) NOT NULL CHECK (qty > 0),
+  remaining_qty NUMERIC(14,3) NOT NULL CHECK (remaining_qty >= 0),
+  unit_cost BIGINT DEFAULT NULL,
+  created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX stock_lots_open_idx ON stock_lots (product_id, variant_id, expires_at, id) WHERE remaining_qty > 0;
+CREATE INDEX stock_lots_expiry_idx ON stock_lots (expires_at) WHERE remaining_qty > 0;
+
+-- Lot a receipt created, or a write-off took from
+ALTER TABLE stock_movements ADD COLUMN lot_id INTEGER DEFAULT NULL REFERENCES stock_lots(id);
+
+-- migrate:down
+ALTER TABLE stock_movements DROP COLUMN IF EXISTS lot_id;
+DROP TABLE IF EXISTS stock_lots;
@@ -0,0 +1,23 @@
+-- migrate:up
+-- Quantity of the line already returned, in the transaction's unit
+ALTER TABLE transactions ADD COLUMN returned_qty NUMERIC(14,3) NOT NULL DEFAULT 0 CHECK (returned_qty >= 0 AND returned_qty <= qty);
+
+-- Partial or full return of a sale line; the refund leaves the till as a cashflow entry
+CREATE TABLE transaction_returns (
+  id SERIAL PRIMARY KEY,
+  transaction_id INTEGER NOT NULL REFERENCES transactions(id),
+  qty NUMERIC(14,3) NOT NULL CHECK (qty > 0),
+  reason VARCHAR(255) NOT NULL,
+  refund_amount BIGINT NOT NULL,
+  hpp_amount BIGINT NOT NULL,
+  cashflow_id INTEGER DEFAULT NULL REFERENCES cashflow(id),
+  created_by INTEGER DEFAULT NULL,
+  created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX transaction_returns_transaction_idx ON transaction_returns (transaction_id);
+CREATE INDEX transaction_returns_created_at_idx ON transaction_returns (created_at);
+
+-- migrate:down
+DROP TABLE IF EXISTS transaction_returns;
+ALTER TABLE transactions DROP COLUMN IF EXISTS returned_qty;
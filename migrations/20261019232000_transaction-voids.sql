@@ -0,0 +1,36 @@
+-- migrate:up
+-- bcrypt hash of the PIN supervisors and owners enter to approve a cashier's void
+ALTER TABLE users ADD COLUMN pin VARCHAR(255) DEFAULT NULL;
+
+-- completed -> voided; a voided sale keeps its row but puts its stock back and leaves the reports
+ALTER TABLE transactions ADD COLUMN status VARCHAR(20) NOT NULL DEFAULT 'completed';
+ALTER TABLE transactions ADD COLUMN void_reason VARCHAR(255) DEFAULT NULL;
+ALTER TABLE transactions ADD COLUMN voided_by INTEGER DEFAULT NULL;
+ALTER TABLE transactions ADD COLUMN void_approved_by INTEGER DEFAULT NULL;
+ALTER TABLE transactions ADD COLUMN voided_at TIMESTAMPTZ DEFAULT NULL;
+
+CREATE INDEX transactions_status_idx ON transactions (status);
+
+-- What outgoing stock took from each cost layer or lot, so a void can put it back where it came from
+CREATE TABLE stock_consumptions (
+  id SERIAL PRIMARY KEY,
+  movement_id INTEGER NOT NULL REFERENCES stock_movements(id) ON DELETE CASCADE,
+  layer_id INTEGER DEFAULT NULL REFERENCES stock_movements(id) ON DELETE CASCADE,
+  lot_id INTEGER DEFAULT NULL REFERENCES stock_lots(id) ON DELETE CASCADE,
+  qty NUMERIC(14,3) NOT NULL CHECK (qty > 0),
+  restored_qty NUMERIC(14,3) NOT NULL DEFAULT 0 CHECK (restored_qty >= 0 AND restored_qty <= qty),
+  created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP,
+  CHECK ((layer_id IS NULL) <> (lot_id IS NULL))
+);
+
+CREATE INDEX stock_consumptions_movement_idx ON stock_consumptions (movement_id);
+
+-- migrate:down
+DROP TABLE IF EXISTS stock_consumptions;
+DROP INDEX IF EXISTS transactions_status_idx;
+ALTER TABLE transactions DROP COLUMN IF EXISTS voided_at;
+ALTER TABLE transactions DROP COLUMN IF EXISTS void_approved_by;
+ALTER TABLE transactions DROP COLUMN IF EXISTS voided_by;
+ALTER TABLE transactions DROP COLUMN IF EXISTS void_reason;
+ALTER TABLE transactions DROP COLUMN IF EXISTS status;
+ALTER TABLE users DROP COLUMN IF EXISTS pin;
@@ -0,0 +1,17 @@
+-- migrate:up
+-- How a sale was paid; split tenders add up to the sale total, change only arises on cash
+CREATE TABLE transaction_payments (
+  id SERIAL PRIMARY KEY,
+  transaction_id INTEGER NOT NULL REFERENCES transactions(id) ON DELETE CASCADE,
+  method VARCHAR(20) NOT NULL,
+  amount BIGINT NOT NULL CHECK (amount > 0),
+  tendered_amount BIGINT DEFAULT NULL,
+  change_amount BIGINT NOT NULL DEFAULT 0 CHECK (change_amount >= 0),
+  reference VARCHAR(100) DEFAULT NULL,
+  created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX transaction_payments_transaction_idx ON transaction_payments (transaction_id);
+
+-- migrate:down
+DROP TABLE IF EXISTS transaction_payments;
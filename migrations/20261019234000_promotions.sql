@@ -0,0 +1,39 @@
+-- migrate:up
+-- Rules that take money off a sale line: percentage off, fixed off each unit,
+-- or buy X get Y free, limited to a product or category, a date range, a daily time window and a minimum purchase
+CREATE TABLE promotions (
+  id SERIAL PRIMARY KEY,
+  name VARCHAR(255) NOT NULL,
+  kind VARCHAR(20) NOT NULL,
+  value BIGINT NOT NULL DEFAULT 0 CHECK (value >= 0),
+  buy_qty INTEGER DEFAULT NULL CHECK (buy_qty > 0),
+  get_qty INTEGER DEFAULT NULL CHECK (get_qty > 0),
+  product_id INTEGER DEFAULT NULL REFERENCES products(id),
+  category_id INTEGER DEFAULT NULL REFERENCES categories(id),
+  min_purchase_amount BIGINT NOT NULL DEFAULT 0 CHECK (min_purchase_amount >= 0),
+  starts_on DATE DEFAULT NULL,
+  ends_on DATE DEFAULT NULL,
+  -- Happy hour, in the till's local time; a window past midnight wraps around
+  start_time TIME DEFAULT NULL,
+  end_time TIME DEFAULT NULL,
+  active BOOLEAN NOT NULL DEFAULT TRUE,
+  created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP,
+  updated_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP,
+  deleted_at TIMESTAMPTZ DEFAULT NULL
+);
+
+-- Money taken off the sale, with the promotion that did it; null for a manual discount
+ALTER TABLE transactions ADD COLUMN discount_amount BIGINT NOT NULL DEFAULT 0;
+ALTER TABLE transactions ADD COLUMN promotion_id INTEGER DEFAULT NULL REFERENCES promotions(id) ON DELETE SET NULL;
+ALTER TABLE transaction_allocations ADD COLUMN discount_amount BIGINT NOT NULL DEFAULT 0;
+ALTER TABLE transaction_returns ADD COLUMN discount_amount BIGINT NOT NULL DEFAULT 0;
+
+CREATE INDEX transactions_promotion_idx ON transactions (promotion_id);
+
+-- migrate:down
+DROP INDEX IF EXISTS transactions_promotion_idx;
+ALTER TABLE transaction_returns DROP COLUMN IF EXISTS discount_amount;
+ALTER TABLE transaction_allocations DROP COLUMN IF EXISTS discount_amount;
+ALTER TABLE transactions DROP COLUMN IF EXISTS promotion_id;
+ALTER TABLE transactions DROP COLUMN IF EXISTS discount_amount;
+DROP TABLE IF EXISTS promotions;
@@ -1,11 +1,47 @@
 use sea_orm::DatabaseConnection;
 
 use crate::module::repository::product_repository::{ProductRepositoryImpl};
+use crate::module::repository::product_price_repository::{ProductPriceRepositoryImpl};
+use crate::module::repository::category_repository::{CategoryRepositoryImpl};
+use crate::module::repository::product_barcode_repository::{ProductBarcodeRepositoryImpl};
+use crate::module::repository::product_variant_repository::{ProductVariantRepositoryImpl};
+use crate::module::repository::stock_repository::{StockRepositoryImpl};
+use crate::module::repository::stock_lot_repository::{StockLotRepositoryImpl};
+use crate::module::repository::transaction_return_repository::{TransactionReturnRepositoryImpl};
+use crate::module::category::v1::category_usecase::{CategoryUsecaseImpl, CategoryUsecase};
 use crate::module::product::v1::product_usecase::{ProductUsecaseImpl, ProductUsecase};
 use crate::module::repository::cashflow_repository::{CashflowRepositoryImpl};
 use crate::module::cashflow::v1::cashflow_usecase::{CashflowUsecaseImpl, CashflowUsecase};
 use crate::module::repository::transaction_repository::{TransactionRepositoryImpl};
 use crate::module::transaction::v1::transaction_usecase::{TransactionUsecaseImpl, TransactionUsecase};
+use crate::module::repository::user_repository::{UserRepositoryImpl};
+use crate::module::repository::audit_log_repository::{AuditLogRepositoryImpl};
+use crate::module::audit::v1::audit_usecase::{AuditUsecaseImpl, AuditUsecase};
+use crate::module::user::v1::user_usecase::{UserUsecaseImpl, UserUsecase};
+use crate::module::retention::v1::retention_usecase::{RetentionUsecaseImpl, RetentionUsecase};
+use crate::module::repository::report_repository::{ReportRepositoryImpl};
+use crate::module::report::v1::report_usecase::{ReportUsecaseImpl, ReportUsecase};
+use crate::module::inventory::v1::inventory_usecase::{InventoryUsecaseImpl, InventoryUsecase};
+use crate::module::repository::modifier_group_repository::{ModifierGroupRepositoryImpl};
+use crate::module::repository::modifier_repository::{ModifierRepositoryImpl};
+use crate::module::repository::transaction_modifier_repository::{TransactionModifierRepositoryImpl};
+use crate::module::repository::transaction_payment_repository::{TransactionPaymentRepositoryImpl};
+use crate::module::repository::recipe_item_repository::{RecipeItemRepositoryImpl};
+use crate::module::repository::bundle_item_repository::{BundleItemRepositoryImpl};
+use crate::module::repository::transaction_allocation_repository::{TransactionAllocationRepositoryImpl};
+use crate::module::repository::product_unit_repository::{ProductUnitRepositoryImpl};
+use crate::module::modifier::v1::modifier_usecase::{ModifierUsecaseImpl, ModifierUsecase};
+use crate::module::repository::supplier_repository::{SupplierRepositoryImpl};
+use crate::module::repository::purchase_order_repository::{PurchaseOrderRepositoryImpl};
+use crate::module::repository::purchase_order_item_repository::{PurchaseOrderItemRepositoryImpl};
+use crate::module::supplier::v1::supplier_usecase::{SupplierUsecaseImpl, SupplierUsecase};
+use crate::module::purchase::v1::purchase_usecase::{PurchaseUsecaseImpl, PurchaseUsecase};
+use crate::module::repository::stock_count_repository::{StockCountRepositoryImpl};
+use crate::module::repository::stock_count_item_repository::{StockCountItemRepositoryImpl};
+use crate::module::stock_count::v1::stock_count_usecase::{StockCountUsecaseImpl, StockCountUsecase};
+use crate::module::repository::promotion_repository::{PromotionRepositoryImpl};
+use crate::module::promotion::v1::promotion_usecase::{PromotionUsecaseImpl, PromotionUsecase};
+use crate::pkg::config::Config;
 
 use std::sync::Arc;
 
@@ -14,23 +50,169 @@ pub struct AppModule {
 	pub product_usecase: Arc<dyn ProductUsecase>,
 	pub cashflow_usecase: Arc<dyn CashflowUsecase>,
 	pub transaction_usecase: Arc<dyn TransactionUsecase>,
+	pub user_usecase: Arc<dyn UserUsecase>,
+	pub retention_usecase: Arc<dyn RetentionUsecase>,
+	pub audit_usecase: Arc<dyn AuditUsecase>,
+	pub category_usecase: Arc<dyn CategoryUsecase>,
+	pub report_usecase: Arc<dyn ReportUsecase>,
+	pub inventory_usecase: Arc<dyn InventoryUsecase>,
+	pub modifier_usecase: Arc<dyn ModifierUsecase>,
+	pub supplier_usecase: Arc<dyn SupplierUsecase>,
+	pub purchase_usecase: Arc<dyn PurchaseUsecase>,
+	pub stock_count_usecase: Arc<dyn StockCountUsecase>,
+	pub promotion_usecase: Arc<dyn PromotionUsecase>,
 	pub db: Arc<DatabaseConnection>
 }
 
 // Dependency injection
-pub fn initialize_di(db: Arc<DatabaseConnection>) -> AppModule {
+pub fn initialize_di(db: Arc<DatabaseConnection>, config: Arc<Config>) -> AppModule {
 	let product_repository = Arc::new(ProductRepositoryImpl::new(db.clone()));
+	let product_price_repository = Arc::new(ProductPriceRepositoryImpl::new(db.clone()));
+	let category_repository = Arc::new(CategoryRepositoryImpl::new(db.clone()));
+	let product_barcode_repository = Arc::new(ProductBarcodeRepositoryImpl::new(db.clone()));
+	let product_variant_repository = Arc::new(ProductVariantRepositoryImpl::new(db.clone()));
+	let stock_repository = Arc::new(StockRepositoryImpl::new(db.clone()));
+	let stock_lot_repository = Arc::new(StockLotRepositoryImpl::new(db.clone()));
+	let transaction_return_repository = Arc::new(TransactionReturnRepositoryImpl::new(db.clone()));
+	let modifier_group_repository = Arc::new(ModifierGroupRepositoryImpl::new(db.clone()));
+	let modifier_repository = Arc::new(ModifierRepositoryImpl::new(db.clone()));
+	let transaction_modifier_repository = Arc::new(TransactionModifierRepositoryImpl::new(db.clone()));
+	let transaction_payment_repository = Arc::new(TransactionPaymentRepositoryImpl::new(db.clone()));
+	let promotion_repository = Arc::new(PromotionRepositoryImpl::new(db.clone()));
+	let recipe_item_repository = Arc::new(RecipeItemRepositoryImpl::new(db.clone()));
+	let bundle_item_repository = Arc::new(BundleItemRepositoryImpl::new(db.clone()));
+	let transaction_allocation_repository = Arc::new(TransactionAllocationRepositoryImpl::new());
+	let product_unit_repository = Arc::new(ProductUnitRepositoryImpl::new(db.clone()));
 	let cashflow_repository = Arc::new(CashflowRepositoryImpl::new(db.clone()));
 	let transaction_repository = Arc::new(TransactionRepositoryImpl::new(db.clone()));
+	let user_repository = Arc::new(UserRepositoryImpl::new(db.clone()));
+	let audit_log_repository = Arc::new(AuditLogRepositoryImpl::new(db.clone()));
+	let report_repository = Arc::new(ReportRepositoryImpl::new(db.clone()));
+	let supplier_repository = Arc::new(SupplierRepositoryImpl::new(db.clone()));
+	let purchase_order_repository = Arc::new(PurchaseOrderRepositoryImpl::new(db.clone()));
+	let purchase_order_item_repository = Arc::new(PurchaseOrderItemRepositoryImpl::new(db.clone()));
+	let stock_count_repository = Arc::new(StockCountRepositoryImpl::new(db.clone()));
+	let stock_count_item_repository = Arc::new(StockCountItemRepositoryImpl::new(db.clone()));
 
-	let product_usecase = Arc::new(ProductUsecaseImpl::new(product_repository.clone()));
-	let cashflow_usecase = Arc::new(CashflowUsecaseImpl::new(cashflow_repository.clone()));
-	let transaction_usecase = Arc::new(TransactionUsecaseImpl::new(transaction_repository.clone(), product_repository.clone()));
+	let product_usecase = Arc::new(ProductUsecaseImpl::new(
+		product_repository.clone(),
+		product_price_repository.clone(),
+		category_repository.clone(),
+		product_barcode_repository.clone(),
+		product_variant_repository.clone(),
+		recipe_item_repository.clone(),
+		bundle_item_repository.clone(),
+		product_unit_repository.clone(),
+		stock_repository.clone(),
+		supplier_repository.clone(),
+		audit_log_repository.clone(),
+		config.clone(),
+	));
+	let cashflow_usecase = Arc::new(CashflowUsecaseImpl::new(cashflow_repository.clone(), transaction_return_repository.clone(), audit_log_repository.clone()));
+	let transaction_usecase = Arc::new(TransactionUsecaseImpl::new(
+		transaction_repository.clone(),
+		transaction_return_repository.clone(),
+		product_repository.clone(),
+		product_variant_repository.clone(),
+		stock_repository.clone(),
+		modifier_group_repository.clone(),
+		modifier_repository.clone(),
+		transaction_modifier_repository.clone(),
+		transaction_payment_repository.clone(),
+		promotion_repository.clone(),
+		category_repository.clone(),
+		recipe_item_repository.clone(),
+		bundle_item_repository.clone(),
+		transaction_allocation_repository.clone(),
+		product_unit_repository.clone(),
+		cashflow_repository.clone(),
+		user_repository.clone(),
+		product_usecase.clone(),
+		audit_log_repository.clone(),
+		config.clone(),
+	));
+	let user_usecase = Arc::new(UserUsecaseImpl::new(user_repository.clone(), config.clone()));
+	let retention_usecase = Arc::new(RetentionUsecaseImpl::new(
+		product_repository.clone(),
+		cashflow_repository.clone(),
+		transaction_repository.clone(),
+		audit_log_repository.clone(),
+		config.clone(),
+	));
+	let audit_usecase = Arc::new(AuditUsecaseImpl::new(audit_log_repository.clone()));
+	let category_usecase = Arc::new(CategoryUsecaseImpl::new(
+		category_repository.clone(),
+		product_repository.clone(),
+		audit_log_repository.clone(),
+	));
+	let report_usecase = Arc::new(ReportUsecaseImpl::new(report_repository.clone(), category_repository.clone()));
+	let inventory_usecase = Arc::new(InventoryUsecaseImpl::new(
+		product_repository.clone(),
+		product_variant_repository.clone(),
+		stock_repository.clone(),
+		stock_lot_repository.clone(),
+		supplier_repository.clone(),
+		product_usecase.clone(),
+		config.clone(),
+	));
+	let modifier_usecase = Arc::new(ModifierUsecaseImpl::new(
+		modifier_group_repository.clone(),
+		modifier_repository.clone(),
+		product_repository.clone(),
+		audit_log_repository.clone(),
+	));
+	let supplier_usecase = Arc::new(SupplierUsecaseImpl::new(
+		supplier_repository.clone(),
+		purchase_order_repository.clone(),
+		audit_log_repository.clone(),
+	));
+	let purchase_usecase = Arc::new(PurchaseUsecaseImpl::new(
+		purchase_order_repository.clone(),
+		purchase_order_item_repository.clone(),
+		supplier_repository.clone(),
+		product_repository.clone(),
+		product_variant_repository.clone(),
+		product_unit_repository.clone(),
+		recipe_item_repository.clone(),
+		bundle_item_repository.clone(),
+		stock_repository.clone(),
+		cashflow_repository.clone(),
+		product_usecase.clone(),
+		audit_log_repository.clone(),
+	));
+	let stock_count_usecase = Arc::new(StockCountUsecaseImpl::new(
+		stock_count_repository.clone(),
+		stock_count_item_repository.clone(),
+		product_repository.clone(),
+		product_variant_repository.clone(),
+		recipe_item_repository.clone(),
+		bundle_item_repository.clone(),
+		stock_repository.clone(),
+		product_usecase.clone(),
+		audit_log_repository.clone(),
+	));
+	let promotion_usecase = Arc::new(PromotionUsecaseImpl::new(
+		promotion_repository.clone(),
+		product_repository.clone(),
+		category_repository.clone(),
+		audit_log_repository.clone(),
+	));
 
 	AppModule {
         product_usecase,
         cashflow_usecase,
         transaction_usecase,
+        user_usecase,
+        retention_usecase,
+        audit_usecase,
+        category_usecase,
+        report_usecase,
+        inventory_usecase,
+        modifier_usecase,
+        supplier_usecase,
+        purchase_usecase,
+        stock_count_usecase,
+        promotion_usecase,
 		db,
 	}
 }
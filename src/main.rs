@@ -24,7 +24,18 @@ async fn main() {
         .await;
 
     // Initialize dependency injection (services, repositories, etc.)
-    let di_module = Arc::new(di::initialize_di(db.db.clone()));
+    let di_module = Arc::new(di::initialize_di(db.db.clone(), config.clone()));
+
+    // Seed the owner account when OWNER_USERNAME / OWNER_PASSWORD are set
+    if let Err(err) = di_module.user_usecase.ensure_owner().await {
+        tracing::error!("Failed to create owner account: {:?}", err);
+    }
+
+    // Purge soft-deleted rows past RETENTION_DAYS in the background
+    module::retention::retention_job::spawn(di_module.retention_usecase.clone(), config.clone());
+
+    // Apply scheduled product price changes when they come due
+    module::product::price_job::spawn(di_module.product_usecase.clone());
 
     // Configure Axum application with routing and dependencies
     let mut app = module::app_module::configure(config.clone(), di_module.clone()).await;
@@ -18,6 +18,17 @@ use crate::pkg::health;
 use super::product::product_module;
 use super::cashflow::cashflow_module;
 use super::transaction::transaction_module;
+use super::user::user_module;
+use super::retention::retention_module;
+use super::audit::audit_module;
+use super::category::category_module;
+use super::report::report_module;
+use super::inventory::inventory_module;
+use super::modifier::modifier_module;
+use super::supplier::supplier_module;
+use super::purchase::purchase_module;
+use super::stock_count::stock_count_module;
+use super::promotion::promotion_module;
 
 pub async fn configure(config: Arc<Config>, di_module: Arc<AppModule>) -> Router {
     // Setup CORS layer
@@ -32,6 +43,17 @@ pub async fn configure(config: Arc<Config>, di_module: Arc<AppModule>) -> Router
         .merge(product_module::configure())
         .merge(cashflow_module::configure())
         .merge(transaction_module::configure())
+        .merge(user_module::configure())
+        .merge(retention_module::configure())
+        .merge(audit_module::configure())
+        .merge(category_module::configure())
+        .merge(report_module::configure())
+        .merge(inventory_module::configure())
+        .merge(modifier_module::configure())
+        .merge(supplier_module::configure())
+        .merge(purchase_module::configure())
+        .merge(stock_count_module::configure())
+        .merge(promotion_module::configure())
         .layer(TraceLayer::new_for_http())
         .layer(cors)
         .layer(CookieManagerLayer::new())
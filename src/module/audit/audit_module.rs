@@ -0,0 +1,7 @@
+use axum::Router;
+
+use crate::module::audit::v1::audit_handler;
+
+pub fn configure() -> Router {
+	Router::new().nest("/api/v1/audit", audit_handler::routes())
+}
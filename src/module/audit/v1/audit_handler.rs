@@ -0,0 +1,39 @@
+use axum::{
+	extract::{Extension, OriginalUri, Query}, 
+    http::StatusCode, 
+    response::IntoResponse, 
+    routing::get, 
+    Json, 
+    Router
+};
+use serde_json::json;
+use std::sync::Arc;
+
+use crate::module::audit::v1::entity::request::list_audit_log_request::GetListAuditLogQuery;
+use crate::pkg::auth::{AuthUser, ROLE_OWNER, ROLE_SUPERVISOR};
+use crate::pkg::custom_error::AppError;
+use crate::pkg::response::ApiResponse;
+use crate::di::AppModule;
+
+
+pub fn routes() -> Router {
+	Router::new()
+		.route("/", get(list_audit_logs))
+}
+
+async fn list_audit_logs(
+    Extension(app_module): Extension<Arc<AppModule>>,
+    OriginalUri(uri): OriginalUri,
+    auth_user: AuthUser,
+    Query(query): Query<GetListAuditLogQuery>,
+) -> Result<impl IntoResponse, AppError> {
+    auth_user.require_role(&[ROLE_OWNER, ROLE_SUPERVISOR])?;
+
+    let audit_logs = app_module.audit_usecase.list_audit_logs(query).await?;
+
+    Ok((
+        StatusCode::OK,
+        audit_logs.headers(&uri),
+        Json(ApiResponse::success("Audit logs retrieved successfully", Some(json!(audit_logs.data)), Some(json!(audit_logs.meta)))),
+    ))
+}
@@ -0,0 +1,32 @@
+use async_trait::async_trait;
+use crate::module::audit::v1::entity::{
+    request::list_audit_log_request::GetListAuditLogQuery,
+    response::audit_log_response::AuditLogResponse,
+};
+use crate::module::repository::audit_log_repository::AuditLogRepository;
+use crate::pkg::custom_error::AppError;
+use crate::pkg::pagination::Paginated;
+use std::sync::Arc;
+
+#[async_trait]
+pub trait AuditUsecase: Send + Sync {
+    async fn list_audit_logs(&self, query: GetListAuditLogQuery) -> Result<Paginated<AuditLogResponse>, AppError>;
+}
+
+pub struct AuditUsecaseImpl {
+    audit_log_repository: Arc<dyn AuditLogRepository>,
+}
+
+impl AuditUsecaseImpl {
+    pub fn new(audit_log_repository: Arc<dyn AuditLogRepository>) -> Self {
+        Self { audit_log_repository }
+    }
+}
+
+#[async_trait]
+impl AuditUsecase for AuditUsecaseImpl {
+    async fn list_audit_logs(&self, query: GetListAuditLogQuery) -> Result<Paginated<AuditLogResponse>, AppError> {
+        let paginated_result = self.audit_log_repository.find_with_pagination(query).await?;
+        Ok(Paginated::from(paginated_result))
+    }
+}
@@ -0,0 +1,29 @@
+use sea_orm::entity::prelude::*;
+use serde::{Serialize, Deserialize};
+use chrono::{Utc, DateTime};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "audit_log")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = true)]
+    pub id: i32,
+    pub actor_id: Option<i32>,
+    pub entity: String,
+    pub entity_id: i32,
+    pub action: String,
+    pub before: Option<Json>,
+    pub after: Option<Json>,
+    pub changes: Option<Json>,
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter)]
+pub enum Relation {}
+
+impl RelationTrait for Relation {
+    fn def(&self) -> RelationDef {
+        unimplemented!()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
\ No newline at end of file
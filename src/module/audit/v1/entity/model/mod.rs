@@ -0,0 +1 @@
+pub mod audit_log;
\ No newline at end of file
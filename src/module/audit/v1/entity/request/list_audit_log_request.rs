@@ -0,0 +1,34 @@
+use validator::{Validate};
+use serde::{Serialize, Deserialize};
+
+#[derive(Debug, Serialize, Deserialize, Validate)]
+pub struct GetListAuditLogQuery {
+    pub limit: Option<String>,
+
+    pub page: Option<String>,
+
+    // Newest first unless "asc"
+    pub direction: Option<String>,
+
+    // "false" skips the COUNT query
+    pub include_total: Option<String>,
+
+    // Comma separated entities: product, cashflow, transaction
+    pub entity: Option<String>,
+
+    pub entity_id: Option<String>,
+
+    // Comma separated user ids
+    pub actor_id: Option<String>,
+
+    // Comma separated actions: create, update, delete, restore, purge
+    pub action: Option<String>,
+
+    // YYYY-MM-DD (interpreted in `timezone`) or RFC 3339
+    pub created_from: Option<String>,
+
+    pub created_to: Option<String>,
+
+    // UTC offset such as +07:00, defaults to UTC
+    pub timezone: Option<String>,
+}
@@ -0,0 +1,32 @@
+use serde::{Serialize, Deserialize};
+use serde_json::Value;
+use chrono::{Utc, DateTime};
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AuditLogResponse {
+    pub id: i32,
+    pub actor_id: Option<i32>,
+    pub entity: String,
+    pub entity_id: i32,
+    pub action: String,
+    pub before: Option<Value>,
+    pub after: Option<Value>,
+    pub changes: Option<Value>,
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+impl From<crate::module::audit::v1::entity::model::audit_log::Model> for AuditLogResponse {
+    fn from(model: crate::module::audit::v1::entity::model::audit_log::Model) -> Self {
+        Self {
+            id: model.id,
+            actor_id: model.actor_id,
+            entity: model.entity,
+            entity_id: model.entity_id,
+            action: model.action,
+            before: model.before,
+            after: model.after,
+            changes: model.changes,
+            created_at: model.created_at,
+        }
+    }
+}
@@ -0,0 +1,3 @@
+pub mod entity;
+pub mod audit_usecase;
+pub mod audit_handler;
\ No newline at end of file
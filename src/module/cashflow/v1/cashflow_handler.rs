@@ -1,5 +1,5 @@
 use axum::{
-	extract::{Extension, Path, Query}, 
+	extract::{Extension, OriginalUri, Path, Query}, 
     http::StatusCode, 
     response::IntoResponse, 
     routing::{get, post, put, delete}, 
@@ -18,6 +18,7 @@ use crate::module::cashflow::v1::entity::{
         list_cashflow_request::GetListCashflowQuery,
     },
 };
+use crate::pkg::auth::{AuthUser, ROLE_OWNER};
 use crate::pkg::custom_error::AppError;
 use crate::pkg::response::ApiResponse;
 use crate::di::AppModule;
@@ -30,17 +31,21 @@ pub fn routes() -> Router {
 		.route("/:id", get(get_cashflow))
 		.route("/:id", put(update_cashflow))
 		.route("/:id", delete(delete_cashflow))
+		.route("/trash", get(list_deleted_cashflows))
+		.route("/trash/:id", delete(purge_cashflow))
+		.route("/trash/:id/restore", post(restore_cashflow))
 }
 
 async fn create_cashflow(
     Extension(app_module): Extension<Arc<AppModule>>,
+    auth_user: Option<AuthUser>,
     Json(payload): Json<CreateCashflowRequest>,
 ) -> Result<impl IntoResponse, AppError> {
     payload.validate().map_err(|e| {
-        AppError::BadRequest(e.to_string().replace("\n", "\n"))
+        AppError::BadRequest(e.to_string())
     })?;
 
-    let cashflow = app_module.cashflow_usecase.create_cashflow(payload).await?;
+    let cashflow = app_module.cashflow_usecase.create_cashflow(payload, auth_user.map(|user| user.id)).await?;
 
     Ok((
         StatusCode::CREATED,
@@ -54,7 +59,7 @@ async fn get_cashflow(
 ) -> Result<impl IntoResponse, AppError> {
     let request = GetCashflowRequest { id };
     request.validate().map_err(|e| {
-        AppError::BadRequest(e.to_string().replace("\n", "\n"))
+        AppError::BadRequest(e.to_string())
     })?;
 
     let cashflow = app_module.cashflow_usecase.get_cashflow(request).await?;
@@ -67,26 +72,29 @@ async fn get_cashflow(
 
 async fn list_cashflows(
     Extension(app_module): Extension<Arc<AppModule>>,
+    OriginalUri(uri): OriginalUri,
     Query(query): Query<GetListCashflowQuery>,
 ) -> Result<impl IntoResponse, AppError> {
     let cashflows = app_module.cashflow_usecase.list_cashflows(query).await?;
 
     Ok((
         StatusCode::OK,
-        Json(ApiResponse::success("Cashflows retrieved successfully", Some(json!(cashflows)), None)),
+        cashflows.headers(&uri),
+        Json(ApiResponse::success("Cashflows retrieved successfully", Some(json!(cashflows.data)), Some(json!(cashflows.meta)))),
     ))
 }
 
 async fn update_cashflow(
     Extension(app_module): Extension<Arc<AppModule>>,
+    auth_user: Option<AuthUser>,
     Path(id): Path<i32>,
     Json(payload): Json<UpdateCashflowRequest>,
 ) -> Result<impl IntoResponse, AppError> {
     payload.validate().map_err(|e| {
-        AppError::BadRequest(e.to_string().replace("\n", "\n"))
+        AppError::BadRequest(e.to_string())
     })?;
 
-    let cashflow = app_module.cashflow_usecase.update_cashflow(id, payload).await?;
+    let cashflow = app_module.cashflow_usecase.update_cashflow(id, payload, auth_user.map(|user| user.id)).await?;
 
     Ok((
         StatusCode::OK,
@@ -96,12 +104,55 @@ async fn update_cashflow(
 
 async fn delete_cashflow(
     Extension(app_module): Extension<Arc<AppModule>>,
+    auth_user: Option<AuthUser>,
     Path(id): Path<i32>,
 ) -> Result<impl IntoResponse, AppError> {
-    app_module.cashflow_usecase.delete_cashflow(id).await?;
+    app_module.cashflow_usecase.delete_cashflow(id, auth_user.map(|user| user.id)).await?;
 
     Ok((
         StatusCode::OK,
         Json(ApiResponse::success("Cashflow deleted successfully", Some(json!({})), None)),
     ))
 }
+
+async fn list_deleted_cashflows(
+    Extension(app_module): Extension<Arc<AppModule>>,
+    OriginalUri(uri): OriginalUri,
+    Query(query): Query<GetListCashflowQuery>,
+) -> Result<impl IntoResponse, AppError> {
+    let cashflows = app_module.cashflow_usecase.list_deleted_cashflows(query).await?;
+
+    Ok((
+        StatusCode::OK,
+        cashflows.headers(&uri),
+        Json(ApiResponse::success("Deleted cashflows retrieved successfully", Some(json!(cashflows.data)), Some(json!(cashflows.meta)))),
+    ))
+}
+
+async fn restore_cashflow(
+    Extension(app_module): Extension<Arc<AppModule>>,
+    auth_user: Option<AuthUser>,
+    Path(id): Path<i32>,
+) -> Result<impl IntoResponse, AppError> {
+    let cashflow = app_module.cashflow_usecase.restore_cashflow(id, auth_user.map(|user| user.id)).await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse::success("Cashflow restored successfully", Some(json!(cashflow)), None)),
+    ))
+}
+
+async fn purge_cashflow(
+    Extension(app_module): Extension<Arc<AppModule>>,
+    auth_user: AuthUser,
+    Path(id): Path<i32>,
+) -> Result<impl IntoResponse, AppError> {
+    auth_user.require_role(&[ROLE_OWNER])?;
+
+    app_module.cashflow_usecase.purge_cashflow(id, Some(auth_user.id)).await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse::success("Cashflow permanently deleted", Some(json!({})), None)),
+    ))
+}
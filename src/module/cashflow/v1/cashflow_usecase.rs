@@ -8,38 +8,51 @@ use crate::module::cashflow::v1::entity::{
     },
     response::{
         cashflow_response::CashflowResponse,
-        list_cashflow_response::ListCashflowResponse,
     },
     model::cashflow::ActiveModel,
 };
 use crate::module::repository::cashflow_repository::CashflowRepository;
+use crate::module::repository::transaction_return_repository::TransactionReturnRepository;
+use crate::module::repository::audit_log_repository::{AuditLogRepository, NewAuditLog};
+use crate::pkg::audit::{ENTITY_CASHFLOW, ACTION_CREATE, ACTION_UPDATE, ACTION_DELETE, ACTION_RESTORE, ACTION_PURGE};
 use crate::pkg::custom_error::AppError;
+use crate::pkg::pagination::Paginated;
 use sea_orm::{prelude::*, Set};
+use serde_json::json;
 use std::sync::Arc;
 use chrono::Utc;
 
 #[async_trait]
 pub trait CashflowUsecase: Send + Sync {
-    async fn create_cashflow(&self, request: CreateCashflowRequest) -> Result<CashflowResponse, AppError>;
+    async fn create_cashflow(&self, request: CreateCashflowRequest, actor_id: Option<i32>) -> Result<CashflowResponse, AppError>;
     async fn get_cashflow(&self, request: GetCashflowRequest) -> Result<CashflowResponse, AppError>;
-    async fn list_cashflows(&self, query: GetListCashflowQuery) -> Result<ListCashflowResponse, AppError>;
-    async fn update_cashflow(&self, id: i32, request: UpdateCashflowRequest) -> Result<CashflowResponse, AppError>;
-    async fn delete_cashflow(&self, id: i32) -> Result<(), AppError>;
+    async fn list_cashflows(&self, query: GetListCashflowQuery) -> Result<Paginated<CashflowResponse>, AppError>;
+    async fn update_cashflow(&self, id: i32, request: UpdateCashflowRequest, actor_id: Option<i32>) -> Result<CashflowResponse, AppError>;
+    async fn delete_cashflow(&self, id: i32, actor_id: Option<i32>) -> Result<(), AppError>;
+    async fn list_deleted_cashflows(&self, query: GetListCashflowQuery) -> Result<Paginated<CashflowResponse>, AppError>;
+    async fn restore_cashflow(&self, id: i32, actor_id: Option<i32>) -> Result<CashflowResponse, AppError>;
+    async fn purge_cashflow(&self, id: i32, actor_id: Option<i32>) -> Result<(), AppError>;
 }
 
 pub struct CashflowUsecaseImpl {
     cashflow_repository: Arc<dyn CashflowRepository>,
+    transaction_return_repository: Arc<dyn TransactionReturnRepository>,
+    audit_log_repository: Arc<dyn AuditLogRepository>,
 }
 
 impl CashflowUsecaseImpl {
-    pub fn new(cashflow_repository: Arc<dyn CashflowRepository>) -> Self {
-        Self { cashflow_repository }
+    pub fn new(
+        cashflow_repository: Arc<dyn CashflowRepository>,
+        transaction_return_repository: Arc<dyn TransactionReturnRepository>,
+        audit_log_repository: Arc<dyn AuditLogRepository>,
+    ) -> Self {
+        Self { cashflow_repository, transaction_return_repository, audit_log_repository }
     }
 }
 
 #[async_trait]
 impl CashflowUsecase for CashflowUsecaseImpl {
-    async fn create_cashflow(&self, request: CreateCashflowRequest) -> Result<CashflowResponse, AppError> {
+    async fn create_cashflow(&self, request: CreateCashflowRequest, actor_id: Option<i32>) -> Result<CashflowResponse, AppError> {
         // Create active model
         let cashflow_active_model = ActiveModel {
             note: Set(request.note),
@@ -53,25 +66,16 @@ impl CashflowUsecase for CashflowUsecaseImpl {
         };
 
         // Save to repository
-        self.cashflow_repository.create(cashflow_active_model).await?;
-
-        // Find the created cashflow to return (we'll get the latest one since we don't have a unique identifier)
-        // For now, we'll return a simple success response
-        // In a real scenario, you might want to add a unique constraint or return the ID differently
-        let created_cashflow = self.cashflow_repository
-            .find_with_pagination(GetListCashflowQuery {
-                limit: Some("1".to_string()),
-                page: Some("1".to_string()),
-                search: None,
-                order_by: Some("id".to_string()),
-                direction: Some("desc".to_string()),
-                r#type: None,
-                recap_type: None,
-            })
-            .await?;
-
-        let cashflow = created_cashflow.data.into_iter().next()
-            .ok_or_else(|| AppError::InternalError)?;
+        let cashflow = self.cashflow_repository.create(cashflow_active_model).await?;
+
+        self.audit_log_repository.record(NewAuditLog {
+            actor_id,
+            entity: ENTITY_CASHFLOW,
+            entity_id: cashflow.id,
+            action: ACTION_CREATE,
+            before: None,
+            after: Some(json!(cashflow)),
+        }).await?;
 
         Ok(CashflowResponse::from(cashflow))
     }
@@ -85,14 +89,14 @@ impl CashflowUsecase for CashflowUsecaseImpl {
         Ok(CashflowResponse::from(cashflow))
     }
 
-    async fn list_cashflows(&self, query: GetListCashflowQuery) -> Result<ListCashflowResponse, AppError> {
+    async fn list_cashflows(&self, query: GetListCashflowQuery) -> Result<Paginated<CashflowResponse>, AppError> {
         let paginated_result = self.cashflow_repository.find_with_pagination(query).await?;
-        Ok(ListCashflowResponse::from(paginated_result))
+        Ok(Paginated::from(paginated_result))
     }
 
-    async fn update_cashflow(&self, id: i32, request: UpdateCashflowRequest) -> Result<CashflowResponse, AppError> {
+    async fn update_cashflow(&self, id: i32, request: UpdateCashflowRequest, actor_id: Option<i32>) -> Result<CashflowResponse, AppError> {
         // Check if cashflow exists
-        self.cashflow_repository
+        let existing_cashflow = self.cashflow_repository
             .find_by_id(id)
             .await?
             .ok_or_else(|| AppError::NotFound(format!("Cashflow with id {} not found", id)))?;
@@ -119,18 +123,88 @@ impl CashflowUsecase for CashflowUsecaseImpl {
         // Update in repository
         let updated_cashflow = self.cashflow_repository.update(id, cashflow_active_model).await?;
 
+        self.audit_log_repository.record(NewAuditLog {
+            actor_id,
+            entity: ENTITY_CASHFLOW,
+            entity_id: id,
+            action: ACTION_UPDATE,
+            before: Some(json!(existing_cashflow)),
+            after: Some(json!(updated_cashflow)),
+        }).await?;
+
         Ok(CashflowResponse::from(updated_cashflow))
     }
 
-    async fn delete_cashflow(&self, id: i32) -> Result<(), AppError> {
+    async fn delete_cashflow(&self, id: i32, actor_id: Option<i32>) -> Result<(), AppError> {
         // Check if cashflow exists
-        self.cashflow_repository
+        let existing_cashflow = self.cashflow_repository
             .find_by_id(id)
             .await?
             .ok_or_else(|| AppError::NotFound(format!("Cashflow with id {} not found", id)))?;
 
+        // A refund stays as long as the return it paid out
+        if let Some(transaction_return) = self.transaction_return_repository.find_by_cashflow(id).await? {
+            return Err(AppError::BadRequest(format!("Cashflow {} is the refund of return {} and cannot be deleted", id, transaction_return.id)));
+        }
+
         // Soft delete
-        self.cashflow_repository.delete(id).await?;
+        let deleted_cashflow = self.cashflow_repository.delete(id).await?;
+
+        self.audit_log_repository.record(NewAuditLog {
+            actor_id,
+            entity: ENTITY_CASHFLOW,
+            entity_id: id,
+            action: ACTION_DELETE,
+            before: Some(json!(existing_cashflow)),
+            after: Some(json!(deleted_cashflow)),
+        }).await?;
+
+        Ok(())
+    }
+
+    async fn list_deleted_cashflows(&self, query: GetListCashflowQuery) -> Result<Paginated<CashflowResponse>, AppError> {
+        let paginated_result = self.cashflow_repository.find_deleted_with_pagination(query).await?;
+        Ok(Paginated::from(paginated_result))
+    }
+
+    async fn restore_cashflow(&self, id: i32, actor_id: Option<i32>) -> Result<CashflowResponse, AppError> {
+        // Check if cashflow is in the trash
+        let deleted_cashflow = self.cashflow_repository
+            .find_deleted_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Deleted cashflow with id {} not found", id)))?;
+
+        let restored_cashflow = self.cashflow_repository.restore(id).await?;
+
+        self.audit_log_repository.record(NewAuditLog {
+            actor_id,
+            entity: ENTITY_CASHFLOW,
+            entity_id: id,
+            action: ACTION_RESTORE,
+            before: Some(json!(deleted_cashflow)),
+            after: Some(json!(restored_cashflow)),
+        }).await?;
+
+        Ok(CashflowResponse::from(restored_cashflow))
+    }
+
+    async fn purge_cashflow(&self, id: i32, actor_id: Option<i32>) -> Result<(), AppError> {
+        // Only soft-deleted rows can be purged
+        let deleted_cashflow = self.cashflow_repository
+            .find_deleted_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Deleted cashflow with id {} not found", id)))?;
+
+        self.cashflow_repository.purge(id).await?;
+
+        self.audit_log_repository.record(NewAuditLog {
+            actor_id,
+            entity: ENTITY_CASHFLOW,
+            entity_id: id,
+            action: ACTION_PURGE,
+            before: Some(json!(deleted_cashflow)),
+            after: None,
+        }).await?;
 
         Ok(())
     }
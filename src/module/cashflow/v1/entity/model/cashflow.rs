@@ -2,6 +2,11 @@ use sea_orm::entity::prelude::*;
 use serde::{Serialize, Deserialize};
 use chrono::{Utc, DateTime};
 
+// Values posted by the system; entries created through the API may use any type
+pub const CASHFLOW_OUT: &str = "out";
+pub const RECAP_PURCHASE: &str = "purchase";
+pub const RECAP_REFUND: &str = "refund";
+
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
 #[sea_orm(table_name = "cashflow")]
 pub struct Model {
@@ -13,7 +13,28 @@ pub struct GetListCashflowQuery {
 
     pub direction: Option<String>,
 
+    // Switches to keyset pagination; pass an empty value for the first page
+    pub cursor: Option<String>,
+
+    // "false" skips the COUNT query
+    pub include_total: Option<String>,
+
+    // Comma separated values
     pub r#type: Option<String>,
 
+    // Comma separated values
     pub recap_type: Option<String>,
+
+    // YYYY-MM-DD (interpreted in `timezone`) or RFC 3339
+    pub created_from: Option<String>,
+
+    pub created_to: Option<String>,
+
+    // UTC offset such as +07:00, defaults to UTC
+    pub timezone: Option<String>,
+
+    // Bounds on nominal
+    pub min_amount: Option<String>,
+
+    pub max_amount: Option<String>,
 }
@@ -10,6 +10,8 @@ pub struct CashflowResponse {
     pub recap_type: String,
     pub created_at: Option<DateTime<Utc>>,
     pub updated_at: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deleted_at: Option<DateTime<Utc>>,
 }
 
 impl From<crate::module::cashflow::v1::entity::model::cashflow::Model> for CashflowResponse {
@@ -22,6 +24,7 @@ impl From<crate::module::cashflow::v1::entity::model::cashflow::Model> for Cashf
             recap_type: model.recap_type,
             created_at: model.created_at,
             updated_at: model.updated_at,
+            deleted_at: model.deleted_at,
         }
     }
 }
@@ -1,2 +1 @@
 pub mod cashflow_response;
-pub mod list_cashflow_response;
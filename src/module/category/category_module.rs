@@ -0,0 +1,7 @@
+use axum::Router;
+
+use crate::module::category::v1::category_handler;
+
+pub fn configure() -> Router {
+	Router::new().nest("/api/v1/category", category_handler::routes())
+}
@@ -0,0 +1,119 @@
+use axum::{
+	extract::{Extension, OriginalUri, Path, Query}, 
+    http::StatusCode, 
+    response::IntoResponse, 
+    routing::{get, post, put, delete}, 
+    Json, 
+    Router
+};
+use serde_json::json;
+use validator::Validate;
+use std::sync::Arc;
+
+use crate::module::category::v1::entity::{
+    request::{
+        create_category_request::CreateCategoryRequest,
+        update_category_request::UpdateCategoryRequest,
+        list_category_request::GetListCategoryQuery,
+    },
+};
+use crate::pkg::auth::AuthUser;
+use crate::pkg::custom_error::AppError;
+use crate::pkg::response::ApiResponse;
+use crate::di::AppModule;
+
+
+pub fn routes() -> Router {
+	Router::new()
+		.route("/", post(create_category))
+		.route("/", get(list_categories))
+		.route("/tree", get(category_tree))
+		.route("/:id", get(get_category))
+		.route("/:id", put(update_category))
+		.route("/:id", delete(delete_category))
+}
+
+async fn create_category(
+    Extension(app_module): Extension<Arc<AppModule>>,
+    auth_user: Option<AuthUser>,
+    Json(payload): Json<CreateCategoryRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    payload.validate().map_err(|e| {
+        AppError::BadRequest(e.to_string())
+    })?;
+
+    let category = app_module.category_usecase.create_category(payload, auth_user.map(|user| user.id)).await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(ApiResponse::success("Category created successfully", Some(json!(category)), None)),
+    ))
+}
+
+async fn get_category(
+    Extension(app_module): Extension<Arc<AppModule>>,
+    Path(id): Path<i32>,
+) -> Result<impl IntoResponse, AppError> {
+    let category = app_module.category_usecase.get_category(id).await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse::success("Category retrieved successfully", Some(json!(category)), None)),
+    ))
+}
+
+async fn list_categories(
+    Extension(app_module): Extension<Arc<AppModule>>,
+    OriginalUri(uri): OriginalUri,
+    Query(query): Query<GetListCategoryQuery>,
+) -> Result<impl IntoResponse, AppError> {
+    let categories = app_module.category_usecase.list_categories(query).await?;
+
+    Ok((
+        StatusCode::OK,
+        categories.headers(&uri),
+        Json(ApiResponse::success("Categories retrieved successfully", Some(json!(categories.data)), Some(json!(categories.meta)))),
+    ))
+}
+
+async fn category_tree(
+    Extension(app_module): Extension<Arc<AppModule>>,
+) -> Result<impl IntoResponse, AppError> {
+    let tree = app_module.category_usecase.category_tree().await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse::success("Category tree retrieved successfully", Some(json!(tree)), None)),
+    ))
+}
+
+async fn update_category(
+    Extension(app_module): Extension<Arc<AppModule>>,
+    auth_user: Option<AuthUser>,
+    Path(id): Path<i32>,
+    Json(payload): Json<UpdateCategoryRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    payload.validate().map_err(|e| {
+        AppError::BadRequest(e.to_string())
+    })?;
+
+    let category = app_module.category_usecase.update_category(id, payload, auth_user.map(|user| user.id)).await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse::success("Category updated successfully", Some(json!(category)), None)),
+    ))
+}
+
+async fn delete_category(
+    Extension(app_module): Extension<Arc<AppModule>>,
+    auth_user: Option<AuthUser>,
+    Path(id): Path<i32>,
+) -> Result<impl IntoResponse, AppError> {
+    app_module.category_usecase.delete_category(id, auth_user.map(|user| user.id)).await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse::success("Category deleted successfully", Some(json!({})), None)),
+    ))
+}
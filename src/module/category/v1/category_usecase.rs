@@ -0,0 +1,217 @@
+use async_trait::async_trait;
+use crate::module::category::v1::entity::{
+    request::{
+        create_category_request::CreateCategoryRequest,
+        update_category_request::UpdateCategoryRequest,
+        list_category_request::GetListCategoryQuery,
+    },
+    response::category_response::{CategoryResponse, CategoryTreeResponse},
+    model::category::{ActiveModel, Model as CategoryModel},
+};
+use crate::module::repository::category_repository::CategoryRepository;
+use crate::module::repository::product_repository::ProductRepository;
+use crate::module::repository::audit_log_repository::{AuditLogRepository, NewAuditLog};
+use crate::pkg::audit::{ENTITY_CATEGORY, ACTION_CREATE, ACTION_UPDATE, ACTION_DELETE};
+use crate::pkg::custom_error::AppError;
+use crate::pkg::pagination::Paginated;
+use sea_orm::Set;
+use serde_json::json;
+use std::collections::HashMap;
+use std::sync::Arc;
+use chrono::Utc;
+
+#[async_trait]
+pub trait CategoryUsecase: Send + Sync {
+    async fn create_category(&self, request: CreateCategoryRequest, actor_id: Option<i32>) -> Result<CategoryResponse, AppError>;
+    async fn get_category(&self, id: i32) -> Result<CategoryResponse, AppError>;
+    async fn list_categories(&self, query: GetListCategoryQuery) -> Result<Paginated<CategoryResponse>, AppError>;
+    async fn category_tree(&self) -> Result<Vec<CategoryTreeResponse>, AppError>;
+    async fn update_category(&self, id: i32, request: UpdateCategoryRequest, actor_id: Option<i32>) -> Result<CategoryResponse, AppError>;
+    async fn delete_category(&self, id: i32, actor_id: Option<i32>) -> Result<(), AppError>;
+}
+
+pub struct CategoryUsecaseImpl {
+    category_repository: Arc<dyn CategoryRepository>,
+    product_repository: Arc<dyn ProductRepository>,
+    audit_log_repository: Arc<dyn AuditLogRepository>,
+}
+
+impl CategoryUsecaseImpl {
+    pub fn new(
+        category_repository: Arc<dyn CategoryRepository>,
+        product_repository: Arc<dyn ProductRepository>,
+        audit_log_repository: Arc<dyn AuditLogRepository>,
+    ) -> Self {
+        Self { category_repository, product_repository, audit_log_repository }
+    }
+
+    async fn check_parent(&self, parent_id: Option<i32>) -> Result<(), AppError> {
+        if let Some(parent_id) = parent_id {
+            self.category_repository
+                .find_by_id(parent_id)
+                .await?
+                .ok_or_else(|| AppError::BadRequest(format!("Parent category with id {} not found", parent_id)))?;
+        }
+
+        Ok(())
+    }
+}
+
+fn build_tree(parent_id: Option<i32>, children: &HashMap<Option<i32>, Vec<CategoryModel>>) -> Vec<CategoryTreeResponse> {
+    children
+        .get(&parent_id)
+        .into_iter()
+        .flatten()
+        .map(|category| CategoryTreeResponse {
+            id: category.id,
+            name: category.name.clone(),
+            parent_id: category.parent_id,
+            children: build_tree(Some(category.id), children),
+        })
+        .collect()
+}
+
+#[async_trait]
+impl CategoryUsecase for CategoryUsecaseImpl {
+    async fn create_category(&self, request: CreateCategoryRequest, actor_id: Option<i32>) -> Result<CategoryResponse, AppError> {
+        self.check_parent(request.parent_id).await?;
+
+        // Check if a sibling with same name already exists
+        if self.category_repository.find_by_name(&request.name, request.parent_id).await?.is_some() {
+            return Err(AppError::BadRequest("Category with this name already exists".to_string()));
+        }
+
+        // Create active model
+        let category_active_model = ActiveModel {
+            name: Set(request.name),
+            parent_id: Set(request.parent_id),
+            created_at: Set(Some(Utc::now())),
+            updated_at: Set(Some(Utc::now())),
+            deleted_at: Set(None),
+            ..Default::default()
+        };
+
+        let created_category = self.category_repository.create(category_active_model).await?;
+
+        self.audit_log_repository.record(NewAuditLog {
+            actor_id,
+            entity: ENTITY_CATEGORY,
+            entity_id: created_category.id,
+            action: ACTION_CREATE,
+            before: None,
+            after: Some(json!(created_category)),
+        }).await?;
+
+        Ok(CategoryResponse::from(created_category))
+    }
+
+    async fn get_category(&self, id: i32) -> Result<CategoryResponse, AppError> {
+        let category = self.category_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Category with id {} not found", id)))?;
+
+        Ok(CategoryResponse::from(category))
+    }
+
+    async fn list_categories(&self, query: GetListCategoryQuery) -> Result<Paginated<CategoryResponse>, AppError> {
+        let paginated_result = self.category_repository.find_with_pagination(query).await?;
+        Ok(Paginated::from(paginated_result))
+    }
+
+    async fn category_tree(&self) -> Result<Vec<CategoryTreeResponse>, AppError> {
+        let categories = self.category_repository.find_all().await?;
+
+        let mut children: HashMap<Option<i32>, Vec<CategoryModel>> = HashMap::new();
+        for category in categories {
+            children.entry(category.parent_id).or_default().push(category);
+        }
+        for siblings in children.values_mut() {
+            siblings.sort_by(|a, b| a.name.cmp(&b.name));
+        }
+
+        Ok(build_tree(None, &children))
+    }
+
+    async fn update_category(&self, id: i32, request: UpdateCategoryRequest, actor_id: Option<i32>) -> Result<CategoryResponse, AppError> {
+        // Check if category exists
+        let existing_category = self.category_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Category with id {} not found", id)))?;
+
+        let parent_id = request.parent_id.unwrap_or(existing_category.parent_id);
+
+        if let Some(Some(new_parent_id)) = request.parent_id {
+            self.check_parent(Some(new_parent_id)).await?;
+
+            // A category cannot move below itself
+            if self.category_repository.find_descendant_ids(&[id]).await?.contains(&new_parent_id) {
+                return Err(AppError::BadRequest("Category cannot be moved under itself or its subcategories".to_string()));
+            }
+        }
+
+        // Check if new name conflicts with a sibling
+        let name = request.name.clone().unwrap_or_else(|| existing_category.name.clone());
+        if let Some(sibling) = self.category_repository.find_by_name(&name, parent_id).await? {
+            if sibling.id != id {
+                return Err(AppError::BadRequest("Category with this name already exists".to_string()));
+            }
+        }
+
+        // Create active model with only changed fields
+        let mut category_active_model = ActiveModel {
+            id: Set(id),
+            ..Default::default()
+        };
+
+        if let Some(name) = request.name {
+            category_active_model.name = Set(name);
+        }
+        if let Some(parent_id) = request.parent_id {
+            category_active_model.parent_id = Set(parent_id);
+        }
+
+        let updated_category = self.category_repository.update(id, category_active_model).await?;
+
+        self.audit_log_repository.record(NewAuditLog {
+            actor_id,
+            entity: ENTITY_CATEGORY,
+            entity_id: id,
+            action: ACTION_UPDATE,
+            before: Some(json!(existing_category)),
+            after: Some(json!(updated_category)),
+        }).await?;
+
+        Ok(CategoryResponse::from(updated_category))
+    }
+
+    async fn delete_category(&self, id: i32, actor_id: Option<i32>) -> Result<(), AppError> {
+        // Check if category exists
+        let existing_category = self.category_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Category with id {} not found", id)))?;
+
+        if self.category_repository.count_children(id).await? > 0 {
+            return Err(AppError::BadRequest("Category still has subcategories".to_string()));
+        }
+        if self.product_repository.count_by_category(id).await? > 0 {
+            return Err(AppError::BadRequest("Category still has products".to_string()));
+        }
+
+        // Soft delete
+        let deleted_category = self.category_repository.delete(id).await?;
+
+        self.audit_log_repository.record(NewAuditLog {
+            actor_id,
+            entity: ENTITY_CATEGORY,
+            entity_id: id,
+            action: ACTION_DELETE,
+            before: Some(json!(existing_category)),
+            after: Some(json!(deleted_category)),
+        }).await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1 @@
+pub mod category;
\ No newline at end of file
@@ -0,0 +1,11 @@
+use validator::{Validate};
+use serde::{Serialize, Deserialize};
+
+#[derive(Debug, Serialize, Deserialize, Validate)]
+pub struct CreateCategoryRequest {
+    #[validate(length(min = 1, max = 255, message = "Name must be between 1 and 255 characters"))]
+    pub name: String,
+
+    #[validate(range(min = 1, message = "Parent ID must be positive"))]
+    pub parent_id: Option<i32>,
+}
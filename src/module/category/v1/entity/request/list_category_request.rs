@@ -0,0 +1,18 @@
+use validator::{Validate};
+use serde::{Serialize, Deserialize};
+
+#[derive(Debug, Serialize, Deserialize, Validate)]
+pub struct GetListCategoryQuery {
+    pub limit: Option<String>,
+
+    pub page: Option<String>,
+
+    pub search: Option<String>,
+
+    pub order_by: Option<String>,
+
+    pub direction: Option<String>,
+
+    // Direct children of this category, "root" for top-level categories
+    pub parent_id: Option<String>,
+}
@@ -0,0 +1,3 @@
+pub mod create_category_request;
+pub mod update_category_request;
+pub mod list_category_request;
@@ -0,0 +1,14 @@
+use validator::{Validate};
+use serde::{Serialize, Deserialize};
+
+use crate::pkg::helper::deserialize_some;
+
+#[derive(Debug, Serialize, Deserialize, Validate)]
+pub struct UpdateCategoryRequest {
+    #[validate(length(min = 1, max = 255, message = "Name must be between 1 and 255 characters"))]
+    pub name: Option<String>,
+
+    // Omitted keeps the parent, null moves the category to the top level
+    #[serde(default, deserialize_with = "deserialize_some")]
+    pub parent_id: Option<Option<i32>>,
+}
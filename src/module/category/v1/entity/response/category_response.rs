@@ -0,0 +1,32 @@
+use serde::{Serialize, Deserialize};
+use chrono::{Utc, DateTime};
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CategoryResponse {
+    pub id: i32,
+    pub name: String,
+    pub parent_id: Option<i32>,
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl From<crate::module::category::v1::entity::model::category::Model> for CategoryResponse {
+    fn from(model: crate::module::category::v1::entity::model::category::Model) -> Self {
+        Self {
+            id: model.id,
+            name: model.name,
+            parent_id: model.parent_id,
+            created_at: model.created_at,
+            updated_at: model.updated_at,
+        }
+    }
+}
+
+/// Category with its subcategories nested
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CategoryTreeResponse {
+    pub id: i32,
+    pub name: String,
+    pub parent_id: Option<i32>,
+    pub children: Vec<CategoryTreeResponse>,
+}
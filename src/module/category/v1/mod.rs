@@ -0,0 +1,3 @@
+pub mod entity;
+pub mod category_usecase;
+pub mod category_handler;
\ No newline at end of file
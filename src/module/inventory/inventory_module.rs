@@ -0,0 +1,7 @@
+use axum::Router;
+
+use crate::module::inventory::v1::inventory_handler;
+
+pub fn configure() -> Router {
+	Router::new().nest("/api/v1/inventory", inventory_handler::routes())
+}
@@ -0,0 +1,3 @@
+pub mod stock_movement;
+pub mod stock_lot;
+pub mod stock_consumption;
\ No newline at end of file
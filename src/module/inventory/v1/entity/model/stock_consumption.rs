@@ -0,0 +1,30 @@
+use sea_orm::entity::prelude::*;
+use serde::{Serialize, Deserialize};
+use chrono::{Utc, DateTime};
+
+/// Base quantity an outgoing movement took from one cost layer or one lot, and how much of it has come back since
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "stock_consumptions")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = true)]
+    pub id: i32,
+    pub movement_id: i32,
+    // Costed receipt the stock was taken from
+    pub layer_id: Option<i32>,
+    // Lot the stock was taken from
+    pub lot_id: Option<i32>,
+    pub qty: Decimal,
+    pub restored_qty: Decimal,
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter)]
+pub enum Relation {}
+
+impl RelationTrait for Relation {
+    fn def(&self) -> RelationDef {
+        unimplemented!()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
\ No newline at end of file
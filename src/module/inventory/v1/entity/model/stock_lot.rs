@@ -0,0 +1,33 @@
+use sea_orm::entity::prelude::*;
+use serde::{Serialize, Deserialize};
+use chrono::{Utc, DateTime, NaiveDate};
+
+/// Base quantity of a product or variant received with one expiry date, used up earliest expiry first
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "stock_lots")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = true)]
+    pub id: i32,
+    pub product_id: i32,
+    pub variant_id: Option<i32>,
+    // Batch number printed by the supplier
+    pub lot_code: Option<String>,
+    // Last day the stock may be sold
+    pub expires_at: NaiveDate,
+    pub qty: Decimal,
+    pub remaining_qty: Decimal,
+    // Purchase cost per base unit, unknown for stock added by adjustment
+    pub unit_cost: Option<i64>,
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter)]
+pub enum Relation {}
+
+impl RelationTrait for Relation {
+    fn def(&self) -> RelationDef {
+        unimplemented!()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
\ No newline at end of file
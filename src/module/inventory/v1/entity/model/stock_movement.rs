@@ -0,0 +1,50 @@
+use sea_orm::entity::prelude::*;
+use serde::{Serialize, Deserialize};
+use chrono::{Utc, DateTime};
+
+pub const MOVEMENT_SALE: &str = "sale";
+pub const MOVEMENT_SALE_REVERSAL: &str = "sale_reversal";
+pub const MOVEMENT_ADJUSTMENT: &str = "adjustment";
+pub const MOVEMENT_PURCHASE: &str = "purchase";
+pub const MOVEMENT_COUNT: &str = "count";
+pub const MOVEMENT_WRITE_OFF: &str = "write_off";
+pub const MOVEMENT_RETURN: &str = "return";
+
+pub const REFERENCE_TRANSACTION: &str = "transaction";
+pub const REFERENCE_PURCHASE_ORDER: &str = "purchase_order";
+pub const REFERENCE_STOCK_COUNT: &str = "stock_count";
+pub const REFERENCE_TRANSACTION_RETURN: &str = "transaction_return";
+
+/// Signed stock change of a product, or of one of its variants when `variant_id` is set
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "stock_movements")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = true)]
+    pub id: i32,
+    pub product_id: i32,
+    pub variant_id: Option<i32>,
+    pub qty: Decimal,
+    pub r#type: String,
+    pub reference_type: Option<String>,
+    pub reference_id: Option<i32>,
+    pub note: Option<String>,
+    pub created_by: Option<i32>,
+    // Total purchase cost of the quantity received, or the cost of the stock taken out
+    pub cost_amount: Option<i64>,
+    // Part of a costed receipt still in stock, consumed first in first out
+    pub remaining_qty: Option<Decimal>,
+    // Lot this receipt created, or this write-off took from
+    pub lot_id: Option<i32>,
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter)]
+pub enum Relation {}
+
+impl RelationTrait for Relation {
+    fn def(&self) -> RelationDef {
+        unimplemented!()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
\ No newline at end of file
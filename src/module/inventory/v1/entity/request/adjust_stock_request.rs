@@ -0,0 +1,30 @@
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use validator::{Validate};
+use serde::{Serialize, Deserialize};
+
+use crate::pkg::quantity::validate_nonzero_qty;
+
+#[derive(Debug, Serialize, Deserialize, Validate)]
+pub struct AdjustStockRequest {
+    #[validate(range(min = 1, message = "Product ID must be positive"))]
+    pub product_id: i32,
+
+    // Required when the product has variants
+    #[validate(range(min = 1, message = "Variant ID must be positive"))]
+    pub variant_id: Option<i32>,
+
+    // In the product's base unit; positive adds stock, negative removes it
+    #[validate(custom = "validate_nonzero_qty")]
+    pub qty: Decimal,
+
+    #[validate(length(min = 1, max = 255, message = "Note must be between 1 and 255 characters"))]
+    pub note: Option<String>,
+
+    // Added stock only: opens a lot expiring at the end of this day
+    pub expires_at: Option<NaiveDate>,
+
+    // Requires `expires_at`
+    #[validate(length(min = 1, max = 64, message = "Lot code must be between 1 and 64 characters"))]
+    pub lot_code: Option<String>,
+}
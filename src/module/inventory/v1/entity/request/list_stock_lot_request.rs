@@ -0,0 +1,14 @@
+use validator::{Validate};
+use serde::{Serialize, Deserialize};
+
+#[derive(Debug, Serialize, Deserialize, Validate)]
+pub struct GetExpiringLotsQuery {
+    // Lots expiring within this many days from today, defaults to 7; expired lots are always listed
+    pub days: Option<String>,
+
+    // Comma separated product ids
+    pub product_id: Option<String>,
+
+    // UTC offset such as +07:00 that decides what today is, defaults to UTC
+    pub timezone: Option<String>,
+}
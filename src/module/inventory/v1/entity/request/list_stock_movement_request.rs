@@ -0,0 +1,32 @@
+use validator::{Validate};
+use serde::{Serialize, Deserialize};
+
+#[derive(Debug, Serialize, Deserialize, Validate)]
+pub struct GetListStockMovementQuery {
+    pub limit: Option<String>,
+
+    pub page: Option<String>,
+
+    // Newest first unless "asc"
+    pub direction: Option<String>,
+
+    // "false" skips the COUNT query
+    pub include_total: Option<String>,
+
+    // Comma separated product ids
+    pub product_id: Option<String>,
+
+    // Comma separated variant ids
+    pub variant_id: Option<String>,
+
+    // Comma separated movement types
+    pub r#type: Option<String>,
+
+    // YYYY-MM-DD (interpreted in `timezone`) or RFC 3339
+    pub created_from: Option<String>,
+
+    pub created_to: Option<String>,
+
+    // UTC offset such as +07:00, defaults to UTC
+    pub timezone: Option<String>,
+}
@@ -0,0 +1,11 @@
+use validator::{Validate};
+use serde::{Serialize, Deserialize};
+
+#[derive(Debug, Serialize, Deserialize, Validate)]
+pub struct GetLowStockQuery {
+    // Days of sales averaged for the suggestion, defaults to REORDER_LOOKBACK_DAYS
+    pub lookback_days: Option<String>,
+
+    // Comma separated supplier ids
+    pub supplier_id: Option<String>,
+}
@@ -0,0 +1,5 @@
+pub mod adjust_stock_request;
+pub mod list_stock_movement_request;
+pub mod low_stock_request;
+pub mod list_stock_lot_request;
+pub mod write_off_lot_request;
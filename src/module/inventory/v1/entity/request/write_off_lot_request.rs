@@ -0,0 +1,11 @@
+use validator::{Validate};
+use serde::{Serialize, Deserialize};
+
+#[derive(Debug, Serialize, Deserialize, Validate)]
+pub struct WriteOffLotRequest {
+    #[validate(length(min = 1, max = 255, message = "Note must be between 1 and 255 characters"))]
+    pub note: Option<String>,
+
+    // UTC offset such as +07:00 that decides what today is, defaults to UTC
+    pub timezone: Option<String>,
+}
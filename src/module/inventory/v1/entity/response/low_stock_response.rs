@@ -0,0 +1,20 @@
+use rust_decimal::Decimal;
+use serde::{Serialize, Deserialize};
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct LowStockResponse {
+    pub product_id: i32,
+    pub product_name: String,
+    pub variant_id: Option<i32>,
+    pub variant_name: Option<String>,
+    pub base_unit: String,
+    pub stock: Decimal,
+    pub reorder_point: Decimal,
+    pub supplier_id: Option<i32>,
+    pub supplier_name: Option<String>,
+    pub lead_time_days: i32,
+    // Base quantity sold per day over the lookback window
+    pub avg_daily_sales: Decimal,
+    // Enough to cover sales during the lead time and get back above the reorder point
+    pub suggested_qty: Decimal,
+}
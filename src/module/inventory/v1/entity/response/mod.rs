@@ -0,0 +1,3 @@
+pub mod stock_movement_response;
+pub mod low_stock_response;
+pub mod stock_lot_response;
@@ -0,0 +1,24 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use serde::{Serialize, Deserialize};
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct StockLotResponse {
+    pub id: i32,
+    pub product_id: i32,
+    pub product_name: String,
+    pub variant_id: Option<i32>,
+    pub variant_name: Option<String>,
+    pub base_unit: String,
+    pub lot_code: Option<String>,
+    pub expires_at: NaiveDate,
+    // Negative once expired
+    pub days_left: i64,
+    pub qty: Decimal,
+    pub remaining_qty: Decimal,
+    // Purchase cost per base unit, or the current HPP when the lot has none
+    pub unit_cost: i64,
+    // Remaining stock valued at `unit_cost`
+    pub value_amount: i64,
+    pub created_at: Option<DateTime<Utc>>,
+}
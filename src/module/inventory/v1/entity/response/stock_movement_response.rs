@@ -0,0 +1,40 @@
+use rust_decimal::Decimal;
+use serde::{Serialize, Deserialize};
+use chrono::{Utc, DateTime};
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct StockMovementResponse {
+    pub id: i32,
+    pub product_id: i32,
+    pub variant_id: Option<i32>,
+    pub qty: Decimal,
+    pub r#type: String,
+    pub reference_type: Option<String>,
+    pub reference_id: Option<i32>,
+    pub note: Option<String>,
+    pub created_by: Option<i32>,
+    pub cost_amount: Option<i64>,
+    pub remaining_qty: Option<Decimal>,
+    pub lot_id: Option<i32>,
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+impl From<crate::module::inventory::v1::entity::model::stock_movement::Model> for StockMovementResponse {
+    fn from(model: crate::module::inventory::v1::entity::model::stock_movement::Model) -> Self {
+        Self {
+            id: model.id,
+            product_id: model.product_id,
+            variant_id: model.variant_id,
+            qty: model.qty,
+            r#type: model.r#type,
+            reference_type: model.reference_type,
+            reference_id: model.reference_id,
+            note: model.note,
+            created_by: model.created_by,
+            cost_amount: model.cost_amount,
+            remaining_qty: model.remaining_qty,
+            lot_id: model.lot_id,
+            created_at: model.created_at,
+        }
+    }
+}
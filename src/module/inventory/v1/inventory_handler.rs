@@ -0,0 +1,110 @@
+use axum::{
+	extract::{Extension, OriginalUri, Path, Query}, 
+    http::StatusCode, 
+    response::IntoResponse, 
+    routing::{get, post}, 
+    Json, 
+    Router
+};
+use serde_json::json;
+use validator::Validate;
+use std::sync::Arc;
+
+use crate::module::inventory::v1::entity::request::{
+    adjust_stock_request::AdjustStockRequest,
+    list_stock_movement_request::GetListStockMovementQuery,
+    low_stock_request::GetLowStockQuery,
+    list_stock_lot_request::GetExpiringLotsQuery,
+    write_off_lot_request::WriteOffLotRequest,
+};
+use crate::pkg::auth::{AuthUser, ROLE_OWNER, ROLE_SUPERVISOR};
+use crate::pkg::custom_error::AppError;
+use crate::pkg::response::ApiResponse;
+use crate::di::AppModule;
+
+
+pub fn routes() -> Router {
+	Router::new()
+		.route("/adjustments", post(adjust_stock))
+		.route("/movements", get(list_movements))
+		.route("/low-stock", get(low_stock))
+		.route("/lots/expiring", get(expiring_lots))
+		.route("/lots/:id/write-off", post(write_off_lot))
+}
+
+async fn adjust_stock(
+    Extension(app_module): Extension<Arc<AppModule>>,
+    auth_user: AuthUser,
+    Json(payload): Json<AdjustStockRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    auth_user.require_role(&[ROLE_OWNER, ROLE_SUPERVISOR])?;
+
+    payload.validate().map_err(|e| {
+        AppError::BadRequest(e.to_string())
+    })?;
+
+    let movement = app_module.inventory_usecase.adjust_stock(payload, Some(auth_user.id)).await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(ApiResponse::success("Stock adjusted successfully", Some(json!(movement)), None)),
+    ))
+}
+
+async fn list_movements(
+    Extension(app_module): Extension<Arc<AppModule>>,
+    OriginalUri(uri): OriginalUri,
+    Query(query): Query<GetListStockMovementQuery>,
+) -> Result<impl IntoResponse, AppError> {
+    let movements = app_module.inventory_usecase.list_movements(query).await?;
+
+    Ok((
+        StatusCode::OK,
+        movements.headers(&uri),
+        Json(ApiResponse::success("Stock movements retrieved successfully", Some(json!(movements.data)), Some(json!(movements.meta)))),
+    ))
+}
+
+async fn low_stock(
+    Extension(app_module): Extension<Arc<AppModule>>,
+    Query(query): Query<GetLowStockQuery>,
+) -> Result<impl IntoResponse, AppError> {
+    let items = app_module.inventory_usecase.low_stock(query).await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse::success("Low stock items retrieved successfully", Some(json!(items)), None)),
+    ))
+}
+
+async fn expiring_lots(
+    Extension(app_module): Extension<Arc<AppModule>>,
+    Query(query): Query<GetExpiringLotsQuery>,
+) -> Result<impl IntoResponse, AppError> {
+    let lots = app_module.inventory_usecase.expiring_lots(query).await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse::success("Expiring lots retrieved successfully", Some(json!(lots)), None)),
+    ))
+}
+
+async fn write_off_lot(
+    Extension(app_module): Extension<Arc<AppModule>>,
+    auth_user: AuthUser,
+    Path(id): Path<i32>,
+    Json(payload): Json<WriteOffLotRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    auth_user.require_role(&[ROLE_OWNER, ROLE_SUPERVISOR])?;
+
+    payload.validate().map_err(|e| {
+        AppError::BadRequest(e.to_string())
+    })?;
+
+    let movement = app_module.inventory_usecase.write_off_lot(id, payload, Some(auth_user.id)).await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(ApiResponse::success("Lot written off successfully", Some(json!(movement)), None)),
+    ))
+}
\ No newline at end of file
@@ -0,0 +1,353 @@
+use async_trait::async_trait;
+use chrono::{Duration, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use std::collections::HashMap;
+use crate::module::inventory::v1::entity::{
+    request::{
+        adjust_stock_request::AdjustStockRequest,
+        list_stock_movement_request::GetListStockMovementQuery,
+        low_stock_request::GetLowStockQuery,
+        list_stock_lot_request::GetExpiringLotsQuery,
+        write_off_lot_request::WriteOffLotRequest,
+    },
+    response::{
+        stock_movement_response::StockMovementResponse,
+        low_stock_response::LowStockResponse,
+        stock_lot_response::StockLotResponse,
+    },
+    model::stock_movement::{MOVEMENT_ADJUSTMENT, MOVEMENT_WRITE_OFF},
+    model::stock_lot::Model as StockLotModel,
+};
+use crate::module::product::v1::entity::model::{product::Model as ProductModel, product_variant::Model as ProductVariantModel};
+use crate::module::repository::product_repository::ProductRepository;
+use crate::module::repository::product_variant_repository::ProductVariantRepository;
+use crate::module::repository::stock_repository::{StockRepository, NewStockMovement, NewStockLot};
+use crate::module::repository::stock_lot_repository::StockLotRepository;
+use crate::module::repository::supplier_repository::SupplierRepository;
+use crate::module::product::v1::product_usecase::ProductUsecase;
+use crate::pkg::config::Config;
+use crate::pkg::custom_error::AppError;
+use crate::pkg::filter::{parse_list, parse_number, parse_timezone};
+use crate::pkg::quantity::{self, QTY_SCALE};
+use crate::pkg::pagination::Paginated;
+use std::sync::Arc;
+
+#[async_trait]
+pub trait InventoryUsecase: Send + Sync {
+    async fn adjust_stock(&self, request: AdjustStockRequest, actor_id: Option<i32>) -> Result<StockMovementResponse, AppError>;
+    async fn list_movements(&self, query: GetListStockMovementQuery) -> Result<Paginated<StockMovementResponse>, AppError>;
+    async fn low_stock(&self, query: GetLowStockQuery) -> Result<Vec<LowStockResponse>, AppError>;
+    async fn expiring_lots(&self, query: GetExpiringLotsQuery) -> Result<Vec<StockLotResponse>, AppError>;
+    async fn write_off_lot(&self, id: i32, request: WriteOffLotRequest, actor_id: Option<i32>) -> Result<StockMovementResponse, AppError>;
+}
+
+pub struct InventoryUsecaseImpl {
+    product_repository: Arc<dyn ProductRepository>,
+    product_variant_repository: Arc<dyn ProductVariantRepository>,
+    stock_repository: Arc<dyn StockRepository>,
+    stock_lot_repository: Arc<dyn StockLotRepository>,
+    supplier_repository: Arc<dyn SupplierRepository>,
+    product_usecase: Arc<dyn ProductUsecase>,
+    config: Arc<Config>,
+}
+
+impl InventoryUsecaseImpl {
+    pub fn new(
+        product_repository: Arc<dyn ProductRepository>,
+        product_variant_repository: Arc<dyn ProductVariantRepository>,
+        stock_repository: Arc<dyn StockRepository>,
+        stock_lot_repository: Arc<dyn StockLotRepository>,
+        supplier_repository: Arc<dyn SupplierRepository>,
+        product_usecase: Arc<dyn ProductUsecase>,
+        config: Arc<Config>,
+    ) -> Self {
+        Self {
+            product_repository,
+            product_variant_repository,
+            stock_repository,
+            stock_lot_repository,
+            supplier_repository,
+            product_usecase,
+            config,
+        }
+    }
+
+    /// Lot response with the remaining stock valued at the lot's cost, or the current HPP when it has none
+    fn lot_response(lot: StockLotModel, product: &ProductModel, variant: Option<&ProductVariantModel>, today: NaiveDate) -> StockLotResponse {
+        let hpp_amount = variant.map(|variant| variant.hpp_amount).unwrap_or(product.hpp_amount);
+        let unit_cost = lot.unit_cost.unwrap_or(i64::from(hpp_amount));
+
+        StockLotResponse {
+            id: lot.id,
+            product_id: lot.product_id,
+            product_name: product.name.clone(),
+            variant_id: lot.variant_id,
+            variant_name: variant.map(|variant| variant.name.clone()),
+            base_unit: product.base_unit.clone(),
+            lot_code: lot.lot_code,
+            expires_at: lot.expires_at,
+            days_left: (lot.expires_at - today).num_days(),
+            qty: lot.qty,
+            remaining_qty: lot.remaining_qty,
+            unit_cost,
+            value_amount: quantity::line_amount(unit_cost, lot.remaining_qty),
+            created_at: lot.created_at,
+        }
+    }
+}
+
+#[async_trait]
+impl InventoryUsecase for InventoryUsecaseImpl {
+    async fn adjust_stock(&self, request: AdjustStockRequest, actor_id: Option<i32>) -> Result<StockMovementResponse, AppError> {
+        self.product_repository
+            .find_by_id(request.product_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Product with id {} not found", request.product_id)))?;
+
+        // Products with variants keep stock on the variants only
+        match request.variant_id {
+            Some(variant_id) => {
+                self.product_variant_repository
+                    .find_by_id(variant_id)
+                    .await?
+                    .filter(|variant| variant.product_id == request.product_id)
+                    .ok_or_else(|| AppError::NotFound(format!("Variant with id {} not found", variant_id)))?;
+            }
+            None => {
+                if self.product_variant_repository.count_by_product(request.product_id).await? > 0 {
+                    return Err(AppError::BadRequest("variant_id is required for products with variants".to_string()));
+                }
+            }
+        }
+
+        if request.lot_code.is_some() && request.expires_at.is_none() {
+            return Err(AppError::BadRequest("lot_code requires expires_at".to_string()));
+        }
+        if request.expires_at.is_some() && request.qty.is_sign_negative() {
+            return Err(AppError::BadRequest("expires_at only applies to added stock".to_string()));
+        }
+
+        let movement = self.stock_repository.record(NewStockMovement {
+            product_id: request.product_id,
+            variant_id: request.variant_id,
+            qty: request.qty,
+            movement_type: MOVEMENT_ADJUSTMENT,
+            reference_type: None,
+            reference_id: None,
+            note: request.note,
+            created_by: actor_id,
+            cost_amount: None,
+            lot: request.expires_at.map(|expires_at| NewStockLot { lot_code: request.lot_code, expires_at }),
+            lot_id: None,
+            restores: None,
+        }).await?;
+
+        // Stock taken out may have emptied the oldest receipt
+        if movement.qty.is_sign_negative() {
+            self.product_usecase.revalue_cost(request.product_id, request.variant_id, None, actor_id).await?;
+        }
+
+        Ok(StockMovementResponse::from(movement))
+    }
+
+    async fn list_movements(&self, query: GetListStockMovementQuery) -> Result<Paginated<StockMovementResponse>, AppError> {
+        let paginated_result = self.stock_repository.find_with_pagination(query).await?;
+        Ok(Paginated::from(paginated_result))
+    }
+
+    async fn low_stock(&self, query: GetLowStockQuery) -> Result<Vec<LowStockResponse>, AppError> {
+        let lookback_days = parse_number::<i64>("lookback_days", query.lookback_days.as_deref())?
+            .unwrap_or(self.config.reorder.lookback_days);
+        if !(1..=365).contains(&lookback_days) {
+            return Err(AppError::BadRequest("lookback_days must be between 1 and 365".to_string()));
+        }
+        let supplier_ids = parse_list::<i32>("supplier_id", query.supplier_id.as_deref())?;
+
+        let low_products = self.product_repository.find_below_reorder_point().await?;
+        let low_variants = self.product_variant_repository.find_below_reorder_point().await?;
+
+        // Variants take their name, unit and supplier from the parent product
+        let mut products: HashMap<i32, _> = low_products.iter().map(|product| (product.id, product.clone())).collect();
+        let mut parent_ids: Vec<i32> = low_variants
+            .iter()
+            .map(|variant| variant.product_id)
+            .filter(|product_id| !products.contains_key(product_id))
+            .collect();
+        parent_ids.sort_unstable();
+        parent_ids.dedup();
+        for product in self.product_repository.find_by_ids(&parent_ids).await? {
+            products.insert(product.id, product);
+        }
+
+        let mut used_supplier_ids: Vec<i32> = products.values().filter_map(|product| product.supplier_id).collect();
+        used_supplier_ids.sort_unstable();
+        used_supplier_ids.dedup();
+        let suppliers: HashMap<i32, _> = self.supplier_repository
+            .find_by_ids(&used_supplier_ids)
+            .await?
+            .into_iter()
+            .map(|supplier| (supplier.id, supplier))
+            .collect();
+
+        let since = Utc::now() - Duration::days(lookback_days);
+        let sold: HashMap<(i32, Option<i32>), Decimal> = self.stock_repository
+            .sold_since(since)
+            .await?
+            .into_iter()
+            .map(|row| ((row.product_id, row.variant_id), row.qty))
+            .collect();
+
+        let lines = low_products
+            .iter()
+            .map(|product| (product, None, product.stock, product.reorder_point))
+            .chain(low_variants.iter().filter_map(|variant| {
+                products
+                    .get(&variant.product_id)
+                    .map(|product| (product, Some(variant), variant.stock, variant.reorder_point))
+            }));
+
+        let mut report = Vec::new();
+        for (product, variant, stock, reorder_point) in lines {
+            let Some(reorder_point) = reorder_point else { continue };
+            if !supplier_ids.is_empty() && !product.supplier_id.is_some_and(|id| supplier_ids.contains(&id)) {
+                continue;
+            }
+
+            let supplier = product.supplier_id.and_then(|id| suppliers.get(&id));
+            let lead_time_days = supplier.map(|supplier| supplier.lead_time_days).unwrap_or(0);
+
+            let sold_qty = sold
+                .get(&(product.id, variant.map(|variant| variant.id)))
+                .copied()
+                .unwrap_or(Decimal::ZERO)
+                .max(Decimal::ZERO);
+            let avg_daily_sales = (sold_qty / Decimal::from(lookback_days)).round_dp(QTY_SCALE);
+
+            // Whole base units, never negative
+            let suggested_qty = (reorder_point + avg_daily_sales * Decimal::from(lead_time_days) - stock)
+                .ceil()
+                .max(Decimal::ZERO);
+
+            report.push(LowStockResponse {
+                product_id: product.id,
+                product_name: product.name.clone(),
+                variant_id: variant.map(|variant| variant.id),
+                variant_name: variant.map(|variant| variant.name.clone()),
+                base_unit: product.base_unit.clone(),
+                stock,
+                reorder_point,
+                supplier_id: supplier.map(|supplier| supplier.id),
+                supplier_name: supplier.map(|supplier| supplier.name.clone()),
+                lead_time_days,
+                avg_daily_sales: avg_daily_sales.normalize(),
+                suggested_qty,
+            });
+        }
+
+        report.sort_by(|a, b| {
+            a.product_name
+                .cmp(&b.product_name)
+                .then_with(|| a.variant_name.cmp(&b.variant_name))
+        });
+
+        Ok(report)
+    }
+
+    async fn expiring_lots(&self, query: GetExpiringLotsQuery) -> Result<Vec<StockLotResponse>, AppError> {
+        let days = parse_number::<i64>("days", query.days.as_deref())?.unwrap_or(7);
+        if !(1..=365).contains(&days) {
+            return Err(AppError::BadRequest("days must be between 1 and 365".to_string()));
+        }
+        let today = Utc::now().with_timezone(&parse_timezone(query.timezone.as_deref())?).date_naive();
+        let product_ids = parse_list::<i32>("product_id", query.product_id.as_deref())?;
+
+        let lots = self.stock_lot_repository.find_expiring(today + Duration::days(days), &product_ids).await?;
+
+        let mut lot_product_ids: Vec<i32> = lots.iter().map(|lot| lot.product_id).collect();
+        lot_product_ids.sort_unstable();
+        lot_product_ids.dedup();
+        let mut lot_variant_ids: Vec<i32> = lots.iter().filter_map(|lot| lot.variant_id).collect();
+        lot_variant_ids.sort_unstable();
+        lot_variant_ids.dedup();
+
+        let products: HashMap<i32, ProductModel> = self.product_repository
+            .find_by_ids(&lot_product_ids)
+            .await?
+            .into_iter()
+            .map(|product| (product.id, product))
+            .collect();
+        let variants: HashMap<i32, ProductVariantModel> = self.product_variant_repository
+            .find_by_ids(&lot_variant_ids)
+            .await?
+            .into_iter()
+            .map(|variant| (variant.id, variant))
+            .collect();
+
+        // Lots of deleted products and variants are left out
+        let report = lots
+            .into_iter()
+            .filter_map(|lot| {
+                let product = products.get(&lot.product_id)?;
+                let variant = match lot.variant_id {
+                    Some(variant_id) => Some(variants.get(&variant_id)?),
+                    None => None,
+                };
+                Some(Self::lot_response(lot, product, variant, today))
+            })
+            .collect();
+
+        Ok(report)
+    }
+
+    async fn write_off_lot(&self, id: i32, request: WriteOffLotRequest, actor_id: Option<i32>) -> Result<StockMovementResponse, AppError> {
+        let lot = self.stock_lot_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Lot with id {} not found", id)))?;
+
+        let today = Utc::now().with_timezone(&parse_timezone(request.timezone.as_deref())?).date_naive();
+        if lot.expires_at >= today {
+            return Err(AppError::BadRequest(format!("Lot {} is good until {} and has not expired yet", id, lot.expires_at)));
+        }
+        if lot.remaining_qty.is_zero() {
+            return Err(AppError::BadRequest(format!("Lot {} has no stock left", id)));
+        }
+
+        let product = self.product_repository
+            .find_by_id(lot.product_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Product with id {} not found", lot.product_id)))?;
+        let variant = match lot.variant_id {
+            Some(variant_id) => Some(
+                self.product_variant_repository
+                    .find_by_id(variant_id)
+                    .await?
+                    .ok_or_else(|| AppError::NotFound(format!("Variant with id {} not found", variant_id)))?,
+            ),
+            None => None,
+        };
+
+        let (product_id, variant_id) = (lot.product_id, lot.variant_id);
+        let valued = Self::lot_response(lot, &product, variant.as_ref(), today);
+
+        let movement = self.stock_repository.record(NewStockMovement {
+            product_id,
+            variant_id,
+            qty: -valued.remaining_qty,
+            movement_type: MOVEMENT_WRITE_OFF,
+            reference_type: None,
+            reference_id: None,
+            note: request.note,
+            created_by: actor_id,
+            cost_amount: Some(valued.value_amount),
+            lot: None,
+            lot_id: Some(id),
+            restores: None,
+        }).await?;
+
+        // Written-off stock may have used up the oldest receipt
+        self.product_usecase.revalue_cost(product_id, variant_id, None, actor_id).await?;
+
+        Ok(StockMovementResponse::from(movement))
+    }
+}
\ No newline at end of file
@@ -0,0 +1,3 @@
+pub mod entity;
+pub mod inventory_usecase;
+pub mod inventory_handler;
\ No newline at end of file
@@ -1,5 +1,16 @@
 pub mod product;
 pub mod cashflow;
 pub mod transaction;
+pub mod user;
+pub mod retention;
+pub mod audit;
+pub mod category;
+pub mod report;
+pub mod inventory;
+pub mod modifier;
+pub mod supplier;
+pub mod purchase;
+pub mod stock_count;
+pub mod promotion;
 pub mod repository;
 pub mod app_module;
\ No newline at end of file
@@ -0,0 +1,7 @@
+use axum::Router;
+
+use crate::module::modifier::v1::modifier_handler;
+
+pub fn configure() -> Router {
+	Router::new().nest("/api/v1/modifier-group", modifier_handler::routes())
+}
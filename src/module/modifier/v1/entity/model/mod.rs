@@ -0,0 +1,3 @@
+pub mod modifier_group;
+pub mod modifier;
+pub mod product_modifier_group;
\ No newline at end of file
@@ -0,0 +1,18 @@
+use validator::{Validate};
+use serde::{Serialize, Deserialize};
+
+#[derive(Debug, Serialize, Deserialize, Validate)]
+pub struct CreateModifierGroupRequest {
+    #[validate(length(min = 1, max = 100, message = "Name must be between 1 and 100 characters"))]
+    pub name: String,
+
+    // A required group needs at least one selection
+    pub required: Option<bool>,
+
+    #[validate(range(min = 0, message = "min_select must be non-negative"))]
+    pub min_select: Option<i32>,
+
+    // Omitted means no upper limit
+    #[validate(range(min = 1, message = "max_select must be positive"))]
+    pub max_select: Option<i32>,
+}
@@ -2,16 +2,15 @@ use validator::{Validate};
 use serde::{Serialize, Deserialize};
 
 #[derive(Debug, Serialize, Deserialize, Validate)]
-pub struct UpdateTransactionRequest {
-    #[validate(range(min = 1, message = "Product ID must be positive"))]
-    pub product_id: Option<i32>,
+pub struct CreateModifierRequest {
+    #[validate(length(min = 1, max = 100, message = "Name must be between 1 and 100 characters"))]
+    pub name: String,
 
+    // Extra cost per unit, defaults to 0
     #[validate(range(min = 0, message = "HPP amount must be non-negative"))]
     pub hpp_amount: Option<i32>,
 
+    // Extra price per unit, defaults to 0
     #[validate(range(min = 0, message = "Selling amount must be non-negative"))]
     pub selling_amount: Option<i32>,
-
-    #[validate(range(min = 1, message = "Quantity must be positive"))]
-    pub qty: Option<i32>,
 }
@@ -0,0 +1,5 @@
+pub mod create_modifier_group_request;
+pub mod update_modifier_group_request;
+pub mod create_modifier_request;
+pub mod update_modifier_request;
+pub mod set_product_modifier_groups_request;
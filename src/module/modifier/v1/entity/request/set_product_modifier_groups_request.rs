@@ -0,0 +1,8 @@
+use validator::{Validate};
+use serde::{Serialize, Deserialize};
+
+#[derive(Debug, Serialize, Deserialize, Validate)]
+pub struct SetProductModifierGroupsRequest {
+    // Replaces the groups offered on the product, empty removes all
+    pub group_ids: Vec<i32>,
+}
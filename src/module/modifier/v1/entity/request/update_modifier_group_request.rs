@@ -0,0 +1,19 @@
+use validator::{Validate};
+use serde::{Serialize, Deserialize};
+
+use crate::pkg::helper::deserialize_some;
+
+#[derive(Debug, Serialize, Deserialize, Validate)]
+pub struct UpdateModifierGroupRequest {
+    #[validate(length(min = 1, max = 100, message = "Name must be between 1 and 100 characters"))]
+    pub name: Option<String>,
+
+    pub required: Option<bool>,
+
+    #[validate(range(min = 0, message = "min_select must be non-negative"))]
+    pub min_select: Option<i32>,
+
+    // Omitted keeps the limit, null removes it
+    #[serde(default, deserialize_with = "deserialize_some")]
+    pub max_select: Option<Option<i32>>,
+}
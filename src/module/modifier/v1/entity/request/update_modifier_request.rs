@@ -0,0 +1,14 @@
+use validator::{Validate};
+use serde::{Serialize, Deserialize};
+
+#[derive(Debug, Serialize, Deserialize, Validate)]
+pub struct UpdateModifierRequest {
+    #[validate(length(min = 1, max = 100, message = "Name must be between 1 and 100 characters"))]
+    pub name: Option<String>,
+
+    #[validate(range(min = 0, message = "HPP amount must be non-negative"))]
+    pub hpp_amount: Option<i32>,
+
+    #[validate(range(min = 0, message = "Selling amount must be non-negative"))]
+    pub selling_amount: Option<i32>,
+}
@@ -0,0 +1 @@
+pub mod modifier_group_response;
@@ -0,0 +1,54 @@
+use serde::{Serialize, Deserialize};
+use chrono::{Utc, DateTime};
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ModifierResponse {
+    pub id: i32,
+    pub group_id: i32,
+    pub name: String,
+    pub hpp_amount: i32,
+    pub selling_amount: i32,
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl From<crate::module::modifier::v1::entity::model::modifier::Model> for ModifierResponse {
+    fn from(model: crate::module::modifier::v1::entity::model::modifier::Model) -> Self {
+        Self {
+            id: model.id,
+            group_id: model.group_id,
+            name: model.name,
+            hpp_amount: model.hpp_amount,
+            selling_amount: model.selling_amount,
+            created_at: model.created_at,
+            updated_at: model.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ModifierGroupResponse {
+    pub id: i32,
+    pub name: String,
+    pub required: bool,
+    pub min_select: i32,
+    pub max_select: Option<i32>,
+    pub modifiers: Vec<ModifierResponse>,
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl From<crate::module::modifier::v1::entity::model::modifier_group::Model> for ModifierGroupResponse {
+    fn from(model: crate::module::modifier::v1::entity::model::modifier_group::Model) -> Self {
+        Self {
+            id: model.id,
+            name: model.name,
+            required: model.required,
+            min_select: model.min_select,
+            max_select: model.max_select,
+            modifiers: Vec::new(),
+            created_at: model.created_at,
+            updated_at: model.updated_at,
+        }
+    }
+}
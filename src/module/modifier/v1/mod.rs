@@ -0,0 +1,3 @@
+pub mod entity;
+pub mod modifier_usecase;
+pub mod modifier_handler;
\ No newline at end of file
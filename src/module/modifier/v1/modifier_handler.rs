@@ -0,0 +1,157 @@
+use axum::{
+	extract::{Extension, Path}, 
+    http::StatusCode, 
+    response::IntoResponse, 
+    routing::{get, post, put, delete}, 
+    Json, 
+    Router
+};
+use serde_json::json;
+use validator::Validate;
+use std::sync::Arc;
+
+use crate::module::modifier::v1::entity::{
+    request::{
+        create_modifier_group_request::CreateModifierGroupRequest,
+        update_modifier_group_request::UpdateModifierGroupRequest,
+        create_modifier_request::CreateModifierRequest,
+        update_modifier_request::UpdateModifierRequest,
+    },
+};
+use crate::pkg::auth::AuthUser;
+use crate::pkg::custom_error::AppError;
+use crate::pkg::response::ApiResponse;
+use crate::di::AppModule;
+
+
+pub fn routes() -> Router {
+	Router::new()
+		.route("/", post(create_group))
+		.route("/", get(list_groups))
+		.route("/:id", get(get_group))
+		.route("/:id", put(update_group))
+		.route("/:id", delete(delete_group))
+		.route("/:id/modifiers", post(create_modifier))
+		.route("/:id/modifiers/:modifier_id", put(update_modifier))
+		.route("/:id/modifiers/:modifier_id", delete(delete_modifier))
+}
+
+async fn create_group(
+    Extension(app_module): Extension<Arc<AppModule>>,
+    auth_user: Option<AuthUser>,
+    Json(payload): Json<CreateModifierGroupRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    payload.validate().map_err(|e| {
+        AppError::BadRequest(e.to_string())
+    })?;
+
+    let group = app_module.modifier_usecase.create_group(payload, auth_user.map(|user| user.id)).await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(ApiResponse::success("Modifier group created successfully", Some(json!(group)), None)),
+    ))
+}
+
+async fn get_group(
+    Extension(app_module): Extension<Arc<AppModule>>,
+    Path(id): Path<i32>,
+) -> Result<impl IntoResponse, AppError> {
+    let group = app_module.modifier_usecase.get_group(id).await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse::success("Modifier group retrieved successfully", Some(json!(group)), None)),
+    ))
+}
+
+async fn list_groups(
+    Extension(app_module): Extension<Arc<AppModule>>,
+) -> Result<impl IntoResponse, AppError> {
+    let groups = app_module.modifier_usecase.list_groups().await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse::success("Modifier groups retrieved successfully", Some(json!(groups)), None)),
+    ))
+}
+
+async fn update_group(
+    Extension(app_module): Extension<Arc<AppModule>>,
+    auth_user: Option<AuthUser>,
+    Path(id): Path<i32>,
+    Json(payload): Json<UpdateModifierGroupRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    payload.validate().map_err(|e| {
+        AppError::BadRequest(e.to_string())
+    })?;
+
+    let group = app_module.modifier_usecase.update_group(id, payload, auth_user.map(|user| user.id)).await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse::success("Modifier group updated successfully", Some(json!(group)), None)),
+    ))
+}
+
+async fn delete_group(
+    Extension(app_module): Extension<Arc<AppModule>>,
+    auth_user: Option<AuthUser>,
+    Path(id): Path<i32>,
+) -> Result<impl IntoResponse, AppError> {
+    app_module.modifier_usecase.delete_group(id, auth_user.map(|user| user.id)).await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse::success("Modifier group deleted successfully", Some(json!({})), None)),
+    ))
+}
+
+async fn create_modifier(
+    Extension(app_module): Extension<Arc<AppModule>>,
+    auth_user: Option<AuthUser>,
+    Path(id): Path<i32>,
+    Json(payload): Json<CreateModifierRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    payload.validate().map_err(|e| {
+        AppError::BadRequest(e.to_string())
+    })?;
+
+    let modifier = app_module.modifier_usecase.create_modifier(id, payload, auth_user.map(|user| user.id)).await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(ApiResponse::success("Modifier created successfully", Some(json!(modifier)), None)),
+    ))
+}
+
+async fn update_modifier(
+    Extension(app_module): Extension<Arc<AppModule>>,
+    auth_user: Option<AuthUser>,
+    Path((id, modifier_id)): Path<(i32, i32)>,
+    Json(payload): Json<UpdateModifierRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    payload.validate().map_err(|e| {
+        AppError::BadRequest(e.to_string())
+    })?;
+
+    let modifier = app_module.modifier_usecase.update_modifier(id, modifier_id, payload, auth_user.map(|user| user.id)).await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse::success("Modifier updated successfully", Some(json!(modifier)), None)),
+    ))
+}
+
+async fn delete_modifier(
+    Extension(app_module): Extension<Arc<AppModule>>,
+    auth_user: Option<AuthUser>,
+    Path((id, modifier_id)): Path<(i32, i32)>,
+) -> Result<impl IntoResponse, AppError> {
+    app_module.modifier_usecase.delete_modifier(id, modifier_id, auth_user.map(|user| user.id)).await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse::success("Modifier deleted successfully", Some(json!({})), None)),
+    ))
+}
\ No newline at end of file
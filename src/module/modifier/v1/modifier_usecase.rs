@@ -0,0 +1,353 @@
+use async_trait::async_trait;
+use crate::module::modifier::v1::entity::{
+    request::{
+        create_modifier_group_request::CreateModifierGroupRequest,
+        update_modifier_group_request::UpdateModifierGroupRequest,
+        create_modifier_request::CreateModifierRequest,
+        update_modifier_request::UpdateModifierRequest,
+        set_product_modifier_groups_request::SetProductModifierGroupsRequest,
+    },
+    response::modifier_group_response::{ModifierGroupResponse, ModifierResponse},
+    model::modifier_group::{ActiveModel, Model as ModifierGroupModel},
+    model::modifier::{ActiveModel as ModifierActiveModel, Model as ModifierModel},
+};
+use crate::module::repository::modifier_group_repository::ModifierGroupRepository;
+use crate::module::repository::modifier_repository::ModifierRepository;
+use crate::module::repository::product_repository::ProductRepository;
+use crate::module::repository::audit_log_repository::{AuditLogRepository, NewAuditLog};
+use crate::pkg::audit::{ENTITY_MODIFIER_GROUP, ENTITY_MODIFIER, ENTITY_PRODUCT, ACTION_CREATE, ACTION_UPDATE, ACTION_DELETE};
+use crate::pkg::custom_error::AppError;
+use sea_orm::Set;
+use serde_json::json;
+use std::sync::Arc;
+use chrono::Utc;
+
+#[async_trait]
+pub trait ModifierUsecase: Send + Sync {
+    async fn create_group(&self, request: CreateModifierGroupRequest, actor_id: Option<i32>) -> Result<ModifierGroupResponse, AppError>;
+    async fn get_group(&self, id: i32) -> Result<ModifierGroupResponse, AppError>;
+    async fn list_groups(&self) -> Result<Vec<ModifierGroupResponse>, AppError>;
+    async fn update_group(&self, id: i32, request: UpdateModifierGroupRequest, actor_id: Option<i32>) -> Result<ModifierGroupResponse, AppError>;
+    async fn delete_group(&self, id: i32, actor_id: Option<i32>) -> Result<(), AppError>;
+    async fn create_modifier(&self, group_id: i32, request: CreateModifierRequest, actor_id: Option<i32>) -> Result<ModifierResponse, AppError>;
+    async fn update_modifier(&self, group_id: i32, id: i32, request: UpdateModifierRequest, actor_id: Option<i32>) -> Result<ModifierResponse, AppError>;
+    async fn delete_modifier(&self, group_id: i32, id: i32, actor_id: Option<i32>) -> Result<(), AppError>;
+    async fn list_product_groups(&self, product_id: i32) -> Result<Vec<ModifierGroupResponse>, AppError>;
+    async fn set_product_groups(&self, product_id: i32, request: SetProductModifierGroupsRequest, actor_id: Option<i32>) -> Result<Vec<ModifierGroupResponse>, AppError>;
+}
+
+pub struct ModifierUsecaseImpl {
+    modifier_group_repository: Arc<dyn ModifierGroupRepository>,
+    modifier_repository: Arc<dyn ModifierRepository>,
+    product_repository: Arc<dyn ProductRepository>,
+    audit_log_repository: Arc<dyn AuditLogRepository>,
+}
+
+impl ModifierUsecaseImpl {
+    pub fn new(
+        modifier_group_repository: Arc<dyn ModifierGroupRepository>,
+        modifier_repository: Arc<dyn ModifierRepository>,
+        product_repository: Arc<dyn ProductRepository>,
+        audit_log_repository: Arc<dyn AuditLogRepository>,
+    ) -> Self {
+        Self {
+            modifier_group_repository,
+            modifier_repository,
+            product_repository,
+            audit_log_repository,
+        }
+    }
+
+    fn check_limits(required: bool, min_select: i32, max_select: Option<i32>) -> Result<(), AppError> {
+        let min_select = if required { min_select.max(1) } else { min_select };
+        if let Some(max_select) = max_select {
+            if max_select < min_select {
+                return Err(AppError::BadRequest("max_select must not be less than min_select".to_string()));
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn find_group(&self, id: i32) -> Result<ModifierGroupModel, AppError> {
+        self.modifier_group_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Modifier group with id {} not found", id)))
+    }
+
+    async fn find_modifier(&self, group_id: i32, id: i32) -> Result<ModifierModel, AppError> {
+        self.modifier_repository
+            .find_by_id(id)
+            .await?
+            .filter(|modifier| modifier.group_id == group_id)
+            .ok_or_else(|| AppError::NotFound(format!("Modifier with id {} not found", id)))
+    }
+
+    /// Groups with their live modifiers nested
+    async fn with_modifiers(&self, groups: Vec<ModifierGroupModel>) -> Result<Vec<ModifierGroupResponse>, AppError> {
+        let group_ids: Vec<i32> = groups.iter().map(|group| group.id).collect();
+        let modifiers = self.modifier_repository.find_by_groups(&group_ids).await?;
+
+        Ok(groups
+            .into_iter()
+            .map(|group| {
+                let mut response = ModifierGroupResponse::from(group);
+                response.modifiers = modifiers
+                    .iter()
+                    .filter(|modifier| modifier.group_id == response.id)
+                    .cloned()
+                    .map(ModifierResponse::from)
+                    .collect();
+                response
+            })
+            .collect())
+    }
+}
+
+#[async_trait]
+impl ModifierUsecase for ModifierUsecaseImpl {
+    async fn create_group(&self, request: CreateModifierGroupRequest, actor_id: Option<i32>) -> Result<ModifierGroupResponse, AppError> {
+        if self.modifier_group_repository.find_by_name(&request.name).await?.is_some() {
+            return Err(AppError::BadRequest("Modifier group with this name already exists".to_string()));
+        }
+
+        let required = request.required.unwrap_or(false);
+        let min_select = request.min_select.unwrap_or(0);
+        Self::check_limits(required, min_select, request.max_select)?;
+
+        let group_active_model = ActiveModel {
+            name: Set(request.name),
+            required: Set(required),
+            min_select: Set(min_select),
+            max_select: Set(request.max_select),
+            created_at: Set(Some(Utc::now())),
+            updated_at: Set(Some(Utc::now())),
+            deleted_at: Set(None),
+            ..Default::default()
+        };
+
+        let created_group = self.modifier_group_repository.create(group_active_model).await?;
+
+        self.audit_log_repository.record(NewAuditLog {
+            actor_id,
+            entity: ENTITY_MODIFIER_GROUP,
+            entity_id: created_group.id,
+            action: ACTION_CREATE,
+            before: None,
+            after: Some(json!(created_group)),
+        }).await?;
+
+        Ok(ModifierGroupResponse::from(created_group))
+    }
+
+    async fn get_group(&self, id: i32) -> Result<ModifierGroupResponse, AppError> {
+        let group = self.find_group(id).await?;
+
+        let mut groups = self.with_modifiers(vec![group]).await?;
+        Ok(groups.remove(0))
+    }
+
+    async fn list_groups(&self) -> Result<Vec<ModifierGroupResponse>, AppError> {
+        let groups = self.modifier_group_repository.find_all().await?;
+        self.with_modifiers(groups).await
+    }
+
+    async fn update_group(&self, id: i32, request: UpdateModifierGroupRequest, actor_id: Option<i32>) -> Result<ModifierGroupResponse, AppError> {
+        let existing_group = self.find_group(id).await?;
+
+        if let Some(new_name) = &request.name {
+            if new_name != &existing_group.name && self.modifier_group_repository.find_by_name(new_name).await?.is_some() {
+                return Err(AppError::BadRequest("Modifier group with this name already exists".to_string()));
+            }
+        }
+
+        Self::check_limits(
+            request.required.unwrap_or(existing_group.required),
+            request.min_select.unwrap_or(existing_group.min_select),
+            request.max_select.unwrap_or(existing_group.max_select),
+        )?;
+
+        let mut group_active_model = ActiveModel {
+            id: Set(id),
+            ..Default::default()
+        };
+
+        if let Some(name) = request.name {
+            group_active_model.name = Set(name);
+        }
+        if let Some(required) = request.required {
+            group_active_model.required = Set(required);
+        }
+        if let Some(min_select) = request.min_select {
+            group_active_model.min_select = Set(min_select);
+        }
+        if let Some(max_select) = request.max_select {
+            group_active_model.max_select = Set(max_select);
+        }
+
+        let updated_group = self.modifier_group_repository.update(id, group_active_model).await?;
+
+        self.audit_log_repository.record(NewAuditLog {
+            actor_id,
+            entity: ENTITY_MODIFIER_GROUP,
+            entity_id: id,
+            action: ACTION_UPDATE,
+            before: Some(json!(existing_group)),
+            after: Some(json!(updated_group)),
+        }).await?;
+
+        let mut groups = self.with_modifiers(vec![updated_group]).await?;
+        Ok(groups.remove(0))
+    }
+
+    async fn delete_group(&self, id: i32, actor_id: Option<i32>) -> Result<(), AppError> {
+        let existing_group = self.find_group(id).await?;
+
+        // Soft delete, products stop offering it and past sales keep their snapshots
+        let deleted_group = self.modifier_group_repository.delete(id).await?;
+
+        self.audit_log_repository.record(NewAuditLog {
+            actor_id,
+            entity: ENTITY_MODIFIER_GROUP,
+            entity_id: id,
+            action: ACTION_DELETE,
+            before: Some(json!(existing_group)),
+            after: Some(json!(deleted_group)),
+        }).await?;
+
+        Ok(())
+    }
+
+    async fn create_modifier(&self, group_id: i32, request: CreateModifierRequest, actor_id: Option<i32>) -> Result<ModifierResponse, AppError> {
+        self.find_group(group_id).await?;
+
+        if self.modifier_repository.find_by_name(group_id, &request.name).await?.is_some() {
+            return Err(AppError::BadRequest("Modifier with this name already exists".to_string()));
+        }
+
+        let modifier_active_model = ModifierActiveModel {
+            group_id: Set(group_id),
+            name: Set(request.name),
+            hpp_amount: Set(request.hpp_amount.unwrap_or(0)),
+            selling_amount: Set(request.selling_amount.unwrap_or(0)),
+            created_at: Set(Some(Utc::now())),
+            updated_at: Set(Some(Utc::now())),
+            deleted_at: Set(None),
+            ..Default::default()
+        };
+
+        let created_modifier = self.modifier_repository.create(modifier_active_model).await?;
+
+        self.audit_log_repository.record(NewAuditLog {
+            actor_id,
+            entity: ENTITY_MODIFIER,
+            entity_id: created_modifier.id,
+            action: ACTION_CREATE,
+            before: None,
+            after: Some(json!(created_modifier)),
+        }).await?;
+
+        Ok(ModifierResponse::from(created_modifier))
+    }
+
+    async fn update_modifier(&self, group_id: i32, id: i32, request: UpdateModifierRequest, actor_id: Option<i32>) -> Result<ModifierResponse, AppError> {
+        let existing_modifier = self.find_modifier(group_id, id).await?;
+
+        if let Some(new_name) = &request.name {
+            if new_name != &existing_modifier.name && self.modifier_repository.find_by_name(group_id, new_name).await?.is_some() {
+                return Err(AppError::BadRequest("Modifier with this name already exists".to_string()));
+            }
+        }
+
+        // Prices only apply to new sales, sold lines keep their snapshot
+        let mut modifier_active_model = ModifierActiveModel {
+            id: Set(id),
+            ..Default::default()
+        };
+
+        if let Some(name) = request.name {
+            modifier_active_model.name = Set(name);
+        }
+        if let Some(hpp_amount) = request.hpp_amount {
+            modifier_active_model.hpp_amount = Set(hpp_amount);
+        }
+        if let Some(selling_amount) = request.selling_amount {
+            modifier_active_model.selling_amount = Set(selling_amount);
+        }
+
+        let updated_modifier = self.modifier_repository.update(id, modifier_active_model).await?;
+
+        self.audit_log_repository.record(NewAuditLog {
+            actor_id,
+            entity: ENTITY_MODIFIER,
+            entity_id: id,
+            action: ACTION_UPDATE,
+            before: Some(json!(existing_modifier)),
+            after: Some(json!(updated_modifier)),
+        }).await?;
+
+        Ok(ModifierResponse::from(updated_modifier))
+    }
+
+    async fn delete_modifier(&self, group_id: i32, id: i32, actor_id: Option<i32>) -> Result<(), AppError> {
+        let existing_modifier = self.find_modifier(group_id, id).await?;
+
+        let deleted_modifier = self.modifier_repository.delete(id).await?;
+
+        self.audit_log_repository.record(NewAuditLog {
+            actor_id,
+            entity: ENTITY_MODIFIER,
+            entity_id: id,
+            action: ACTION_DELETE,
+            before: Some(json!(existing_modifier)),
+            after: Some(json!(deleted_modifier)),
+        }).await?;
+
+        Ok(())
+    }
+
+    async fn list_product_groups(&self, product_id: i32) -> Result<Vec<ModifierGroupResponse>, AppError> {
+        // Check if product exists
+        self.product_repository
+            .find_by_id(product_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Product with id {} not found", product_id)))?;
+
+        let groups = self.modifier_group_repository.find_by_product(product_id).await?;
+        self.with_modifiers(groups).await
+    }
+
+    async fn set_product_groups(&self, product_id: i32, request: SetProductModifierGroupsRequest, actor_id: Option<i32>) -> Result<Vec<ModifierGroupResponse>, AppError> {
+        // Check if product exists
+        self.product_repository
+            .find_by_id(product_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Product with id {} not found", product_id)))?;
+
+        let mut group_ids = request.group_ids;
+        group_ids.sort_unstable();
+        group_ids.dedup();
+
+        let groups = self.modifier_group_repository.find_by_ids(&group_ids).await?;
+        if let Some(missing_id) = group_ids.iter().find(|id| !groups.iter().any(|group| group.id == **id)) {
+            return Err(AppError::BadRequest(format!("Modifier group with id {} not found", missing_id)));
+        }
+
+        let existing_groups = self.modifier_group_repository.find_by_product(product_id).await?;
+        let existing_ids: Vec<i32> = existing_groups.iter().map(|group| group.id).collect();
+
+        self.modifier_group_repository.set_for_product(product_id, &group_ids).await?;
+
+        self.audit_log_repository.record(NewAuditLog {
+            actor_id,
+            entity: ENTITY_PRODUCT,
+            entity_id: product_id,
+            action: ACTION_UPDATE,
+            before: Some(json!({ "modifier_group_ids": existing_ids })),
+            after: Some(json!({ "modifier_group_ids": group_ids })),
+        }).await?;
+
+        self.with_modifiers(groups).await
+    }
+}
\ No newline at end of file
@@ -1,2 +1,3 @@
 pub mod product_module;
+pub mod price_job;
 pub mod v1;
\ No newline at end of file
@@ -0,0 +1,25 @@
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::log::{error, info};
+
+use crate::module::product::v1::product_usecase::ProductUsecase;
+
+/// How often scheduled price changes are checked
+const INTERVAL: Duration = Duration::from_secs(60);
+
+/// Apply scheduled price changes once their `effective_from` has passed
+pub fn spawn(product_usecase: Arc<dyn ProductUsecase>) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(INTERVAL);
+
+        loop {
+            interval.tick().await;
+
+            match product_usecase.apply_scheduled_prices().await {
+                Ok(0) => {}
+                Ok(applied) => info!("Applied {} scheduled price change(s)", applied),
+                Err(err) => error!("Applying scheduled prices failed: {:?}", err),
+            }
+        }
+    });
+}
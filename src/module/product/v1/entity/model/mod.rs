@@ -1 +1,7 @@
-pub mod product;
\ No newline at end of file
+pub mod product;
+pub mod product_price;
+pub mod product_barcode;
+pub mod product_variant;
+pub mod recipe_item;
+pub mod bundle_item;
+pub mod product_unit;
\ No newline at end of file
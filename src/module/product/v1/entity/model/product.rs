@@ -8,8 +8,18 @@ pub struct Model {
     #[sea_orm(primary_key, auto_increment = true)]
     pub id: i32,
     pub name: String,
+    pub sku: Option<String>,
     pub hpp_amount: i32,
     pub selling_amount: i32,
+    pub category_id: Option<i32>,
+    // Unit that stock, recipes and HPP are counted in
+    pub base_unit: String,
+    // In `base_unit`; unused once the product has variants, each variant keeps its own
+    pub stock: Decimal,
+    // Low stock threshold in `base_unit`
+    pub reorder_point: Option<Decimal>,
+    // Preferred supplier, whose lead time drives reorder suggestions
+    pub supplier_id: Option<i32>,
     pub created_at: Option<DateTime<Utc>>,
     pub updated_at: Option<DateTime<Utc>>,
     pub deleted_at: Option<DateTime<Utc>>,
@@ -0,0 +1,31 @@
+use sea_orm::entity::prelude::*;
+use serde::{Serialize, Deserialize};
+use chrono::{Utc, DateTime};
+
+/// A price change of a product. Rows with `applied_at` set are history,
+/// the rest are scheduled and take effect at `effective_from`.
+/// A NULL amount on a scheduled row keeps the price current at that time.
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "product_prices")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = true)]
+    pub id: i32,
+    pub product_id: i32,
+    pub hpp_amount: Option<i32>,
+    pub selling_amount: Option<i32>,
+    pub effective_from: DateTime<Utc>,
+    pub applied_at: Option<DateTime<Utc>>,
+    pub created_by: Option<i32>,
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter)]
+pub enum Relation {}
+
+impl RelationTrait for Relation {
+    fn def(&self) -> RelationDef {
+        unimplemented!()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
\ No newline at end of file
@@ -0,0 +1,32 @@
+use sea_orm::entity::prelude::*;
+use serde::{Serialize, Deserialize};
+use chrono::{Utc, DateTime};
+
+/// Base unit of products created without one
+pub const DEFAULT_BASE_UNIT: &str = "pcs";
+
+/// A unit the product can be sold in, holding `factor` of its base unit (1 sack = 25 kg)
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "product_units")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = true)]
+    pub id: i32,
+    pub product_id: i32,
+    pub name: String,
+    pub factor: Decimal,
+    // Own price per unit; otherwise the base price times `factor`
+    pub selling_amount: Option<i32>,
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter)]
+pub enum Relation {}
+
+impl RelationTrait for Relation {
+    fn def(&self) -> RelationDef {
+        unimplemented!()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
\ No newline at end of file
@@ -1,14 +1,38 @@
+use rust_decimal::Decimal;
 use validator::{Validate};
 use serde::{Serialize, Deserialize};
 
+use crate::pkg::quantity::validate_non_negative_qty;
+
 #[derive(Debug, Serialize, Deserialize, Validate)]
 pub struct CreateProductRequest {
     #[validate(length(min = 1, max = 255, message = "Name must be between 1 and 255 characters"))]
     pub name: String,
 
+    #[validate(length(min = 1, max = 64, message = "SKU must be between 1 and 64 characters"))]
+    pub sku: Option<String>,
+
+    // EAN-13, UPC-A or EAN-8
+    #[validate(length(max = 20, message = "At most 20 barcodes per product"))]
+    pub barcodes: Option<Vec<String>>,
+
     #[validate(range(min = 0, message = "HPP amount must be non-negative"))]
     pub hpp_amount: i32,
 
     #[validate(range(min = 0, message = "Selling amount must be non-negative"))]
     pub selling_amount: i32,
+
+    #[validate(range(min = 1, message = "Category ID must be positive"))]
+    pub category_id: Option<i32>,
+
+    // Unit stock is counted in, defaults to pcs
+    #[validate(length(min = 1, max = 20, message = "Base unit must be between 1 and 20 characters"))]
+    pub base_unit: Option<String>,
+
+    // Stock in the base unit at or below which the product is reported as low
+    #[validate(custom = "validate_non_negative_qty")]
+    pub reorder_point: Option<Decimal>,
+
+    #[validate(range(min = 1, message = "Supplier ID must be positive"))]
+    pub supplier_id: Option<i32>,
 }
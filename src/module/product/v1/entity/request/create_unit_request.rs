@@ -0,0 +1,19 @@
+use rust_decimal::Decimal;
+use validator::{Validate};
+use serde::{Serialize, Deserialize};
+
+use crate::pkg::quantity::validate_factor;
+
+#[derive(Debug, Serialize, Deserialize, Validate)]
+pub struct CreateUnitRequest {
+    #[validate(length(min = 1, max = 20, message = "Name must be between 1 and 20 characters"))]
+    pub name: String,
+
+    // How many of the product's base unit one of this unit holds
+    #[validate(custom = "validate_factor")]
+    pub factor: Decimal,
+
+    // Defaults to the base selling price times factor
+    #[validate(range(min = 0, message = "Selling amount must be non-negative"))]
+    pub selling_amount: Option<i32>,
+}
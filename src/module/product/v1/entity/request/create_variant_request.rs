@@ -0,0 +1,25 @@
+use rust_decimal::Decimal;
+use validator::{Validate};
+use serde::{Serialize, Deserialize};
+
+use crate::pkg::quantity::validate_non_negative_qty;
+
+#[derive(Debug, Serialize, Deserialize, Validate)]
+pub struct CreateVariantRequest {
+    #[validate(length(min = 1, max = 100, message = "Name must be between 1 and 100 characters"))]
+    pub name: String,
+
+    #[validate(length(min = 1, max = 64, message = "SKU must be between 1 and 64 characters"))]
+    pub sku: Option<String>,
+
+    // Defaults to the product's prices
+    #[validate(range(min = 0, message = "HPP amount must be non-negative"))]
+    pub hpp_amount: Option<i32>,
+
+    #[validate(range(min = 0, message = "Selling amount must be non-negative"))]
+    pub selling_amount: Option<i32>,
+
+    // Stock in the product's base unit at or below which the variant is reported as low
+    #[validate(custom = "validate_non_negative_qty")]
+    pub reorder_point: Option<Decimal>,
+}
@@ -12,4 +12,10 @@ pub struct GetListProductQuery {
     pub order_by: Option<String>,
 
     pub direction: Option<String>,
+
+    // Comma separated category ids
+    pub category_id: Option<String>,
+
+    // "false" matches only the given categories, not their subcategories
+    pub include_subcategories: Option<String>,
 }
\ No newline at end of file
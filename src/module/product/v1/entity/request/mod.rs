@@ -1,4 +1,11 @@
 pub mod list_product_request;
 pub mod create_product_request;
 pub mod update_product_request;
-pub mod get_product_request;
\ No newline at end of file
+pub mod get_product_request;
+pub mod schedule_price_request;
+pub mod create_variant_request;
+pub mod update_variant_request;
+pub mod set_recipe_request;
+pub mod set_bundle_request;
+pub mod create_unit_request;
+pub mod update_unit_request;
\ No newline at end of file
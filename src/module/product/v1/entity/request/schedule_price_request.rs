@@ -0,0 +1,16 @@
+use validator::{Validate};
+use serde::{Serialize, Deserialize};
+use chrono::{Utc, DateTime};
+
+#[derive(Debug, Serialize, Deserialize, Validate)]
+pub struct SchedulePriceRequest {
+    // Omitted amounts keep the price current when the change takes effect
+    #[validate(range(min = 0, message = "HPP amount must be non-negative"))]
+    pub hpp_amount: Option<i32>,
+
+    #[validate(range(min = 0, message = "Selling amount must be non-negative"))]
+    pub selling_amount: Option<i32>,
+
+    // RFC 3339, must be in the future
+    pub effective_from: DateTime<Utc>,
+}
@@ -0,0 +1,22 @@
+use validator::{Validate};
+use serde::{Serialize, Deserialize};
+
+#[derive(Debug, Serialize, Deserialize, Validate)]
+pub struct SetBundleRequest {
+    // Replaces the components, empty turns the product back into a plain item
+    #[validate]
+    pub items: Vec<BundleItemRequest>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Validate)]
+pub struct BundleItemRequest {
+    #[validate(range(min = 1, message = "Component ID must be positive"))]
+    pub component_id: i32,
+
+    // Required when the component has variants
+    #[validate(range(min = 1, message = "Variant ID must be positive"))]
+    pub variant_id: Option<i32>,
+
+    #[validate(range(min = 1, message = "Quantity must be positive"))]
+    pub qty: i32,
+}
@@ -0,0 +1,25 @@
+use rust_decimal::Decimal;
+use validator::{Validate};
+use serde::{Serialize, Deserialize};
+
+use crate::pkg::quantity::validate_positive_qty;
+
+#[derive(Debug, Serialize, Deserialize, Validate)]
+pub struct SetRecipeRequest {
+    // Replaces the recipe, empty turns the product back into a stocked item
+    #[validate]
+    pub items: Vec<RecipeItemRequest>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Validate)]
+pub struct RecipeItemRequest {
+    #[validate(range(min = 1, message = "Ingredient ID must be positive"))]
+    pub ingredient_id: i32,
+
+    // In `unit`, the ingredient's base unit when omitted
+    #[validate(custom = "validate_positive_qty")]
+    pub qty: Decimal,
+
+    #[validate(length(min = 1, max = 20, message = "Unit must be between 1 and 20 characters"))]
+    pub unit: Option<String>,
+}
@@ -1,14 +1,44 @@
+use rust_decimal::Decimal;
 use validator::{Validate};
 use serde::{Serialize, Deserialize};
 
+use crate::pkg::helper::deserialize_some;
+use crate::pkg::quantity::validate_non_negative_qty;
+
 #[derive(Debug, Serialize, Deserialize, Validate)]
 pub struct UpdateProductRequest {
     #[validate(length(min = 1, max = 255, message = "Name must be between 1 and 255 characters"))]
     pub name: Option<String>,
 
+    // Omitted keeps the SKU, null removes it
+    #[serde(default, deserialize_with = "deserialize_some")]
+    pub sku: Option<Option<String>>,
+
+    // Replaces all barcodes of the product
+    #[validate(length(max = 20, message = "At most 20 barcodes per product"))]
+    pub barcodes: Option<Vec<String>>,
+
     #[validate(range(min = 0, message = "HPP amount must be non-negative"))]
     pub hpp_amount: Option<i32>,
 
     #[validate(range(min = 0, message = "Selling amount must be non-negative"))]
     pub selling_amount: Option<i32>,
+
+    // Omitted keeps the category, null removes it
+    #[serde(default, deserialize_with = "deserialize_some")]
+    pub category_id: Option<Option<i32>>,
+
+    // Renames the base unit; stock and conversion factors are unchanged
+    #[validate(length(min = 1, max = 20, message = "Base unit must be between 1 and 20 characters"))]
+    pub base_unit: Option<String>,
+
+    // Omitted keeps the reorder point, null turns the low stock alert off
+    #[validate(custom = "validate_non_negative_qty")]
+    #[serde(default, deserialize_with = "deserialize_some")]
+    pub reorder_point: Option<Option<Decimal>>,
+
+    // Omitted keeps the supplier, null removes it
+    #[validate(range(min = 1, message = "Supplier ID must be positive"))]
+    #[serde(default, deserialize_with = "deserialize_some")]
+    pub supplier_id: Option<Option<i32>>,
 }
@@ -0,0 +1,19 @@
+use rust_decimal::Decimal;
+use validator::{Validate};
+use serde::{Serialize, Deserialize};
+
+use crate::pkg::helper::deserialize_some;
+use crate::pkg::quantity::validate_factor;
+
+#[derive(Debug, Serialize, Deserialize, Validate)]
+pub struct UpdateUnitRequest {
+    #[validate(length(min = 1, max = 20, message = "Name must be between 1 and 20 characters"))]
+    pub name: Option<String>,
+
+    #[validate(custom = "validate_factor")]
+    pub factor: Option<Decimal>,
+
+    // Omitted keeps the price, null goes back to the base price times factor
+    #[serde(default, deserialize_with = "deserialize_some")]
+    pub selling_amount: Option<Option<i32>>,
+}
@@ -0,0 +1,27 @@
+use rust_decimal::Decimal;
+use validator::{Validate};
+use serde::{Serialize, Deserialize};
+
+use crate::pkg::helper::deserialize_some;
+use crate::pkg::quantity::validate_non_negative_qty;
+
+#[derive(Debug, Serialize, Deserialize, Validate)]
+pub struct UpdateVariantRequest {
+    #[validate(length(min = 1, max = 100, message = "Name must be between 1 and 100 characters"))]
+    pub name: Option<String>,
+
+    // Omitted keeps the SKU, null removes it
+    #[serde(default, deserialize_with = "deserialize_some")]
+    pub sku: Option<Option<String>>,
+
+    #[validate(range(min = 0, message = "HPP amount must be non-negative"))]
+    pub hpp_amount: Option<i32>,
+
+    #[validate(range(min = 0, message = "Selling amount must be non-negative"))]
+    pub selling_amount: Option<i32>,
+
+    // Omitted keeps the reorder point, null turns the low stock alert off
+    #[validate(custom = "validate_non_negative_qty")]
+    #[serde(default, deserialize_with = "deserialize_some")]
+    pub reorder_point: Option<Option<Decimal>>,
+}
@@ -0,0 +1,24 @@
+use serde::{Serialize, Deserialize};
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct BundleItemResponse {
+    pub component_id: i32,
+    pub component_name: String,
+    pub variant_id: Option<i32>,
+    pub qty: i32,
+    // Component prices per unit
+    pub hpp_amount: i32,
+    pub selling_amount: i32,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct BundleResponse {
+    pub product_id: i32,
+    pub items: Vec<BundleItemResponse>,
+    // Computed HPP of one bundle
+    pub hpp_amount: i64,
+    // Components bought separately against the bundle price
+    pub components_selling_amount: i64,
+    pub selling_amount: i32,
+    pub savings_amount: i64,
+}
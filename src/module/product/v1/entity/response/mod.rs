@@ -1,3 +1,7 @@
-pub mod login_response;
 pub mod product_response;
-pub mod list_product_response;
\ No newline at end of file
+pub mod product_price_response;
+pub mod product_variant_response;
+
+pub mod recipe_response;
+pub mod bundle_response;
+pub mod product_unit_response;
\ No newline at end of file
@@ -0,0 +1,31 @@
+use serde::{Serialize, Deserialize};
+use chrono::{Utc, DateTime};
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ProductPriceResponse {
+    pub id: i32,
+    pub product_id: i32,
+    pub hpp_amount: Option<i32>,
+    pub selling_amount: Option<i32>,
+    pub effective_from: DateTime<Utc>,
+    pub applied_at: Option<DateTime<Utc>>,
+    pub scheduled: bool,
+    pub created_by: Option<i32>,
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+impl From<crate::module::product::v1::entity::model::product_price::Model> for ProductPriceResponse {
+    fn from(model: crate::module::product::v1::entity::model::product_price::Model) -> Self {
+        Self {
+            id: model.id,
+            product_id: model.product_id,
+            hpp_amount: model.hpp_amount,
+            selling_amount: model.selling_amount,
+            effective_from: model.effective_from,
+            applied_at: model.applied_at,
+            scheduled: model.applied_at.is_none(),
+            created_by: model.created_by,
+            created_at: model.created_at,
+        }
+    }
+}
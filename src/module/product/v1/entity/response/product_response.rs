@@ -1,3 +1,4 @@
+use rust_decimal::Decimal;
 use serde::{Serialize, Deserialize};
 use chrono::{Utc, DateTime};
 
@@ -5,10 +6,19 @@ use chrono::{Utc, DateTime};
 pub struct ProductResponse {
     pub id: i32,
     pub name: String,
+    pub sku: Option<String>,
+    pub barcodes: Vec<String>,
     pub hpp_amount: i32,
     pub selling_amount: i32,
+    pub category_id: Option<i32>,
+    pub base_unit: String,
+    pub stock: Decimal,
+    pub reorder_point: Option<Decimal>,
+    pub supplier_id: Option<i32>,
     pub created_at: Option<DateTime<Utc>>,
     pub updated_at: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deleted_at: Option<DateTime<Utc>>,
 }
 
 impl From<crate::module::product::v1::entity::model::product::Model> for ProductResponse {
@@ -16,10 +26,18 @@ impl From<crate::module::product::v1::entity::model::product::Model> for Product
         Self {
             id: model.id,
             name: model.name,
+            sku: model.sku,
+            barcodes: Vec::new(),
             hpp_amount: model.hpp_amount,
             selling_amount: model.selling_amount,
+            category_id: model.category_id,
+            base_unit: model.base_unit,
+            stock: model.stock,
+            reorder_point: model.reorder_point,
+            supplier_id: model.supplier_id,
             created_at: model.created_at,
             updated_at: model.updated_at,
+            deleted_at: model.deleted_at,
         }
     }
 }
@@ -0,0 +1,28 @@
+use rust_decimal::Decimal;
+use serde::{Serialize, Deserialize};
+use chrono::{Utc, DateTime};
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ProductUnitResponse {
+    pub id: i32,
+    pub product_id: i32,
+    pub name: String,
+    pub factor: Decimal,
+    pub selling_amount: Option<i32>,
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl From<crate::module::product::v1::entity::model::product_unit::Model> for ProductUnitResponse {
+    fn from(model: crate::module::product::v1::entity::model::product_unit::Model) -> Self {
+        Self {
+            id: model.id,
+            product_id: model.product_id,
+            name: model.name,
+            factor: model.factor,
+            selling_amount: model.selling_amount,
+            created_at: model.created_at,
+            updated_at: model.updated_at,
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use rust_decimal::Decimal;
+use serde::{Serialize, Deserialize};
+use chrono::{Utc, DateTime};
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ProductVariantResponse {
+    pub id: i32,
+    pub product_id: i32,
+    pub name: String,
+    pub sku: Option<String>,
+    pub hpp_amount: i32,
+    pub selling_amount: i32,
+    pub stock: Decimal,
+    pub reorder_point: Option<Decimal>,
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl From<crate::module::product::v1::entity::model::product_variant::Model> for ProductVariantResponse {
+    fn from(model: crate::module::product::v1::entity::model::product_variant::Model) -> Self {
+        Self {
+            id: model.id,
+            product_id: model.product_id,
+            name: model.name,
+            sku: model.sku,
+            hpp_amount: model.hpp_amount,
+            selling_amount: model.selling_amount,
+            stock: model.stock,
+            reorder_point: model.reorder_point,
+            created_at: model.created_at,
+            updated_at: model.updated_at,
+        }
+    }
+}
@@ -0,0 +1,22 @@
+use rust_decimal::Decimal;
+use serde::{Serialize, Deserialize};
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct RecipeItemResponse {
+    pub ingredient_id: i32,
+    pub ingredient_name: String,
+    pub qty: Decimal,
+    pub unit: Option<String>,
+    pub unit_factor: Decimal,
+    // Ingredient HPP per base unit and for `qty`
+    pub unit_hpp_amount: i32,
+    pub hpp_amount: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct RecipeResponse {
+    pub product_id: i32,
+    pub items: Vec<RecipeItemResponse>,
+    // Computed HPP of one unit of the product
+    pub hpp_amount: i64,
+}
@@ -1,5 +1,5 @@
 use axum::{
-	extract::{Extension, Path, Query}, 
+	extract::{Extension, OriginalUri, Path, Query}, 
     http::StatusCode, 
     response::IntoResponse, 
     routing::{get, post, put, delete}, 
@@ -16,8 +16,17 @@ use crate::module::product::v1::entity::{
         update_product_request::UpdateProductRequest,
         get_product_request::GetProductRequest,
         list_product_request::GetListProductQuery,
+        schedule_price_request::SchedulePriceRequest,
+        create_variant_request::CreateVariantRequest,
+        update_variant_request::UpdateVariantRequest,
+        set_recipe_request::SetRecipeRequest,
+        set_bundle_request::SetBundleRequest,
+        create_unit_request::CreateUnitRequest,
+        update_unit_request::UpdateUnitRequest,
     },
 };
+use crate::module::modifier::v1::entity::request::set_product_modifier_groups_request::SetProductModifierGroupsRequest;
+use crate::pkg::auth::{AuthUser, ROLE_OWNER};
 use crate::pkg::custom_error::AppError;
 use crate::pkg::response::ApiResponse;
 use crate::di::AppModule;
@@ -30,17 +39,39 @@ pub fn routes() -> Router {
 		.route("/:id", get(get_product))
 		.route("/:id", put(update_product))
 		.route("/:id", delete(delete_product))
+		.route("/barcode/:code", get(find_by_barcode))
+		.route("/trash", get(list_deleted_products))
+		.route("/trash/:id", delete(purge_product))
+		.route("/trash/:id/restore", post(restore_product))
+		.route("/:id/prices", get(list_price_history))
+		.route("/:id/prices", post(schedule_price))
+		.route("/:id/prices/:price_id", delete(cancel_scheduled_price))
+		.route("/:id/variants", get(list_variants))
+		.route("/:id/variants", post(create_variant))
+		.route("/:id/variants/:variant_id", put(update_variant))
+		.route("/:id/variants/:variant_id", delete(delete_variant))
+		.route("/:id/units", get(list_units))
+		.route("/:id/units", post(create_unit))
+		.route("/:id/units/:unit_id", put(update_unit))
+		.route("/:id/units/:unit_id", delete(delete_unit))
+		.route("/:id/modifier-groups", get(list_modifier_groups))
+		.route("/:id/modifier-groups", put(set_modifier_groups))
+		.route("/:id/recipe", get(get_recipe))
+		.route("/:id/recipe", put(set_recipe))
+		.route("/:id/bundle", get(get_bundle))
+		.route("/:id/bundle", put(set_bundle))
 }
 
 async fn create_product(
     Extension(app_module): Extension<Arc<AppModule>>,
+    auth_user: Option<AuthUser>,
     Json(payload): Json<CreateProductRequest>,
 ) -> Result<impl IntoResponse, AppError> {
     payload.validate().map_err(|e| {
-        AppError::BadRequest(e.to_string().replace("\n", "\n"))
+        AppError::BadRequest(e.to_string())
     })?;
 
-    let product = app_module.product_usecase.create_product(payload).await?;
+    let product = app_module.product_usecase.create_product(payload, auth_user.map(|user| user.id)).await?;
 
     Ok((
         StatusCode::CREATED,
@@ -54,7 +85,7 @@ async fn get_product(
 ) -> Result<impl IntoResponse, AppError> {
     let request = GetProductRequest { id };
     request.validate().map_err(|e| {
-        AppError::BadRequest(e.to_string().replace("\n", "\n"))
+        AppError::BadRequest(e.to_string())
     })?;
 
     let product = app_module.product_usecase.get_product(request).await?;
@@ -67,26 +98,29 @@ async fn get_product(
 
 async fn list_products(
     Extension(app_module): Extension<Arc<AppModule>>,
+    OriginalUri(uri): OriginalUri,
     Query(query): Query<GetListProductQuery>,
 ) -> Result<impl IntoResponse, AppError> {
     let products = app_module.product_usecase.list_products(query).await?;
 
     Ok((
         StatusCode::OK,
-        Json(ApiResponse::success("Products retrieved successfully", Some(json!(products)), None)),
+        products.headers(&uri),
+        Json(ApiResponse::success("Products retrieved successfully", Some(json!(products.data)), Some(json!(products.meta)))),
     ))
 }
 
 async fn update_product(
     Extension(app_module): Extension<Arc<AppModule>>,
+    auth_user: Option<AuthUser>,
     Path(id): Path<i32>,
     Json(payload): Json<UpdateProductRequest>,
 ) -> Result<impl IntoResponse, AppError> {
     payload.validate().map_err(|e| {
-        AppError::BadRequest(e.to_string().replace("\n", "\n"))
+        AppError::BadRequest(e.to_string())
     })?;
 
-    let product = app_module.product_usecase.update_product(id, payload).await?;
+    let product = app_module.product_usecase.update_product(id, payload, auth_user.map(|user| user.id)).await?;
 
     Ok((
         StatusCode::OK,
@@ -96,12 +130,321 @@ async fn update_product(
 
 async fn delete_product(
     Extension(app_module): Extension<Arc<AppModule>>,
+    auth_user: Option<AuthUser>,
     Path(id): Path<i32>,
 ) -> Result<impl IntoResponse, AppError> {
-    app_module.product_usecase.delete_product(id).await?;
+    app_module.product_usecase.delete_product(id, auth_user.map(|user| user.id)).await?;
 
     Ok((
         StatusCode::OK,
         Json(ApiResponse::success("Product deleted successfully", Some(json!({})), None)),
     ))
+}
+
+async fn list_deleted_products(
+    Extension(app_module): Extension<Arc<AppModule>>,
+    OriginalUri(uri): OriginalUri,
+    Query(query): Query<GetListProductQuery>,
+) -> Result<impl IntoResponse, AppError> {
+    let products = app_module.product_usecase.list_deleted_products(query).await?;
+
+    Ok((
+        StatusCode::OK,
+        products.headers(&uri),
+        Json(ApiResponse::success("Deleted products retrieved successfully", Some(json!(products.data)), Some(json!(products.meta)))),
+    ))
+}
+
+async fn restore_product(
+    Extension(app_module): Extension<Arc<AppModule>>,
+    auth_user: Option<AuthUser>,
+    Path(id): Path<i32>,
+) -> Result<impl IntoResponse, AppError> {
+    let product = app_module.product_usecase.restore_product(id, auth_user.map(|user| user.id)).await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse::success("Product restored successfully", Some(json!(product)), None)),
+    ))
+}
+
+async fn purge_product(
+    Extension(app_module): Extension<Arc<AppModule>>,
+    auth_user: AuthUser,
+    Path(id): Path<i32>,
+) -> Result<impl IntoResponse, AppError> {
+    auth_user.require_role(&[ROLE_OWNER])?;
+
+    app_module.product_usecase.purge_product(id, Some(auth_user.id)).await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse::success("Product permanently deleted", Some(json!({})), None)),
+    ))
+}
+
+async fn list_price_history(
+    Extension(app_module): Extension<Arc<AppModule>>,
+    Path(id): Path<i32>,
+) -> Result<impl IntoResponse, AppError> {
+    let prices = app_module.product_usecase.list_price_history(id).await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse::success("Price history retrieved successfully", Some(json!(prices)), None)),
+    ))
+}
+
+async fn schedule_price(
+    Extension(app_module): Extension<Arc<AppModule>>,
+    auth_user: Option<AuthUser>,
+    Path(id): Path<i32>,
+    Json(payload): Json<SchedulePriceRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    payload.validate().map_err(|e| {
+        AppError::BadRequest(e.to_string())
+    })?;
+
+    let price = app_module.product_usecase.schedule_price(id, payload, auth_user.map(|user| user.id)).await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(ApiResponse::success("Price change scheduled successfully", Some(json!(price)), None)),
+    ))
+}
+
+async fn cancel_scheduled_price(
+    Extension(app_module): Extension<Arc<AppModule>>,
+    Path((id, price_id)): Path<(i32, i32)>,
+) -> Result<impl IntoResponse, AppError> {
+    app_module.product_usecase.cancel_scheduled_price(id, price_id).await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse::success("Scheduled price change cancelled", Some(json!({})), None)),
+    ))
+}
+
+async fn find_by_barcode(
+    Extension(app_module): Extension<Arc<AppModule>>,
+    Path(code): Path<String>,
+) -> Result<impl IntoResponse, AppError> {
+    let product = app_module.product_usecase.find_by_barcode(&code).await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse::success("Product retrieved successfully", Some(json!(product)), None)),
+    ))
+}
+
+async fn list_variants(
+    Extension(app_module): Extension<Arc<AppModule>>,
+    Path(id): Path<i32>,
+) -> Result<impl IntoResponse, AppError> {
+    let variants = app_module.product_usecase.list_variants(id).await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse::success("Variants retrieved successfully", Some(json!(variants)), None)),
+    ))
+}
+
+async fn create_variant(
+    Extension(app_module): Extension<Arc<AppModule>>,
+    auth_user: Option<AuthUser>,
+    Path(id): Path<i32>,
+    Json(payload): Json<CreateVariantRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    payload.validate().map_err(|e| {
+        AppError::BadRequest(e.to_string())
+    })?;
+
+    let variant = app_module.product_usecase.create_variant(id, payload, auth_user.map(|user| user.id)).await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(ApiResponse::success("Variant created successfully", Some(json!(variant)), None)),
+    ))
+}
+
+async fn update_variant(
+    Extension(app_module): Extension<Arc<AppModule>>,
+    auth_user: Option<AuthUser>,
+    Path((id, variant_id)): Path<(i32, i32)>,
+    Json(payload): Json<UpdateVariantRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    payload.validate().map_err(|e| {
+        AppError::BadRequest(e.to_string())
+    })?;
+
+    let variant = app_module.product_usecase.update_variant(id, variant_id, payload, auth_user.map(|user| user.id)).await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse::success("Variant updated successfully", Some(json!(variant)), None)),
+    ))
+}
+
+async fn delete_variant(
+    Extension(app_module): Extension<Arc<AppModule>>,
+    auth_user: Option<AuthUser>,
+    Path((id, variant_id)): Path<(i32, i32)>,
+) -> Result<impl IntoResponse, AppError> {
+    app_module.product_usecase.delete_variant(id, variant_id, auth_user.map(|user| user.id)).await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse::success("Variant deleted successfully", Some(json!({})), None)),
+    ))
+}
+
+async fn list_units(
+    Extension(app_module): Extension<Arc<AppModule>>,
+    Path(id): Path<i32>,
+) -> Result<impl IntoResponse, AppError> {
+    let units = app_module.product_usecase.list_units(id).await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse::success("Units retrieved successfully", Some(json!(units)), None)),
+    ))
+}
+
+async fn create_unit(
+    Extension(app_module): Extension<Arc<AppModule>>,
+    auth_user: Option<AuthUser>,
+    Path(id): Path<i32>,
+    Json(payload): Json<CreateUnitRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    payload.validate().map_err(|e| {
+        AppError::BadRequest(e.to_string())
+    })?;
+
+    let unit = app_module.product_usecase.create_unit(id, payload, auth_user.map(|user| user.id)).await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(ApiResponse::success("Unit created successfully", Some(json!(unit)), None)),
+    ))
+}
+
+async fn update_unit(
+    Extension(app_module): Extension<Arc<AppModule>>,
+    auth_user: Option<AuthUser>,
+    Path((id, unit_id)): Path<(i32, i32)>,
+    Json(payload): Json<UpdateUnitRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    payload.validate().map_err(|e| {
+        AppError::BadRequest(e.to_string())
+    })?;
+
+    let unit = app_module.product_usecase.update_unit(id, unit_id, payload, auth_user.map(|user| user.id)).await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse::success("Unit updated successfully", Some(json!(unit)), None)),
+    ))
+}
+
+async fn delete_unit(
+    Extension(app_module): Extension<Arc<AppModule>>,
+    auth_user: Option<AuthUser>,
+    Path((id, unit_id)): Path<(i32, i32)>,
+) -> Result<impl IntoResponse, AppError> {
+    app_module.product_usecase.delete_unit(id, unit_id, auth_user.map(|user| user.id)).await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse::success("Unit deleted successfully", Some(json!({})), None)),
+    ))
+}
+
+async fn list_modifier_groups(
+    Extension(app_module): Extension<Arc<AppModule>>,
+    Path(id): Path<i32>,
+) -> Result<impl IntoResponse, AppError> {
+    let groups = app_module.modifier_usecase.list_product_groups(id).await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse::success("Modifier groups retrieved successfully", Some(json!(groups)), None)),
+    ))
+}
+
+async fn set_modifier_groups(
+    Extension(app_module): Extension<Arc<AppModule>>,
+    auth_user: Option<AuthUser>,
+    Path(id): Path<i32>,
+    Json(payload): Json<SetProductModifierGroupsRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    payload.validate().map_err(|e| {
+        AppError::BadRequest(e.to_string())
+    })?;
+
+    let groups = app_module.modifier_usecase.set_product_groups(id, payload, auth_user.map(|user| user.id)).await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse::success("Modifier groups updated successfully", Some(json!(groups)), None)),
+    ))
+}
+
+async fn get_recipe(
+    Extension(app_module): Extension<Arc<AppModule>>,
+    Path(id): Path<i32>,
+) -> Result<impl IntoResponse, AppError> {
+    let recipe = app_module.product_usecase.get_recipe(id).await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse::success("Recipe retrieved successfully", Some(json!(recipe)), None)),
+    ))
+}
+
+async fn set_recipe(
+    Extension(app_module): Extension<Arc<AppModule>>,
+    auth_user: Option<AuthUser>,
+    Path(id): Path<i32>,
+    Json(payload): Json<SetRecipeRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    payload.validate().map_err(|e| {
+        AppError::BadRequest(e.to_string())
+    })?;
+
+    let recipe = app_module.product_usecase.set_recipe(id, payload, auth_user.map(|user| user.id)).await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse::success("Recipe updated successfully", Some(json!(recipe)), None)),
+    ))
+}
+
+async fn get_bundle(
+    Extension(app_module): Extension<Arc<AppModule>>,
+    Path(id): Path<i32>,
+) -> Result<impl IntoResponse, AppError> {
+    let bundle = app_module.product_usecase.get_bundle(id).await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse::success("Bundle retrieved successfully", Some(json!(bundle)), None)),
+    ))
+}
+
+async fn set_bundle(
+    Extension(app_module): Extension<Arc<AppModule>>,
+    auth_user: Option<AuthUser>,
+    Path(id): Path<i32>,
+    Json(payload): Json<SetBundleRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    payload.validate().map_err(|e| {
+        AppError::BadRequest(e.to_string())
+    })?;
+
+    let bundle = app_module.product_usecase.set_bundle(id, payload, auth_user.map(|user| user.id)).await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse::success("Bundle updated successfully", Some(json!(bundle)), None)),
+    ))
 }
\ No newline at end of file
@@ -5,52 +5,454 @@ use crate::module::product::v1::entity::{
         update_product_request::UpdateProductRequest,
         get_product_request::GetProductRequest,
         list_product_request::GetListProductQuery,
+        schedule_price_request::SchedulePriceRequest,
+        create_variant_request::CreateVariantRequest,
+        update_variant_request::UpdateVariantRequest,
+        set_recipe_request::SetRecipeRequest,
+        set_bundle_request::SetBundleRequest,
+        create_unit_request::CreateUnitRequest,
+        update_unit_request::UpdateUnitRequest,
     },
     response::{
         product_response::ProductResponse,
-        list_product_response::ListProductResponse,
+        product_price_response::ProductPriceResponse,
+        product_variant_response::ProductVariantResponse,
+        recipe_response::{RecipeResponse, RecipeItemResponse},
+        bundle_response::{BundleResponse, BundleItemResponse},
+        product_unit_response::ProductUnitResponse,
     },
-    model::product::ActiveModel,
+    model::product::{ActiveModel, Model as ProductModel},
+    model::product_price::ActiveModel as ProductPriceActiveModel,
+    model::product_variant::{ActiveModel as ProductVariantActiveModel, Model as ProductVariantModel},
+    model::recipe_item::Model as RecipeItemModel,
+    model::bundle_item::Model as BundleItemModel,
+    model::product_unit::{ActiveModel as ProductUnitActiveModel, Model as ProductUnitModel, DEFAULT_BASE_UNIT},
 };
 use crate::module::repository::product_repository::ProductRepository;
+use crate::module::repository::product_price_repository::ProductPriceRepository;
+use crate::module::repository::category_repository::CategoryRepository;
+use crate::module::repository::product_barcode_repository::ProductBarcodeRepository;
+use crate::module::repository::product_variant_repository::ProductVariantRepository;
+use crate::module::repository::recipe_item_repository::{RecipeItemRepository, NewRecipeItem};
+use crate::module::repository::bundle_item_repository::{BundleItemRepository, NewBundleItem};
+use crate::module::repository::product_unit_repository::ProductUnitRepository;
+use crate::module::repository::stock_repository::StockRepository;
+use crate::module::repository::supplier_repository::SupplierRepository;
+use crate::module::repository::audit_log_repository::{AuditLogRepository, NewAuditLog};
+use crate::pkg::audit::{ENTITY_PRODUCT, ENTITY_PRODUCT_VARIANT, ENTITY_PRODUCT_UNIT, ACTION_CREATE, ACTION_UPDATE, ACTION_DELETE, ACTION_RESTORE, ACTION_PURGE};
+use crate::pkg::barcode;
+use crate::pkg::config::Config;
+use crate::pkg::costing::{self, CostReceipt, COSTING_FIFO};
 use crate::pkg::custom_error::AppError;
+use crate::pkg::filter;
+use crate::pkg::pagination::Paginated;
+use crate::pkg::quantity;
 use sea_orm::{prelude::*, Set};
+use serde_json::json;
 use std::sync::Arc;
 use chrono::Utc;
 
 #[async_trait]
 pub trait ProductUsecase: Send + Sync {
-    async fn create_product(&self, request: CreateProductRequest) -> Result<ProductResponse, AppError>;
+    async fn create_product(&self, request: CreateProductRequest, actor_id: Option<i32>) -> Result<ProductResponse, AppError>;
     async fn get_product(&self, request: GetProductRequest) -> Result<ProductResponse, AppError>;
-    async fn list_products(&self, query: GetListProductQuery) -> Result<ListProductResponse, AppError>;
-    async fn update_product(&self, id: i32, request: UpdateProductRequest) -> Result<ProductResponse, AppError>;
-    async fn delete_product(&self, id: i32) -> Result<(), AppError>;
+    async fn list_products(&self, query: GetListProductQuery) -> Result<Paginated<ProductResponse>, AppError>;
+    async fn update_product(&self, id: i32, request: UpdateProductRequest, actor_id: Option<i32>) -> Result<ProductResponse, AppError>;
+    async fn delete_product(&self, id: i32, actor_id: Option<i32>) -> Result<(), AppError>;
+    async fn list_deleted_products(&self, query: GetListProductQuery) -> Result<Paginated<ProductResponse>, AppError>;
+    async fn restore_product(&self, id: i32, actor_id: Option<i32>) -> Result<ProductResponse, AppError>;
+    async fn purge_product(&self, id: i32, actor_id: Option<i32>) -> Result<(), AppError>;
+    async fn list_price_history(&self, id: i32) -> Result<Vec<ProductPriceResponse>, AppError>;
+    async fn schedule_price(&self, id: i32, request: SchedulePriceRequest, actor_id: Option<i32>) -> Result<ProductPriceResponse, AppError>;
+    async fn cancel_scheduled_price(&self, id: i32, price_id: i32) -> Result<(), AppError>;
+    async fn apply_scheduled_prices(&self) -> Result<u64, AppError>;
+    async fn find_by_barcode(&self, code: &str) -> Result<ProductResponse, AppError>;
+    async fn list_variants(&self, id: i32) -> Result<Vec<ProductVariantResponse>, AppError>;
+    async fn create_variant(&self, id: i32, request: CreateVariantRequest, actor_id: Option<i32>) -> Result<ProductVariantResponse, AppError>;
+    async fn update_variant(&self, id: i32, variant_id: i32, request: UpdateVariantRequest, actor_id: Option<i32>) -> Result<ProductVariantResponse, AppError>;
+    async fn delete_variant(&self, id: i32, variant_id: i32, actor_id: Option<i32>) -> Result<(), AppError>;
+    async fn get_recipe(&self, id: i32) -> Result<RecipeResponse, AppError>;
+    async fn set_recipe(&self, id: i32, request: SetRecipeRequest, actor_id: Option<i32>) -> Result<RecipeResponse, AppError>;
+    async fn get_bundle(&self, id: i32) -> Result<BundleResponse, AppError>;
+    async fn set_bundle(&self, id: i32, request: SetBundleRequest, actor_id: Option<i32>) -> Result<BundleResponse, AppError>;
+    async fn list_units(&self, id: i32) -> Result<Vec<ProductUnitResponse>, AppError>;
+    async fn create_unit(&self, id: i32, request: CreateUnitRequest, actor_id: Option<i32>) -> Result<ProductUnitResponse, AppError>;
+    async fn update_unit(&self, id: i32, unit_id: i32, request: UpdateUnitRequest, actor_id: Option<i32>) -> Result<ProductUnitResponse, AppError>;
+    async fn delete_unit(&self, id: i32, unit_id: i32, actor_id: Option<i32>) -> Result<(), AppError>;
+    async fn revalue_cost(&self, product_id: i32, variant_id: Option<i32>, receipt: Option<CostReceipt>, actor_id: Option<i32>) -> Result<(), AppError>;
 }
 
 pub struct ProductUsecaseImpl {
     product_repository: Arc<dyn ProductRepository>,
+    product_price_repository: Arc<dyn ProductPriceRepository>,
+    category_repository: Arc<dyn CategoryRepository>,
+    product_barcode_repository: Arc<dyn ProductBarcodeRepository>,
+    product_variant_repository: Arc<dyn ProductVariantRepository>,
+    recipe_item_repository: Arc<dyn RecipeItemRepository>,
+    bundle_item_repository: Arc<dyn BundleItemRepository>,
+    product_unit_repository: Arc<dyn ProductUnitRepository>,
+    stock_repository: Arc<dyn StockRepository>,
+    supplier_repository: Arc<dyn SupplierRepository>,
+    audit_log_repository: Arc<dyn AuditLogRepository>,
+    config: Arc<Config>,
 }
 
 impl ProductUsecaseImpl {
-    pub fn new(product_repository: Arc<dyn ProductRepository>) -> Self {
-        Self { product_repository }
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        product_repository: Arc<dyn ProductRepository>,
+        product_price_repository: Arc<dyn ProductPriceRepository>,
+        category_repository: Arc<dyn CategoryRepository>,
+        product_barcode_repository: Arc<dyn ProductBarcodeRepository>,
+        product_variant_repository: Arc<dyn ProductVariantRepository>,
+        recipe_item_repository: Arc<dyn RecipeItemRepository>,
+        bundle_item_repository: Arc<dyn BundleItemRepository>,
+        product_unit_repository: Arc<dyn ProductUnitRepository>,
+        stock_repository: Arc<dyn StockRepository>,
+        supplier_repository: Arc<dyn SupplierRepository>,
+        audit_log_repository: Arc<dyn AuditLogRepository>,
+        config: Arc<Config>,
+    ) -> Self {
+        Self {
+            product_repository,
+            product_price_repository,
+            category_repository,
+            product_barcode_repository,
+            product_variant_repository,
+            recipe_item_repository,
+            bundle_item_repository,
+            product_unit_repository,
+            stock_repository,
+            supplier_repository,
+            audit_log_repository,
+            config,
+        }
+    }
+
+    /// Validated, canonical and de-duplicated barcodes
+    fn normalize_barcodes(codes: &[String]) -> Result<Vec<String>, AppError> {
+        let mut normalized: Vec<String> = Vec::new();
+        for code in codes {
+            let code = barcode::normalize(code)?;
+            if !normalized.contains(&code) {
+                normalized.push(code);
+            }
+        }
+
+        Ok(normalized)
+    }
+
+    /// SKU and barcodes must be unique among live products other than `id`
+    async fn check_identifiers(&self, id: Option<i32>, sku: Option<&str>, barcodes: &[String]) -> Result<(), AppError> {
+        if let Some(sku) = sku {
+            if let Some(product) = self.product_repository.find_by_sku(sku).await? {
+                if Some(product.id) != id {
+                    return Err(AppError::BadRequest("Product with this SKU already exists".to_string()));
+                }
+            }
+            self.check_variant_sku(None, sku).await?;
+        }
+
+        let taken = self.product_barcode_repository.find_live_by_codes(barcodes).await?;
+        if let Some(barcode) = taken.iter().find(|barcode| Some(barcode.product_id) != id) {
+            return Err(AppError::BadRequest(format!(
+                "Barcode {} is already used by product {}",
+                barcode.code, barcode.product_id
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Fill in `barcodes` of each product
+    async fn attach_barcodes(&self, products: &mut [ProductResponse]) -> Result<(), AppError> {
+        let product_ids: Vec<i32> = products.iter().map(|product| product.id).collect();
+        let barcodes = self.product_barcode_repository.find_by_products(&product_ids).await?;
+
+        for product in products.iter_mut() {
+            product.barcodes = barcodes
+                .iter()
+                .filter(|barcode| barcode.product_id == product.id)
+                .map(|barcode| barcode.code.clone())
+                .collect();
+        }
+
+        Ok(())
+    }
+
+    /// Variant SKUs share one namespace with product SKUs; `variant_id` is the variant being updated
+    async fn check_variant_sku(&self, variant_id: Option<i32>, sku: &str) -> Result<(), AppError> {
+        if let Some(variant) = self.product_variant_repository.find_by_sku(sku).await? {
+            if Some(variant.id) != variant_id {
+                return Err(AppError::BadRequest("Variant with this SKU already exists".to_string()));
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn find_variant(&self, id: i32, variant_id: i32) -> Result<ProductVariantModel, AppError> {
+        self.product_variant_repository
+            .find_by_id(variant_id)
+            .await?
+            .filter(|variant| variant.product_id == id)
+            .ok_or_else(|| AppError::NotFound(format!("Variant with id {} not found", variant_id)))
+    }
+
+    async fn find_unit(&self, id: i32, unit_id: i32) -> Result<ProductUnitModel, AppError> {
+        self.product_unit_repository
+            .find_by_id(unit_id)
+            .await?
+            .filter(|unit| unit.product_id == id)
+            .ok_or_else(|| AppError::NotFound(format!("Unit with id {} not found", unit_id)))
+    }
+
+    /// Unit names are unique per product, the base unit included; `unit_id` is the unit being updated
+    async fn check_unit_name(&self, product: &ProductModel, unit_id: Option<i32>, name: &str) -> Result<(), AppError> {
+        if name == product.base_unit {
+            return Err(AppError::BadRequest(format!("{} is already the base unit of the product", name)));
+        }
+        if let Some(unit) = self.product_unit_repository.find_by_name(product.id, name).await? {
+            if Some(unit.id) != unit_id {
+                return Err(AppError::BadRequest("Unit with this name already exists".to_string()));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Products that were deleted since they were added to a recipe or bundle still count at their last prices
+    async fn find_any_product(&self, id: i32) -> Result<ProductModel, AppError> {
+        match self.product_repository.find_by_id(id).await? {
+            Some(product) => Ok(product),
+            None => self.product_repository
+                .find_deleted_by_id(id)
+                .await?
+                .ok_or_else(|| AppError::NotFound(format!("Product with id {} not found", id))),
+        }
+    }
+
+    /// Whether the product's HPP comes from a recipe or bundle rather than being typed in
+    async fn has_computed_hpp(&self, id: i32) -> Result<bool, AppError> {
+        Ok(!self.recipe_item_repository.find_by_product(id).await?.is_empty()
+            || !self.bundle_item_repository.find_by_bundle(id).await?.is_empty())
+    }
+
+    /// Bundle components priced at their current HPP and selling price
+    async fn price_bundle(&self, product: &ProductModel, items: Vec<BundleItemModel>) -> Result<BundleResponse, AppError> {
+        let mut bundle = BundleResponse {
+            product_id: product.id,
+            items: Vec::new(),
+            hpp_amount: 0,
+            components_selling_amount: 0,
+            selling_amount: product.selling_amount,
+            savings_amount: 0,
+        };
+
+        for item in items {
+            let component = self.find_any_product(item.component_id).await?;
+            let (hpp_amount, selling_amount) = match item.variant_id {
+                Some(variant_id) => match self.product_variant_repository.find_by_id(variant_id).await? {
+                    Some(variant) => (variant.hpp_amount, variant.selling_amount),
+                    None => (component.hpp_amount, component.selling_amount),
+                },
+                None => (component.hpp_amount, component.selling_amount),
+            };
+
+            bundle.hpp_amount += i64::from(hpp_amount) * i64::from(item.qty);
+            bundle.components_selling_amount += i64::from(selling_amount) * i64::from(item.qty);
+            bundle.items.push(BundleItemResponse {
+                component_id: item.component_id,
+                component_name: component.name,
+                variant_id: item.variant_id,
+                qty: item.qty,
+                hpp_amount,
+                selling_amount,
+            });
+        }
+
+        bundle.savings_amount = bundle.components_selling_amount - i64::from(product.selling_amount);
+
+        Ok(bundle)
+    }
+
+    /// Recipe lines priced at the ingredients' current HPP
+    async fn price_recipe(&self, product_id: i32, items: Vec<RecipeItemModel>) -> Result<RecipeResponse, AppError> {
+        let mut recipe = RecipeResponse {
+            product_id,
+            items: Vec::new(),
+            hpp_amount: 0,
+        };
+
+        for item in items {
+            let ingredient = self.find_any_product(item.ingredient_id).await?;
+
+            let hpp_amount = quantity::line_amount(i64::from(ingredient.hpp_amount), quantity::to_base(item.qty, item.unit_factor));
+            recipe.hpp_amount += hpp_amount;
+            recipe.items.push(RecipeItemResponse {
+                ingredient_id: item.ingredient_id,
+                ingredient_name: ingredient.name,
+                qty: item.qty,
+                unit: item.unit,
+                unit_factor: item.unit_factor,
+                unit_hpp_amount: ingredient.hpp_amount,
+                hpp_amount,
+            });
+        }
+
+        Ok(recipe)
+    }
+
+    /// Set a recipe or bundle product's HPP to the cost of its ingredients or components
+    async fn refresh_computed_hpp(&self, product_id: i32, actor_id: Option<i32>) -> Result<(), AppError> {
+        let Some(product) = self.product_repository.find_by_id(product_id).await? else {
+            return Ok(());
+        };
+
+        let recipe_items = self.recipe_item_repository.find_by_product(product_id).await?;
+        let bundle_items = self.bundle_item_repository.find_by_bundle(product_id).await?;
+        let computed_hpp = if !recipe_items.is_empty() {
+            self.price_recipe(product_id, recipe_items).await?.hpp_amount
+        } else if !bundle_items.is_empty() {
+            self.price_bundle(&product, bundle_items).await?.hpp_amount
+        } else {
+            return Ok(());
+        };
+
+        let hpp_amount = i32::try_from(computed_hpp)
+            .map_err(|_| AppError::BadRequest(format!("Computed HPP of product {} is too large", product_id)))?;
+        if hpp_amount == product.hpp_amount {
+            return Ok(());
+        }
+
+        let product_active_model = ActiveModel {
+            id: Set(product_id),
+            hpp_amount: Set(hpp_amount),
+            ..Default::default()
+        };
+
+        let updated_product = self.product_repository.update(product_id, product_active_model).await?;
+
+        self.audit_log_repository.record(NewAuditLog {
+            actor_id,
+            entity: ENTITY_PRODUCT,
+            entity_id: product_id,
+            action: ACTION_UPDATE,
+            before: Some(json!(product)),
+            after: Some(json!(updated_product)),
+        }).await?;
+
+        self.record_price(&updated_product, actor_id).await
+    }
+
+    /// Re-cost every recipe and bundle built from the product, and the bundles containing those recipes
+    async fn refresh_dependents(&self, product_id: i32, actor_id: Option<i32>) -> Result<(), AppError> {
+        for recipe_item in self.recipe_item_repository.find_by_ingredient(product_id).await? {
+            self.refresh_computed_hpp(recipe_item.product_id, actor_id).await?;
+            for bundle_item in self.bundle_item_repository.find_by_component(recipe_item.product_id).await? {
+                self.refresh_computed_hpp(bundle_item.bundle_id, actor_id).await?;
+            }
+        }
+
+        for bundle_item in self.bundle_item_repository.find_by_component(product_id).await? {
+            self.refresh_computed_hpp(bundle_item.bundle_id, actor_id).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn response_with_barcodes(&self, product: ProductModel) -> Result<ProductResponse, AppError> {
+        let mut response = ProductResponse::from(product);
+        self.attach_barcodes(std::slice::from_mut(&mut response)).await?;
+        Ok(response)
+    }
+
+    async fn check_category(&self, category_id: Option<i32>) -> Result<(), AppError> {
+        if let Some(category_id) = category_id {
+            self.category_repository
+                .find_by_id(category_id)
+                .await?
+                .ok_or_else(|| AppError::BadRequest(format!("Category with id {} not found", category_id)))?;
+        }
+
+        Ok(())
+    }
+
+    async fn check_supplier(&self, supplier_id: Option<i32>) -> Result<(), AppError> {
+        if let Some(supplier_id) = supplier_id {
+            self.supplier_repository
+                .find_by_id(supplier_id)
+                .await?
+                .ok_or_else(|| AppError::BadRequest(format!("Supplier with id {} not found", supplier_id)))?;
+        }
+
+        Ok(())
+    }
+
+    /// Widen the `category_id` filter to subcategories unless `include_subcategories=false`
+    async fn expand_categories(&self, mut query: GetListProductQuery) -> Result<GetListProductQuery, AppError> {
+        let category_ids = filter::parse_list::<i32>("category_id", query.category_id.as_deref())?;
+        let include_subcategories = !matches!(query.include_subcategories.as_deref(), Some("false") | Some("0"));
+
+        if !category_ids.is_empty() && include_subcategories {
+            let category_ids = self.category_repository.find_descendant_ids(&category_ids).await?;
+            query.category_id = Some(
+                category_ids
+                    .iter()
+                    .map(|id| id.to_string())
+                    .collect::<Vec<_>>()
+                    .join(","),
+            );
+        }
+
+        Ok(query)
+    }
+
+    /// Append the product's current prices to its history
+    async fn record_price(&self, product: &ProductModel, actor_id: Option<i32>) -> Result<(), AppError> {
+        let now = Utc::now();
+        let price_active_model = ProductPriceActiveModel {
+            product_id: Set(product.id),
+            hpp_amount: Set(Some(product.hpp_amount)),
+            selling_amount: Set(Some(product.selling_amount)),
+            effective_from: Set(now),
+            applied_at: Set(Some(now)),
+            created_by: Set(actor_id),
+            created_at: Set(Some(now)),
+            ..Default::default()
+        };
+
+        self.product_price_repository.create(price_active_model).await?;
+
+        Ok(())
     }
 }
 
 #[async_trait]
 impl ProductUsecase for ProductUsecaseImpl {
-    async fn create_product(&self, request: CreateProductRequest) -> Result<ProductResponse, AppError> {
+    async fn create_product(&self, request: CreateProductRequest, actor_id: Option<i32>) -> Result<ProductResponse, AppError> {
 
         // Check if product with same name already exists
-        if let Some(_) = self.product_repository.find_by_name(&request.name).await? {
+        if self.product_repository.find_by_name(&request.name).await?.is_some() {
             return Err(AppError::BadRequest("Product with this name already exists".to_string()));
         }
 
+        self.check_category(request.category_id).await?;
+        self.check_supplier(request.supplier_id).await?;
+
+        let barcodes = Self::normalize_barcodes(&request.barcodes.unwrap_or_default())?;
+        self.check_identifiers(None, request.sku.as_deref(), &barcodes).await?;
+
         // Create active model
         let product_active_model = ActiveModel {
             name: Set(request.name.clone()),
+            sku: Set(request.sku),
             hpp_amount: Set(request.hpp_amount),
             selling_amount: Set(request.selling_amount),
+            category_id: Set(request.category_id),
+            base_unit: Set(request.base_unit.unwrap_or_else(|| DEFAULT_BASE_UNIT.to_string())),
+            reorder_point: Set(request.reorder_point),
+            supplier_id: Set(request.supplier_id),
             created_at: Set(Some(Utc::now())),
             updated_at: Set(Some(Utc::now())),
             deleted_at: Set(None),
@@ -58,15 +460,24 @@ impl ProductUsecase for ProductUsecaseImpl {
         };
 
         // Save to repository
-        self.product_repository.create(product_active_model).await?;
+        let created_product = self.product_repository.create(product_active_model).await?;
 
-        // Find the created product to return
-        let created_product = self.product_repository
-            .find_by_name(&request.name)
-            .await?
-            .ok_or_else(|| AppError::InternalError)?;
+        self.audit_log_repository.record(NewAuditLog {
+            actor_id,
+            entity: ENTITY_PRODUCT,
+            entity_id: created_product.id,
+            action: ACTION_CREATE,
+            before: None,
+            after: Some(json!(created_product)),
+        }).await?;
+
+        self.record_price(&created_product, actor_id).await?;
+
+        if !barcodes.is_empty() {
+            self.product_barcode_repository.replace(created_product.id, &barcodes).await?;
+        }
 
-        Ok(ProductResponse::from(created_product))
+        self.response_with_barcodes(created_product).await
     }
 
     async fn get_product(&self, request: GetProductRequest) -> Result<ProductResponse, AppError> {
@@ -75,15 +486,18 @@ impl ProductUsecase for ProductUsecaseImpl {
             .await?
             .ok_or_else(|| AppError::NotFound(format!("Product with id {} not found", request.id)))?;
 
-        Ok(ProductResponse::from(product))
+        self.response_with_barcodes(product).await
     }
 
-    async fn list_products(&self, query: GetListProductQuery) -> Result<ListProductResponse, AppError> {
+    async fn list_products(&self, query: GetListProductQuery) -> Result<Paginated<ProductResponse>, AppError> {
+        let query = self.expand_categories(query).await?;
         let paginated_result = self.product_repository.find_with_pagination(query).await?;
-        Ok(ListProductResponse::from(paginated_result))
+        let mut products = Paginated::from(paginated_result);
+        self.attach_barcodes(&mut products.data).await?;
+        Ok(products)
     }
 
-    async fn update_product(&self, id: i32, request: UpdateProductRequest) -> Result<ProductResponse, AppError> {
+    async fn update_product(&self, id: i32, request: UpdateProductRequest, actor_id: Option<i32>) -> Result<ProductResponse, AppError> {
         // Check if product exists
         let existing_product = self.product_repository
             .find_by_id(id)
@@ -92,13 +506,38 @@ impl ProductUsecase for ProductUsecaseImpl {
 
         // Check if new name conflicts with existing product (if name is being updated)
         if let Some(new_name) = &request.name {
-            if new_name != &existing_product.name {
-                if let Some(_) = self.product_repository.find_by_name(new_name).await? {
-                    return Err(AppError::BadRequest("Product with this name already exists".to_string()));
-                }
+            if new_name != &existing_product.name && self.product_repository.find_by_name(new_name).await?.is_some() {
+                return Err(AppError::BadRequest("Product with this name already exists".to_string()));
             }
         }
 
+        if let Some(category_id) = request.category_id {
+            self.check_category(category_id).await?;
+        }
+
+        if let Some(supplier_id) = request.supplier_id {
+            self.check_supplier(supplier_id).await?;
+        }
+
+        if request.hpp_amount.is_some() && self.has_computed_hpp(id).await? {
+            return Err(AppError::BadRequest("HPP of a recipe or bundle product is computed from its parts".to_string()));
+        }
+
+        // Quantities already on record are in the current base unit, so it can only change before any exist
+        let changes_base_unit = request.base_unit.as_ref().is_some_and(|unit| *unit != existing_product.base_unit);
+        if changes_base_unit && self.product_repository.has_unit_history(id).await? {
+            return Err(AppError::BadRequest(
+                "Base unit cannot change once the product has stock, units, movements or sales".to_string(),
+            ));
+        }
+
+        let barcodes = request.barcodes
+            .as_deref()
+            .map(Self::normalize_barcodes)
+            .transpose()?;
+        let new_sku = request.sku.clone().flatten();
+        self.check_identifiers(Some(id), new_sku.as_deref(), barcodes.as_deref().unwrap_or_default()).await?;
+
         // Create active model with only changed fields
         let mut product_active_model = ActiveModel {
             id: Set(id),
@@ -114,23 +553,775 @@ impl ProductUsecase for ProductUsecaseImpl {
         if let Some(selling_amount) = request.selling_amount {
             product_active_model.selling_amount = Set(selling_amount);
         }
+        if let Some(category_id) = request.category_id {
+            product_active_model.category_id = Set(category_id);
+        }
+        if let Some(sku) = request.sku {
+            product_active_model.sku = Set(sku);
+        }
+        if let Some(base_unit) = request.base_unit {
+            product_active_model.base_unit = Set(base_unit);
+        }
+        if let Some(reorder_point) = request.reorder_point {
+            product_active_model.reorder_point = Set(reorder_point);
+        }
+        if let Some(supplier_id) = request.supplier_id {
+            product_active_model.supplier_id = Set(supplier_id);
+        }
 
         // Update in repository
         let updated_product = self.product_repository.update(id, product_active_model).await?;
 
-        Ok(ProductResponse::from(updated_product))
+        self.audit_log_repository.record(NewAuditLog {
+            actor_id,
+            entity: ENTITY_PRODUCT,
+            entity_id: id,
+            action: ACTION_UPDATE,
+            before: Some(json!(existing_product)),
+            after: Some(json!(updated_product)),
+        }).await?;
+
+        if updated_product.hpp_amount != existing_product.hpp_amount
+            || updated_product.selling_amount != existing_product.selling_amount
+        {
+            self.record_price(&updated_product, actor_id).await?;
+        }
+
+        if updated_product.hpp_amount != existing_product.hpp_amount {
+            self.refresh_dependents(id, actor_id).await?;
+        }
+
+        if let Some(barcodes) = barcodes {
+            self.product_barcode_repository.replace(id, &barcodes).await?;
+        }
+
+        self.response_with_barcodes(updated_product).await
     }
 
-    async fn delete_product(&self, id: i32) -> Result<(), AppError> {
+    async fn delete_product(&self, id: i32, actor_id: Option<i32>) -> Result<(), AppError> {
         // Check if product exists
-        self.product_repository
+        let existing_product = self.product_repository
             .find_by_id(id)
             .await?
             .ok_or_else(|| AppError::NotFound(format!("Product with id {} not found", id)))?;
 
+        // Live recipes and bundles still need the product
+        for item in self.recipe_item_repository.find_by_ingredient(id).await? {
+            if self.product_repository.find_by_id(item.product_id).await?.is_some() {
+                return Err(AppError::BadRequest(format!("Product is an ingredient of product {}", item.product_id)));
+            }
+        }
+        for item in self.bundle_item_repository.find_by_component(id).await? {
+            if self.product_repository.find_by_id(item.bundle_id).await?.is_some() {
+                return Err(AppError::BadRequest(format!("Product is a component of bundle {}", item.bundle_id)));
+            }
+        }
+
         // Soft delete
-        self.product_repository.delete(id).await?;
+        let deleted_product = self.product_repository.delete(id).await?;
+
+        self.audit_log_repository.record(NewAuditLog {
+            actor_id,
+            entity: ENTITY_PRODUCT,
+            entity_id: id,
+            action: ACTION_DELETE,
+            before: Some(json!(existing_product)),
+            after: Some(json!(deleted_product)),
+        }).await?;
+
+        Ok(())
+    }
+
+    async fn list_deleted_products(&self, query: GetListProductQuery) -> Result<Paginated<ProductResponse>, AppError> {
+        let query = self.expand_categories(query).await?;
+        let paginated_result = self.product_repository.find_deleted_with_pagination(query).await?;
+        let mut products = Paginated::from(paginated_result);
+        self.attach_barcodes(&mut products.data).await?;
+        Ok(products)
+    }
+
+    async fn restore_product(&self, id: i32, actor_id: Option<i32>) -> Result<ProductResponse, AppError> {
+        // Check if product is in the trash
+        let deleted_product = self.product_repository
+            .find_deleted_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Deleted product with id {} not found", id)))?;
+
+        // Names must stay unique among live products
+        if self.product_repository.find_by_name(&deleted_product.name).await?.is_some() {
+            return Err(AppError::BadRequest("Product with this name already exists".to_string()));
+        }
+
+        // So must its SKU and barcodes
+        let barcodes: Vec<String> = self.product_barcode_repository
+            .find_by_products(&[id])
+            .await?
+            .into_iter()
+            .map(|barcode| barcode.code)
+            .collect();
+        self.check_identifiers(Some(id), deleted_product.sku.as_deref(), &barcodes).await?;
+
+        let restored_product = self.product_repository.restore(id).await?;
+
+        self.audit_log_repository.record(NewAuditLog {
+            actor_id,
+            entity: ENTITY_PRODUCT,
+            entity_id: id,
+            action: ACTION_RESTORE,
+            before: Some(json!(deleted_product)),
+            after: Some(json!(restored_product)),
+        }).await?;
+
+        self.response_with_barcodes(restored_product).await
+    }
+
+    async fn purge_product(&self, id: i32, actor_id: Option<i32>) -> Result<(), AppError> {
+        // Only soft-deleted rows can be purged
+        let deleted_product = self.product_repository
+            .find_deleted_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Deleted product with id {} not found", id)))?;
+
+        self.product_repository.purge(id).await?;
+
+        self.audit_log_repository.record(NewAuditLog {
+            actor_id,
+            entity: ENTITY_PRODUCT,
+            entity_id: id,
+            action: ACTION_PURGE,
+            before: Some(json!(deleted_product)),
+            after: None,
+        }).await?;
 
         Ok(())
     }
+
+    async fn list_price_history(&self, id: i32) -> Result<Vec<ProductPriceResponse>, AppError> {
+        // Check if product exists
+        self.product_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Product with id {} not found", id)))?;
+
+        let prices = self.product_price_repository.find_by_product(id).await?;
+
+        Ok(prices.into_iter().map(ProductPriceResponse::from).collect())
+    }
+
+    async fn schedule_price(&self, id: i32, request: SchedulePriceRequest, actor_id: Option<i32>) -> Result<ProductPriceResponse, AppError> {
+        // Check if product exists
+        self.product_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Product with id {} not found", id)))?;
+
+        if request.hpp_amount.is_none() && request.selling_amount.is_none() {
+            return Err(AppError::BadRequest("Either hpp_amount or selling_amount is required".to_string()));
+        }
+        if request.effective_from <= Utc::now() {
+            return Err(AppError::BadRequest("effective_from must be in the future, use update for immediate changes".to_string()));
+        }
+        if request.hpp_amount.is_some() && self.has_computed_hpp(id).await? {
+            return Err(AppError::BadRequest("HPP of a recipe or bundle product is computed from its parts".to_string()));
+        }
+
+        let price_active_model = ProductPriceActiveModel {
+            product_id: Set(id),
+            hpp_amount: Set(request.hpp_amount),
+            selling_amount: Set(request.selling_amount),
+            effective_from: Set(request.effective_from),
+            applied_at: Set(None),
+            created_by: Set(actor_id),
+            created_at: Set(Some(Utc::now())),
+            ..Default::default()
+        };
+
+        let scheduled_price = self.product_price_repository.create(price_active_model).await?;
+
+        Ok(ProductPriceResponse::from(scheduled_price))
+    }
+
+    async fn cancel_scheduled_price(&self, id: i32, price_id: i32) -> Result<(), AppError> {
+        let price = self.product_price_repository
+            .find_by_id(price_id)
+            .await?
+            .filter(|price| price.product_id == id)
+            .ok_or_else(|| AppError::NotFound(format!("Price with id {} not found", price_id)))?;
+
+        if price.applied_at.is_some() || !self.product_price_repository.delete_scheduled(price_id).await? {
+            return Err(AppError::BadRequest("Price change has already taken effect".to_string()));
+        }
+
+        Ok(())
+    }
+
+    async fn apply_scheduled_prices(&self) -> Result<u64, AppError> {
+        let now = Utc::now();
+        let due_prices = self.product_price_repository.find_due(now).await?;
+
+        let mut applied = 0;
+        for price in due_prices {
+            // Deleted products pick up their schedule once restored
+            let Some(product) = self.product_repository.find_by_id(price.product_id).await? else {
+                continue;
+            };
+
+            // Recipe and bundle products keep their computed HPP
+            let hpp_amount = if self.has_computed_hpp(product.id).await? {
+                product.hpp_amount
+            } else {
+                price.hpp_amount.unwrap_or(product.hpp_amount)
+            };
+            let selling_amount = price.selling_amount.unwrap_or(product.selling_amount);
+
+            // Claim the row first so overlapping runs apply it once
+            if !self.product_price_repository.mark_applied(price.id, hpp_amount, selling_amount, now).await? {
+                continue;
+            }
+
+            let product_active_model = ActiveModel {
+                id: Set(product.id),
+                hpp_amount: Set(hpp_amount),
+                selling_amount: Set(selling_amount),
+                ..Default::default()
+            };
+
+            let updated_product = self.product_repository.update(product.id, product_active_model).await?;
+
+            self.audit_log_repository.record(NewAuditLog {
+                actor_id: price.created_by,
+                entity: ENTITY_PRODUCT,
+                entity_id: product.id,
+                action: ACTION_UPDATE,
+                before: Some(json!(product)),
+                after: Some(json!(updated_product)),
+            }).await?;
+
+            if updated_product.hpp_amount != product.hpp_amount {
+                self.refresh_dependents(product.id, price.created_by).await?;
+            }
+
+            applied += 1;
+        }
+
+        Ok(applied)
+    }
+
+    async fn find_by_barcode(&self, code: &str) -> Result<ProductResponse, AppError> {
+        let code = barcode::normalize(code)?;
+
+        let barcode = self.product_barcode_repository
+            .find_live_by_codes(std::slice::from_ref(&code))
+            .await?
+            .into_iter()
+            .next()
+            .ok_or_else(|| AppError::NotFound(format!("Product with barcode {} not found", code)))?;
+
+        let product = self.product_repository
+            .find_by_id(barcode.product_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Product with barcode {} not found", code)))?;
+
+        self.response_with_barcodes(product).await
+    }
+
+    async fn list_variants(&self, id: i32) -> Result<Vec<ProductVariantResponse>, AppError> {
+        // Check if product exists
+        self.product_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Product with id {} not found", id)))?;
+
+        let variants = self.product_variant_repository.find_by_product(id).await?;
+
+        Ok(variants.into_iter().map(ProductVariantResponse::from).collect())
+    }
+
+    async fn create_variant(&self, id: i32, request: CreateVariantRequest, actor_id: Option<i32>) -> Result<ProductVariantResponse, AppError> {
+        // Check if product exists
+        let product = self.product_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Product with id {} not found", id)))?;
+
+        if self.product_variant_repository.find_by_name(id, &request.name).await?.is_some() {
+            return Err(AppError::BadRequest("Variant with this name already exists".to_string()));
+        }
+
+        if let Some(sku) = &request.sku {
+            if self.product_repository.find_by_sku(sku).await?.is_some() {
+                return Err(AppError::BadRequest("Product with this SKU already exists".to_string()));
+            }
+            self.check_variant_sku(None, sku).await?;
+        }
+
+        let variant_active_model = ProductVariantActiveModel {
+            product_id: Set(id),
+            name: Set(request.name),
+            sku: Set(request.sku),
+            hpp_amount: Set(request.hpp_amount.unwrap_or(product.hpp_amount)),
+            selling_amount: Set(request.selling_amount.unwrap_or(product.selling_amount)),
+            stock: Set(Decimal::ZERO),
+            reorder_point: Set(request.reorder_point),
+            created_at: Set(Some(Utc::now())),
+            updated_at: Set(Some(Utc::now())),
+            deleted_at: Set(None),
+            ..Default::default()
+        };
+
+        let created_variant = self.product_variant_repository.create(variant_active_model).await?;
+
+        self.audit_log_repository.record(NewAuditLog {
+            actor_id,
+            entity: ENTITY_PRODUCT_VARIANT,
+            entity_id: created_variant.id,
+            action: ACTION_CREATE,
+            before: None,
+            after: Some(json!(created_variant)),
+        }).await?;
+
+        Ok(ProductVariantResponse::from(created_variant))
+    }
+
+    async fn update_variant(&self, id: i32, variant_id: i32, request: UpdateVariantRequest, actor_id: Option<i32>) -> Result<ProductVariantResponse, AppError> {
+        let existing_variant = self.find_variant(id, variant_id).await?;
+
+        if let Some(new_name) = &request.name {
+            if new_name != &existing_variant.name && self.product_variant_repository.find_by_name(id, new_name).await?.is_some() {
+                return Err(AppError::BadRequest("Variant with this name already exists".to_string()));
+            }
+        }
+
+        if let Some(Some(sku)) = &request.sku {
+            if self.product_repository.find_by_sku(sku).await?.is_some() {
+                return Err(AppError::BadRequest("Product with this SKU already exists".to_string()));
+            }
+            self.check_variant_sku(Some(variant_id), sku).await?;
+        }
+
+        // Stock only changes through the movement ledger
+        let mut variant_active_model = ProductVariantActiveModel {
+            id: Set(variant_id),
+            ..Default::default()
+        };
+
+        if let Some(name) = request.name {
+            variant_active_model.name = Set(name);
+        }
+        if let Some(sku) = request.sku {
+            variant_active_model.sku = Set(sku);
+        }
+        if let Some(hpp_amount) = request.hpp_amount {
+            variant_active_model.hpp_amount = Set(hpp_amount);
+        }
+        if let Some(selling_amount) = request.selling_amount {
+            variant_active_model.selling_amount = Set(selling_amount);
+        }
+        if let Some(reorder_point) = request.reorder_point {
+            variant_active_model.reorder_point = Set(reorder_point);
+        }
+
+        let updated_variant = self.product_variant_repository.update(variant_id, variant_active_model).await?;
+
+        self.audit_log_repository.record(NewAuditLog {
+            actor_id,
+            entity: ENTITY_PRODUCT_VARIANT,
+            entity_id: variant_id,
+            action: ACTION_UPDATE,
+            before: Some(json!(existing_variant)),
+            after: Some(json!(updated_variant)),
+        }).await?;
+
+        if updated_variant.hpp_amount != existing_variant.hpp_amount {
+            self.refresh_dependents(id, actor_id).await?;
+        }
+
+        Ok(ProductVariantResponse::from(updated_variant))
+    }
+
+    async fn delete_variant(&self, id: i32, variant_id: i32, actor_id: Option<i32>) -> Result<(), AppError> {
+        let existing_variant = self.find_variant(id, variant_id).await?;
+
+        // Soft delete, past transactions keep pointing at it
+        let deleted_variant = self.product_variant_repository.delete(variant_id).await?;
+
+        self.audit_log_repository.record(NewAuditLog {
+            actor_id,
+            entity: ENTITY_PRODUCT_VARIANT,
+            entity_id: variant_id,
+            action: ACTION_DELETE,
+            before: Some(json!(existing_variant)),
+            after: Some(json!(deleted_variant)),
+        }).await?;
+
+        Ok(())
+    }
+
+    async fn get_recipe(&self, id: i32) -> Result<RecipeResponse, AppError> {
+        // Check if product exists
+        self.product_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Product with id {} not found", id)))?;
+
+        let items = self.recipe_item_repository.find_by_product(id).await?;
+        self.price_recipe(id, items).await
+    }
+
+    async fn set_recipe(&self, id: i32, request: SetRecipeRequest, actor_id: Option<i32>) -> Result<RecipeResponse, AppError> {
+        // Check if product exists
+        self.product_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Product with id {} not found", id)))?;
+
+        // One level only: ingredients are stocked items, never recipes themselves
+        if !request.items.is_empty() {
+            if let Some(item) = self.recipe_item_repository.find_by_ingredient(id).await?.first() {
+                return Err(AppError::BadRequest(format!("Product is an ingredient of product {} and cannot have a recipe", item.product_id)));
+            }
+            if !self.bundle_item_repository.find_by_bundle(id).await?.is_empty() {
+                return Err(AppError::BadRequest("Bundle products cannot have a recipe".to_string()));
+            }
+        }
+
+        let mut items: Vec<NewRecipeItem> = Vec::new();
+        for item in request.items {
+            if item.ingredient_id == id {
+                return Err(AppError::BadRequest("Product cannot be its own ingredient".to_string()));
+            }
+            if items.iter().any(|existing| existing.ingredient_id == item.ingredient_id) {
+                return Err(AppError::BadRequest(format!("Ingredient {} is listed more than once", item.ingredient_id)));
+            }
+
+            let ingredient = self.product_repository
+                .find_by_id(item.ingredient_id)
+                .await?
+                .ok_or_else(|| AppError::BadRequest(format!("Ingredient with id {} not found", item.ingredient_id)))?;
+            if self.has_computed_hpp(item.ingredient_id).await? {
+                return Err(AppError::BadRequest(format!("Ingredient {} is itself a recipe or bundle product", item.ingredient_id)));
+            }
+
+            let (unit, unit_factor) = match item.unit.filter(|unit| *unit != ingredient.base_unit) {
+                Some(name) => {
+                    let unit = self.product_unit_repository
+                        .find_by_name(ingredient.id, &name)
+                        .await?
+                        .ok_or_else(|| AppError::BadRequest(format!("Unit {} is not configured for product {}", name, ingredient.id)))?;
+                    (unit.name, unit.factor)
+                }
+                None => (ingredient.base_unit, Decimal::ONE),
+            };
+
+            if quantity::to_base(item.qty, unit_factor).is_zero() {
+                return Err(AppError::BadRequest(format!("Quantity of ingredient {} is too small for its base unit", ingredient.id)));
+            }
+
+            items.push(NewRecipeItem {
+                ingredient_id: item.ingredient_id,
+                qty: item.qty,
+                unit,
+                unit_factor,
+            });
+        }
+
+        let existing_items = self.recipe_item_repository.find_by_product(id).await?;
+        let saved_items = self.recipe_item_repository.replace(id, items).await?;
+
+        self.audit_log_repository.record(NewAuditLog {
+            actor_id,
+            entity: ENTITY_PRODUCT,
+            entity_id: id,
+            action: ACTION_UPDATE,
+            before: Some(json!({ "recipe": existing_items })),
+            after: Some(json!({ "recipe": saved_items })),
+        }).await?;
+
+        self.refresh_computed_hpp(id, actor_id).await?;
+        for bundle_item in self.bundle_item_repository.find_by_component(id).await? {
+            self.refresh_computed_hpp(bundle_item.bundle_id, actor_id).await?;
+        }
+
+        self.price_recipe(id, saved_items).await
+    }
+
+    async fn get_bundle(&self, id: i32) -> Result<BundleResponse, AppError> {
+        let product = self.product_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Product with id {} not found", id)))?;
+
+        let items = self.bundle_item_repository.find_by_bundle(id).await?;
+        self.price_bundle(&product, items).await
+    }
+
+    async fn set_bundle(&self, id: i32, request: SetBundleRequest, actor_id: Option<i32>) -> Result<BundleResponse, AppError> {
+        self.product_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Product with id {} not found", id)))?;
+
+        // Bundles are built from sellable items and are never nested or used as ingredients
+        if !request.items.is_empty() {
+            if !self.recipe_item_repository.find_by_product(id).await?.is_empty() {
+                return Err(AppError::BadRequest("Recipe products cannot be bundles".to_string()));
+            }
+            if let Some(item) = self.bundle_item_repository.find_by_component(id).await?.first() {
+                return Err(AppError::BadRequest(format!("Product is a component of bundle {} and cannot be a bundle", item.bundle_id)));
+            }
+            if let Some(item) = self.recipe_item_repository.find_by_ingredient(id).await?.first() {
+                return Err(AppError::BadRequest(format!("Product is an ingredient of product {} and cannot be a bundle", item.product_id)));
+            }
+        }
+
+        let mut items: Vec<NewBundleItem> = Vec::new();
+        for item in request.items {
+            if item.component_id == id {
+                return Err(AppError::BadRequest("Bundle cannot contain itself".to_string()));
+            }
+            if items.iter().any(|existing| existing.component_id == item.component_id && existing.variant_id == item.variant_id) {
+                return Err(AppError::BadRequest(format!("Component {} is listed more than once", item.component_id)));
+            }
+
+            self.product_repository
+                .find_by_id(item.component_id)
+                .await?
+                .ok_or_else(|| AppError::BadRequest(format!("Component with id {} not found", item.component_id)))?;
+            if !self.bundle_item_repository.find_by_bundle(item.component_id).await?.is_empty() {
+                return Err(AppError::BadRequest(format!("Component {} is itself a bundle", item.component_id)));
+            }
+
+            match item.variant_id {
+                Some(variant_id) => {
+                    self.find_variant(item.component_id, variant_id).await?;
+                }
+                None => {
+                    if self.product_variant_repository.count_by_product(item.component_id).await? > 0 {
+                        return Err(AppError::BadRequest(format!("variant_id is required for component {}", item.component_id)));
+                    }
+                }
+            }
+
+            items.push(NewBundleItem {
+                component_id: item.component_id,
+                variant_id: item.variant_id,
+                qty: item.qty,
+            });
+        }
+
+        let existing_items = self.bundle_item_repository.find_by_bundle(id).await?;
+        let saved_items = self.bundle_item_repository.replace(id, items).await?;
+
+        self.audit_log_repository.record(NewAuditLog {
+            actor_id,
+            entity: ENTITY_PRODUCT,
+            entity_id: id,
+            action: ACTION_UPDATE,
+            before: Some(json!({ "bundle": existing_items })),
+            after: Some(json!({ "bundle": saved_items })),
+        }).await?;
+
+        self.refresh_computed_hpp(id, actor_id).await?;
+
+        // Re-read for the refreshed HPP
+        let product = self.product_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Product with id {} not found", id)))?;
+        self.price_bundle(&product, saved_items).await
+    }
+    async fn list_units(&self, id: i32) -> Result<Vec<ProductUnitResponse>, AppError> {
+        // Check if product exists
+        self.product_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Product with id {} not found", id)))?;
+
+        let units = self.product_unit_repository.find_by_product(id).await?;
+
+        Ok(units.into_iter().map(ProductUnitResponse::from).collect())
+    }
+
+    async fn create_unit(&self, id: i32, request: CreateUnitRequest, actor_id: Option<i32>) -> Result<ProductUnitResponse, AppError> {
+        let product = self.product_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Product with id {} not found", id)))?;
+
+        self.check_unit_name(&product, None, &request.name).await?;
+
+        let unit_active_model = ProductUnitActiveModel {
+            product_id: Set(id),
+            name: Set(request.name),
+            factor: Set(request.factor),
+            selling_amount: Set(request.selling_amount),
+            created_at: Set(Some(Utc::now())),
+            updated_at: Set(Some(Utc::now())),
+            ..Default::default()
+        };
+
+        let created_unit = self.product_unit_repository.create(unit_active_model).await?;
+
+        self.audit_log_repository.record(NewAuditLog {
+            actor_id,
+            entity: ENTITY_PRODUCT_UNIT,
+            entity_id: created_unit.id,
+            action: ACTION_CREATE,
+            before: None,
+            after: Some(json!(created_unit)),
+        }).await?;
+
+        Ok(ProductUnitResponse::from(created_unit))
+    }
+
+    async fn update_unit(&self, id: i32, unit_id: i32, request: UpdateUnitRequest, actor_id: Option<i32>) -> Result<ProductUnitResponse, AppError> {
+        let product = self.product_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Product with id {} not found", id)))?;
+        let existing_unit = self.find_unit(id, unit_id).await?;
+
+        if let Some(name) = &request.name {
+            self.check_unit_name(&product, Some(unit_id), name).await?;
+        }
+        if let Some(Some(selling_amount)) = request.selling_amount {
+            if selling_amount < 0 {
+                return Err(AppError::BadRequest("Selling amount must be non-negative".to_string()));
+            }
+        }
+
+        // Past sale lines keep the factor they were sold with
+        let mut unit_active_model = ProductUnitActiveModel {
+            id: Set(unit_id),
+            updated_at: Set(Some(Utc::now())),
+            ..Default::default()
+        };
+
+        if let Some(name) = request.name {
+            unit_active_model.name = Set(name);
+        }
+        if let Some(factor) = request.factor {
+            unit_active_model.factor = Set(factor);
+        }
+        if let Some(selling_amount) = request.selling_amount {
+            unit_active_model.selling_amount = Set(selling_amount);
+        }
+
+        let updated_unit = self.product_unit_repository.update(unit_active_model).await?;
+
+        self.audit_log_repository.record(NewAuditLog {
+            actor_id,
+            entity: ENTITY_PRODUCT_UNIT,
+            entity_id: unit_id,
+            action: ACTION_UPDATE,
+            before: Some(json!(existing_unit)),
+            after: Some(json!(updated_unit)),
+        }).await?;
+
+        Ok(ProductUnitResponse::from(updated_unit))
+    }
+
+    async fn delete_unit(&self, id: i32, unit_id: i32, actor_id: Option<i32>) -> Result<(), AppError> {
+        let existing_unit = self.find_unit(id, unit_id).await?;
+
+        self.product_unit_repository.delete(unit_id).await?;
+
+        self.audit_log_repository.record(NewAuditLog {
+            actor_id,
+            entity: ENTITY_PRODUCT_UNIT,
+            entity_id: unit_id,
+            action: ACTION_DELETE,
+            before: Some(json!(existing_unit)),
+            after: None,
+        }).await?;
+
+        Ok(())
+    }
+
+    /// Follow purchase costs into the HPP of the product or variant: a receipt moves the weighted average,
+    /// while under FIFO the HPP tracks the oldest receipt still in stock after any movement
+    async fn revalue_cost(&self, product_id: i32, variant_id: Option<i32>, receipt: Option<CostReceipt>, actor_id: Option<i32>) -> Result<(), AppError> {
+        let fifo = self.config.costing.method == COSTING_FIFO;
+        if !fifo && receipt.is_none() {
+            return Ok(());
+        }
+
+        // Deleted items keep their last HPP
+        let (stock, hpp_amount, existing) = match variant_id {
+            Some(variant_id) => {
+                let Some(variant) = self.product_variant_repository.find_by_id(variant_id).await? else {
+                    return Ok(());
+                };
+                (variant.stock, variant.hpp_amount, json!(variant))
+            }
+            None => {
+                let Some(product) = self.product_repository.find_by_id(product_id).await? else {
+                    return Ok(());
+                };
+                (product.stock, product.hpp_amount, json!(product))
+            }
+        };
+
+        let revalued = if fifo {
+            match self.stock_repository.find_open_layers(product_id, variant_id).await?.first() {
+                Some(layer) => costing::unit_cost(layer.cost_amount.unwrap_or_default(), layer.qty),
+                None => None,
+            }
+        } else {
+            // `stock` already includes the receipt
+            receipt.and_then(|receipt| costing::moving_average(stock - receipt.qty, hpp_amount, &receipt))
+        };
+
+        let Some(revalued) = revalued else {
+            return Ok(());
+        };
+        let revalued = i32::try_from(revalued)
+            .map_err(|_| AppError::BadRequest(format!("HPP of product {} is too large", product_id)))?;
+        if revalued == hpp_amount {
+            return Ok(());
+        }
+
+        match variant_id {
+            Some(variant_id) => {
+                let variant_active_model = ProductVariantActiveModel {
+                    id: Set(variant_id),
+                    hpp_amount: Set(revalued),
+                    ..Default::default()
+                };
+
+                let updated_variant = self.product_variant_repository.update(variant_id, variant_active_model).await?;
+
+                self.audit_log_repository.record(NewAuditLog {
+                    actor_id,
+                    entity: ENTITY_PRODUCT_VARIANT,
+                    entity_id: variant_id,
+                    action: ACTION_UPDATE,
+                    before: Some(existing),
+                    after: Some(json!(updated_variant)),
+                }).await?;
+            }
+            None => {
+                let product_active_model = ActiveModel {
+                    id: Set(product_id),
+                    hpp_amount: Set(revalued),
+                    ..Default::default()
+                };
+
+                let updated_product = self.product_repository.update(product_id, product_active_model).await?;
+
+                self.audit_log_repository.record(NewAuditLog {
+                    actor_id,
+                    entity: ENTITY_PRODUCT,
+                    entity_id: product_id,
+                    action: ACTION_UPDATE,
+                    before: Some(existing),
+                    after: Some(json!(updated_product)),
+                }).await?;
+
+                self.record_price(&updated_product, actor_id).await?;
+            }
+        }
+
+        self.refresh_dependents(product_id, actor_id).await
+    }
 }
\ No newline at end of file
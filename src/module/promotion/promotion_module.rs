@@ -0,0 +1,7 @@
+use axum::Router;
+
+use crate::module::promotion::v1::promotion_handler;
+
+pub fn configure() -> Router {
+	Router::new().nest("/api/v1/promotion", promotion_handler::routes())
+}
@@ -0,0 +1 @@
+pub mod promotion;
\ No newline at end of file
@@ -0,0 +1,87 @@
+use sea_orm::entity::prelude::*;
+use serde::{Serialize, Deserialize};
+use chrono::{Utc, DateTime, NaiveDate, NaiveDateTime, NaiveTime};
+
+use crate::pkg::discount::{self, KIND_BUY_X_GET_Y};
+use crate::pkg::quantity;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "promotions")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = true)]
+    pub id: i32,
+    pub name: String,
+    // "percentage", "fixed" or "buy_x_get_y"
+    pub kind: String,
+    // Percent for percentage, rupiah for fixed
+    pub value: i64,
+    pub buy_qty: Option<i32>,
+    pub get_qty: Option<i32>,
+    // Only sales of this product, or of products in this category; any product when both are unset
+    pub product_id: Option<i32>,
+    pub category_id: Option<i32>,
+    // Least the line has to be worth before the discount
+    pub min_purchase_amount: i64,
+    pub starts_on: Option<NaiveDate>,
+    pub ends_on: Option<NaiveDate>,
+    // Daily happy hour in local time, wrapping past midnight when `end_time` is earlier
+    pub start_time: Option<NaiveTime>,
+    pub end_time: Option<NaiveTime>,
+    pub active: bool,
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+impl Model {
+    /// Whether the promotion runs at `local`, the till's wall-clock time
+    pub fn is_running_at(&self, local: NaiveDateTime) -> bool {
+        let date = local.date();
+        let time = local.time();
+
+        if !self.active
+            || self.starts_on.is_some_and(|starts_on| date < starts_on)
+            || self.ends_on.is_some_and(|ends_on| date > ends_on)
+        {
+            return false;
+        }
+
+        match (self.start_time, self.end_time) {
+            (Some(start_time), Some(end_time)) if start_time <= end_time => start_time <= time && time < end_time,
+            (Some(start_time), Some(end_time)) => time >= start_time || time < end_time,
+            (Some(start_time), None) => time >= start_time,
+            (None, Some(end_time)) => time < end_time,
+            (None, None) => true,
+        }
+    }
+
+    /// Whether the promotion applies to a product in `category_id`; `category_ids` are the promotion's category and those below it
+    pub fn covers(&self, product_id: i32, category_id: Option<i32>, category_ids: &[i32]) -> bool {
+        self.product_id.is_none_or(|id| id == product_id)
+            && (self.category_id.is_none() || category_id.is_some_and(|id| category_ids.contains(&id)))
+    }
+
+    /// Money taken off `qty` units at `unit_price`, nothing below the minimum purchase
+    pub fn discount(&self, unit_price: i64, qty: Decimal) -> i64 {
+        let gross = quantity::line_amount(unit_price, qty);
+        if gross < self.min_purchase_amount {
+            return 0;
+        }
+
+        if self.kind == KIND_BUY_X_GET_Y {
+            return discount::buy_x_get_y(self.buy_qty.unwrap_or_default(), self.get_qty.unwrap_or_default(), unit_price, qty);
+        }
+        discount::amount(&self.kind, self.value, unit_price, qty)
+    }
+}
+
+#[derive(Copy, Clone, Debug, EnumIter)]
+pub enum Relation {}
+
+impl RelationTrait for Relation {
+    fn def(&self) -> RelationDef {
+        unimplemented!()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
\ No newline at end of file
@@ -0,0 +1,56 @@
+use chrono::{NaiveDate, NaiveTime};
+use validator::{Validate, ValidationError};
+use serde::{Serialize, Deserialize};
+
+use crate::pkg::discount::KINDS;
+
+#[derive(Debug, Serialize, Deserialize, Validate)]
+pub struct CreatePromotionRequest {
+    #[validate(length(min = 1, max = 255, message = "Name must be between 1 and 255 characters"))]
+    pub name: String,
+
+    // "percentage", "fixed" or "buy_x_get_y"
+    #[validate(custom = "validate_kind")]
+    pub kind: String,
+
+    // Percent (1-100) for percentage, rupiah off each unit for fixed; unused by buy X get Y
+    #[validate(range(min = 0, message = "Value must not be negative"))]
+    pub value: Option<i64>,
+
+    #[validate(range(min = 1, message = "Buy quantity must be positive"))]
+    pub buy_qty: Option<i32>,
+
+    #[validate(range(min = 1, message = "Get quantity must be positive"))]
+    pub get_qty: Option<i32>,
+
+    #[validate(range(min = 1, message = "Product ID must be positive"))]
+    pub product_id: Option<i32>,
+
+    #[validate(range(min = 1, message = "Category ID must be positive"))]
+    pub category_id: Option<i32>,
+
+    #[validate(range(min = 0, message = "Minimum purchase must not be negative"))]
+    pub min_purchase_amount: Option<i64>,
+
+    pub starts_on: Option<NaiveDate>,
+
+    pub ends_on: Option<NaiveDate>,
+
+    // Happy hour in the till's local time, e.g. "15:00:00" to "17:00:00"
+    pub start_time: Option<NaiveTime>,
+
+    pub end_time: Option<NaiveTime>,
+
+    // Defaults to true
+    pub active: Option<bool>,
+}
+
+pub(crate) fn validate_kind(kind: &str) -> Result<(), ValidationError> {
+    if KINDS.contains(&kind) {
+        Ok(())
+    } else {
+        let mut error = ValidationError::new("kind");
+        error.message = Some(format!("Kind must be one of: {}", KINDS.join(", ")).into());
+        Err(error)
+    }
+}
@@ -0,0 +1,3 @@
+pub mod create_promotion_request;
+pub mod update_promotion_request;
+pub mod list_promotion_request;
\ No newline at end of file
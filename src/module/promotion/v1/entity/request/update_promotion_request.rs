@@ -0,0 +1,52 @@
+use chrono::{NaiveDate, NaiveTime};
+use validator::{Validate};
+use serde::{Serialize, Deserialize};
+
+use crate::module::promotion::v1::entity::request::create_promotion_request::validate_kind;
+use crate::pkg::helper::deserialize_some;
+
+// Omitted fields are kept, null clears the optional ones
+#[derive(Debug, Serialize, Deserialize, Validate)]
+pub struct UpdatePromotionRequest {
+    #[validate(length(min = 1, max = 255, message = "Name must be between 1 and 255 characters"))]
+    pub name: Option<String>,
+
+    #[validate(custom = "validate_kind")]
+    pub kind: Option<String>,
+
+    #[validate(range(min = 0, message = "Value must not be negative"))]
+    pub value: Option<i64>,
+
+    #[validate(range(min = 1, message = "Buy quantity must be positive"))]
+    #[serde(default, deserialize_with = "deserialize_some")]
+    pub buy_qty: Option<Option<i32>>,
+
+    #[validate(range(min = 1, message = "Get quantity must be positive"))]
+    #[serde(default, deserialize_with = "deserialize_some")]
+    pub get_qty: Option<Option<i32>>,
+
+    #[validate(range(min = 1, message = "Product ID must be positive"))]
+    #[serde(default, deserialize_with = "deserialize_some")]
+    pub product_id: Option<Option<i32>>,
+
+    #[validate(range(min = 1, message = "Category ID must be positive"))]
+    #[serde(default, deserialize_with = "deserialize_some")]
+    pub category_id: Option<Option<i32>>,
+
+    #[validate(range(min = 0, message = "Minimum purchase must not be negative"))]
+    pub min_purchase_amount: Option<i64>,
+
+    #[serde(default, deserialize_with = "deserialize_some")]
+    pub starts_on: Option<Option<NaiveDate>>,
+
+    #[serde(default, deserialize_with = "deserialize_some")]
+    pub ends_on: Option<Option<NaiveDate>>,
+
+    #[serde(default, deserialize_with = "deserialize_some")]
+    pub start_time: Option<Option<NaiveTime>>,
+
+    #[serde(default, deserialize_with = "deserialize_some")]
+    pub end_time: Option<Option<NaiveTime>>,
+
+    pub active: Option<bool>,
+}
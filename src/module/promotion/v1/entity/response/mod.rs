@@ -0,0 +1 @@
+pub mod promotion_response;
\ No newline at end of file
@@ -0,0 +1,45 @@
+use serde::{Serialize, Deserialize};
+use chrono::{Utc, DateTime, NaiveDate, NaiveTime};
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PromotionResponse {
+    pub id: i32,
+    pub name: String,
+    pub kind: String,
+    pub value: i64,
+    pub buy_qty: Option<i32>,
+    pub get_qty: Option<i32>,
+    pub product_id: Option<i32>,
+    pub category_id: Option<i32>,
+    pub min_purchase_amount: i64,
+    pub starts_on: Option<NaiveDate>,
+    pub ends_on: Option<NaiveDate>,
+    pub start_time: Option<NaiveTime>,
+    pub end_time: Option<NaiveTime>,
+    pub active: bool,
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl From<crate::module::promotion::v1::entity::model::promotion::Model> for PromotionResponse {
+    fn from(model: crate::module::promotion::v1::entity::model::promotion::Model) -> Self {
+        Self {
+            id: model.id,
+            name: model.name,
+            kind: model.kind,
+            value: model.value,
+            buy_qty: model.buy_qty,
+            get_qty: model.get_qty,
+            product_id: model.product_id,
+            category_id: model.category_id,
+            min_purchase_amount: model.min_purchase_amount,
+            starts_on: model.starts_on,
+            ends_on: model.ends_on,
+            start_time: model.start_time,
+            end_time: model.end_time,
+            active: model.active,
+            created_at: model.created_at,
+            updated_at: model.updated_at,
+        }
+    }
+}
\ No newline at end of file
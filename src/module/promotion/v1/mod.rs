@@ -0,0 +1,3 @@
+pub mod entity;
+pub mod promotion_usecase;
+pub mod promotion_handler;
\ No newline at end of file
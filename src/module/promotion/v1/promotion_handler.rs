@@ -0,0 +1,113 @@
+use axum::{
+	extract::{Extension, OriginalUri, Path, Query}, 
+    http::StatusCode, 
+    response::IntoResponse, 
+    routing::{get, post, put, delete}, 
+    Json, 
+    Router
+};
+use serde_json::json;
+use validator::Validate;
+use std::sync::Arc;
+
+use crate::module::promotion::v1::entity::{
+    request::{
+        create_promotion_request::CreatePromotionRequest,
+        update_promotion_request::UpdatePromotionRequest,
+        list_promotion_request::GetListPromotionQuery,
+    },
+};
+use crate::pkg::auth::{AuthUser, ROLE_OWNER, ROLE_SUPERVISOR};
+use crate::pkg::custom_error::AppError;
+use crate::pkg::response::ApiResponse;
+use crate::di::AppModule;
+
+
+pub fn routes() -> Router {
+	Router::new()
+		.route("/", post(create_promotion))
+		.route("/", get(list_promotions))
+		.route("/:id", get(get_promotion))
+		.route("/:id", put(update_promotion))
+		.route("/:id", delete(delete_promotion))
+}
+
+async fn create_promotion(
+    Extension(app_module): Extension<Arc<AppModule>>,
+    auth_user: AuthUser,
+    Json(payload): Json<CreatePromotionRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    auth_user.require_role(&[ROLE_OWNER, ROLE_SUPERVISOR])?;
+
+    payload.validate().map_err(|e| {
+        AppError::BadRequest(e.to_string())
+    })?;
+
+    let promotion = app_module.promotion_usecase.create_promotion(payload, Some(auth_user.id)).await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(ApiResponse::success("Promotion created successfully", Some(json!(promotion)), None)),
+    ))
+}
+
+async fn get_promotion(
+    Extension(app_module): Extension<Arc<AppModule>>,
+    Path(id): Path<i32>,
+) -> Result<impl IntoResponse, AppError> {
+    let promotion = app_module.promotion_usecase.get_promotion(id).await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse::success("Promotion retrieved successfully", Some(json!(promotion)), None)),
+    ))
+}
+
+async fn list_promotions(
+    Extension(app_module): Extension<Arc<AppModule>>,
+    OriginalUri(uri): OriginalUri,
+    Query(query): Query<GetListPromotionQuery>,
+) -> Result<impl IntoResponse, AppError> {
+    let promotions = app_module.promotion_usecase.list_promotions(query).await?;
+
+    Ok((
+        StatusCode::OK,
+        promotions.headers(&uri),
+        Json(ApiResponse::success("Promotions retrieved successfully", Some(json!(promotions.data)), Some(json!(promotions.meta)))),
+    ))
+}
+
+async fn update_promotion(
+    Extension(app_module): Extension<Arc<AppModule>>,
+    auth_user: AuthUser,
+    Path(id): Path<i32>,
+    Json(payload): Json<UpdatePromotionRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    auth_user.require_role(&[ROLE_OWNER, ROLE_SUPERVISOR])?;
+
+    payload.validate().map_err(|e| {
+        AppError::BadRequest(e.to_string())
+    })?;
+
+    let promotion = app_module.promotion_usecase.update_promotion(id, payload, Some(auth_user.id)).await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse::success("Promotion updated successfully", Some(json!(promotion)), None)),
+    ))
+}
+
+async fn delete_promotion(
+    Extension(app_module): Extension<Arc<AppModule>>,
+    auth_user: AuthUser,
+    Path(id): Path<i32>,
+) -> Result<impl IntoResponse, AppError> {
+    auth_user.require_role(&[ROLE_OWNER, ROLE_SUPERVISOR])?;
+
+    app_module.promotion_usecase.delete_promotion(id, Some(auth_user.id)).await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse::success("Promotion deleted successfully", Some(json!({})), None)),
+    ))
+}
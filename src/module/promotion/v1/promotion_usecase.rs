@@ -0,0 +1,260 @@
+use async_trait::async_trait;
+use crate::module::promotion::v1::entity::{
+    request::{
+        create_promotion_request::CreatePromotionRequest,
+        update_promotion_request::UpdatePromotionRequest,
+        list_promotion_request::GetListPromotionQuery,
+    },
+    response::promotion_response::PromotionResponse,
+    model::promotion::{ActiveModel, Model as PromotionModel},
+};
+use crate::module::repository::promotion_repository::PromotionRepository;
+use crate::module::repository::product_repository::ProductRepository;
+use crate::module::repository::category_repository::CategoryRepository;
+use crate::module::repository::audit_log_repository::{AuditLogRepository, NewAuditLog};
+use crate::pkg::audit::{ENTITY_PROMOTION, ACTION_CREATE, ACTION_UPDATE, ACTION_DELETE};
+use crate::pkg::custom_error::AppError;
+use crate::pkg::discount::{KIND_BUY_X_GET_Y, KIND_PERCENTAGE};
+use crate::pkg::pagination::Paginated;
+use sea_orm::Set;
+use serde_json::json;
+use std::sync::Arc;
+use chrono::Utc;
+
+#[async_trait]
+pub trait PromotionUsecase: Send + Sync {
+    async fn create_promotion(&self, request: CreatePromotionRequest, actor_id: Option<i32>) -> Result<PromotionResponse, AppError>;
+    async fn get_promotion(&self, id: i32) -> Result<PromotionResponse, AppError>;
+    async fn list_promotions(&self, query: GetListPromotionQuery) -> Result<Paginated<PromotionResponse>, AppError>;
+    async fn update_promotion(&self, id: i32, request: UpdatePromotionRequest, actor_id: Option<i32>) -> Result<PromotionResponse, AppError>;
+    async fn delete_promotion(&self, id: i32, actor_id: Option<i32>) -> Result<(), AppError>;
+}
+
+pub struct PromotionUsecaseImpl {
+    promotion_repository: Arc<dyn PromotionRepository>,
+    product_repository: Arc<dyn ProductRepository>,
+    category_repository: Arc<dyn CategoryRepository>,
+    audit_log_repository: Arc<dyn AuditLogRepository>,
+}
+
+impl PromotionUsecaseImpl {
+    pub fn new(
+        promotion_repository: Arc<dyn PromotionRepository>,
+        product_repository: Arc<dyn ProductRepository>,
+        category_repository: Arc<dyn CategoryRepository>,
+        audit_log_repository: Arc<dyn AuditLogRepository>,
+    ) -> Self {
+        Self { promotion_repository, product_repository, category_repository, audit_log_repository }
+    }
+
+    async fn find_promotion(&self, id: i32) -> Result<PromotionModel, AppError> {
+        self.promotion_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Promotion with id {} not found", id)))
+    }
+
+    /// Reject a rule that could never apply or would give away more than a line is worth
+    async fn check_rule(&self, promotion: &PromotionModel) -> Result<(), AppError> {
+        if promotion.kind == KIND_BUY_X_GET_Y {
+            if promotion.buy_qty.is_none() || promotion.get_qty.is_none() {
+                return Err(AppError::BadRequest("buy_qty and get_qty are required for buy X get Y".to_string()));
+            }
+        } else if promotion.value <= 0 {
+            return Err(AppError::BadRequest("Value must be positive".to_string()));
+        }
+        if promotion.kind == KIND_PERCENTAGE && promotion.value > 100 {
+            return Err(AppError::BadRequest("A percentage discount cannot exceed 100".to_string()));
+        }
+
+        if let (Some(starts_on), Some(ends_on)) = (promotion.starts_on, promotion.ends_on) {
+            if ends_on < starts_on {
+                return Err(AppError::BadRequest("ends_on cannot be before starts_on".to_string()));
+            }
+        }
+        if promotion.start_time.is_some() && promotion.start_time == promotion.end_time {
+            return Err(AppError::BadRequest("start_time and end_time cannot be the same".to_string()));
+        }
+
+        if let Some(product_id) = promotion.product_id {
+            if self.product_repository.find_by_id(product_id).await?.is_none() {
+                return Err(AppError::BadRequest(format!("Product with id {} not found", product_id)));
+            }
+        }
+        if let Some(category_id) = promotion.category_id {
+            if self.category_repository.find_by_id(category_id).await?.is_none() {
+                return Err(AppError::BadRequest(format!("Category with id {} not found", category_id)));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl PromotionUsecase for PromotionUsecaseImpl {
+    async fn create_promotion(&self, request: CreatePromotionRequest, actor_id: Option<i32>) -> Result<PromotionResponse, AppError> {
+        let promotion = PromotionModel {
+            id: 0,
+            name: request.name,
+            kind: request.kind,
+            value: request.value.unwrap_or_default(),
+            buy_qty: request.buy_qty,
+            get_qty: request.get_qty,
+            product_id: request.product_id,
+            category_id: request.category_id,
+            min_purchase_amount: request.min_purchase_amount.unwrap_or_default(),
+            starts_on: request.starts_on,
+            ends_on: request.ends_on,
+            start_time: request.start_time,
+            end_time: request.end_time,
+            active: request.active.unwrap_or(true),
+            created_at: Some(Utc::now()),
+            updated_at: Some(Utc::now()),
+            deleted_at: None,
+        };
+        self.check_rule(&promotion).await?;
+
+        // Create active model
+        let promotion_active_model = ActiveModel {
+            name: Set(promotion.name),
+            kind: Set(promotion.kind),
+            value: Set(promotion.value),
+            buy_qty: Set(promotion.buy_qty),
+            get_qty: Set(promotion.get_qty),
+            product_id: Set(promotion.product_id),
+            category_id: Set(promotion.category_id),
+            min_purchase_amount: Set(promotion.min_purchase_amount),
+            starts_on: Set(promotion.starts_on),
+            ends_on: Set(promotion.ends_on),
+            start_time: Set(promotion.start_time),
+            end_time: Set(promotion.end_time),
+            active: Set(promotion.active),
+            created_at: Set(promotion.created_at),
+            updated_at: Set(promotion.updated_at),
+            deleted_at: Set(None),
+            ..Default::default()
+        };
+
+        let created_promotion = self.promotion_repository.create(promotion_active_model).await?;
+
+        self.audit_log_repository.record(NewAuditLog {
+            actor_id,
+            entity: ENTITY_PROMOTION,
+            entity_id: created_promotion.id,
+            action: ACTION_CREATE,
+            before: None,
+            after: Some(json!(created_promotion)),
+        }).await?;
+
+        Ok(PromotionResponse::from(created_promotion))
+    }
+
+    async fn get_promotion(&self, id: i32) -> Result<PromotionResponse, AppError> {
+        let promotion = self.find_promotion(id).await?;
+        Ok(PromotionResponse::from(promotion))
+    }
+
+    async fn list_promotions(&self, query: GetListPromotionQuery) -> Result<Paginated<PromotionResponse>, AppError> {
+        let paginated_result = self.promotion_repository.find_with_pagination(query).await?;
+        Ok(Paginated::from(paginated_result))
+    }
+
+    async fn update_promotion(&self, id: i32, request: UpdatePromotionRequest, actor_id: Option<i32>) -> Result<PromotionResponse, AppError> {
+        // Check if promotion exists
+        let existing_promotion = self.find_promotion(id).await?;
+
+        // The rule as it will stand, checked as a whole
+        let mut promotion = existing_promotion.clone();
+        if let Some(name) = request.name {
+            promotion.name = name;
+        }
+        if let Some(kind) = request.kind {
+            promotion.kind = kind;
+        }
+        if let Some(value) = request.value {
+            promotion.value = value;
+        }
+        if let Some(buy_qty) = request.buy_qty {
+            promotion.buy_qty = buy_qty;
+        }
+        if let Some(get_qty) = request.get_qty {
+            promotion.get_qty = get_qty;
+        }
+        if let Some(product_id) = request.product_id {
+            promotion.product_id = product_id;
+        }
+        if let Some(category_id) = request.category_id {
+            promotion.category_id = category_id;
+        }
+        if let Some(min_purchase_amount) = request.min_purchase_amount {
+            promotion.min_purchase_amount = min_purchase_amount;
+        }
+        if let Some(starts_on) = request.starts_on {
+            promotion.starts_on = starts_on;
+        }
+        if let Some(ends_on) = request.ends_on {
+            promotion.ends_on = ends_on;
+        }
+        if let Some(start_time) = request.start_time {
+            promotion.start_time = start_time;
+        }
+        if let Some(end_time) = request.end_time {
+            promotion.end_time = end_time;
+        }
+        if let Some(active) = request.active {
+            promotion.active = active;
+        }
+        self.check_rule(&promotion).await?;
+
+        let promotion_active_model = ActiveModel {
+            id: Set(id),
+            name: Set(promotion.name),
+            kind: Set(promotion.kind),
+            value: Set(promotion.value),
+            buy_qty: Set(promotion.buy_qty),
+            get_qty: Set(promotion.get_qty),
+            product_id: Set(promotion.product_id),
+            category_id: Set(promotion.category_id),
+            min_purchase_amount: Set(promotion.min_purchase_amount),
+            starts_on: Set(promotion.starts_on),
+            ends_on: Set(promotion.ends_on),
+            start_time: Set(promotion.start_time),
+            end_time: Set(promotion.end_time),
+            active: Set(promotion.active),
+            ..Default::default()
+        };
+
+        let updated_promotion = self.promotion_repository.update(id, promotion_active_model).await?;
+
+        self.audit_log_repository.record(NewAuditLog {
+            actor_id,
+            entity: ENTITY_PROMOTION,
+            entity_id: id,
+            action: ACTION_UPDATE,
+            before: Some(json!(existing_promotion)),
+            after: Some(json!(updated_promotion)),
+        }).await?;
+
+        Ok(PromotionResponse::from(updated_promotion))
+    }
+
+    async fn delete_promotion(&self, id: i32, actor_id: Option<i32>) -> Result<(), AppError> {
+        // Check if promotion exists
+        let existing_promotion = self.find_promotion(id).await?;
+
+        // Soft delete; past sales keep pointing at it
+        let deleted_promotion = self.promotion_repository.delete(id).await?;
+
+        self.audit_log_repository.record(NewAuditLog {
+            actor_id,
+            entity: ENTITY_PROMOTION,
+            entity_id: id,
+            action: ACTION_DELETE,
+            before: Some(json!(existing_promotion)),
+            after: Some(json!(deleted_promotion)),
+        }).await?;
+
+        Ok(())
+    }
+}
\ No newline at end of file
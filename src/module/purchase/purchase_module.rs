@@ -0,0 +1,7 @@
+use axum::Router;
+
+use crate::module::purchase::v1::purchase_handler;
+
+pub fn configure() -> Router {
+	Router::new().nest("/api/v1/purchase-order", purchase_handler::routes())
+}
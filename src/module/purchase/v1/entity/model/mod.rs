@@ -0,0 +1,2 @@
+pub mod purchase_order;
+pub mod purchase_order_item;
\ No newline at end of file
@@ -0,0 +1,53 @@
+use sea_orm::entity::prelude::*;
+use serde::{Serialize, Deserialize};
+use chrono::{Utc, DateTime};
+
+pub const STATUS_DRAFT: &str = "draft";
+pub const STATUS_ORDERED: &str = "ordered";
+pub const STATUS_RECEIVED: &str = "received";
+pub const STATUS_CANCELLED: &str = "cancelled";
+
+pub const PAYMENT_CASH: &str = "cash";
+pub const PAYMENT_PAYABLE: &str = "payable";
+pub const PAYMENT_METHODS: &[&str] = &[PAYMENT_CASH, PAYMENT_PAYABLE];
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "purchase_orders")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = true)]
+    pub id: i32,
+    pub supplier_id: i32,
+    pub status: String,
+    pub note: Option<String>,
+    pub total_amount: i64,
+    // Set on receipt
+    pub payment_method: Option<String>,
+    // Cashflow "out" entry posted when the order was paid
+    pub cashflow_id: Option<i32>,
+    pub ordered_at: Option<DateTime<Utc>>,
+    pub received_at: Option<DateTime<Utc>>,
+    pub paid_at: Option<DateTime<Utc>>,
+    pub cancelled_at: Option<DateTime<Utc>>,
+    pub created_by: Option<i32>,
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+impl Model {
+    /// Items and supplier can still change
+    pub fn is_editable(&self) -> bool {
+        self.status == STATUS_DRAFT
+    }
+}
+
+#[derive(Copy, Clone, Debug, EnumIter)]
+pub enum Relation {}
+
+impl RelationTrait for Relation {
+    fn def(&self) -> RelationDef {
+        unimplemented!()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
\ No newline at end of file
@@ -0,0 +1,31 @@
+use sea_orm::entity::prelude::*;
+use serde::{Serialize, Deserialize};
+use chrono::{Utc, DateTime};
+
+/// `qty` of a product or variant bought in `unit`, which holds `unit_factor` of its base unit
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "purchase_order_items")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = true)]
+    pub id: i32,
+    pub purchase_order_id: i32,
+    pub product_id: i32,
+    pub variant_id: Option<i32>,
+    pub qty: Decimal,
+    pub unit: String,
+    pub unit_factor: Decimal,
+    // Per `unit`
+    pub unit_cost: i32,
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter)]
+pub enum Relation {}
+
+impl RelationTrait for Relation {
+    fn def(&self) -> RelationDef {
+        unimplemented!()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
\ No newline at end of file
@@ -0,0 +1,40 @@
+use rust_decimal::Decimal;
+use validator::{Validate};
+use serde::{Serialize, Deserialize};
+
+use crate::pkg::quantity::validate_positive_qty;
+
+#[derive(Debug, Serialize, Deserialize, Validate)]
+pub struct CreatePurchaseOrderRequest {
+    #[validate(range(min = 1, message = "Supplier ID must be positive"))]
+    pub supplier_id: i32,
+
+    #[validate(length(min = 1, max = 500, message = "Note must be between 1 and 500 characters"))]
+    pub note: Option<String>,
+
+    #[validate(length(min = 1, max = 200, message = "Between 1 and 200 items per purchase order"))]
+    #[validate]
+    pub items: Vec<PurchaseOrderItemRequest>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Validate)]
+pub struct PurchaseOrderItemRequest {
+    #[validate(range(min = 1, message = "Product ID must be positive"))]
+    pub product_id: i32,
+
+    // Required when the product has variants
+    #[validate(range(min = 1, message = "Variant ID must be positive"))]
+    pub variant_id: Option<i32>,
+
+    // In `unit`
+    #[validate(custom = "validate_positive_qty")]
+    pub qty: Decimal,
+
+    // One of the product's units, defaults to its base unit
+    #[validate(length(min = 1, max = 20, message = "Unit must be between 1 and 20 characters"))]
+    pub unit: Option<String>,
+
+    // Cost of one `unit`
+    #[validate(range(min = 0, message = "Unit cost must be non-negative"))]
+    pub unit_cost: i32,
+}
@@ -0,0 +1,36 @@
+use validator::{Validate};
+use serde::{Serialize, Deserialize};
+
+#[derive(Debug, Serialize, Deserialize, Validate)]
+pub struct GetListPurchaseOrderQuery {
+    pub limit: Option<String>,
+
+    pub page: Option<String>,
+
+    pub order_by: Option<String>,
+
+    pub direction: Option<String>,
+
+    // Switches to keyset pagination; pass an empty value for the first page
+    pub cursor: Option<String>,
+
+    // "false" skips the COUNT query
+    pub include_total: Option<String>,
+
+    // Comma separated statuses
+    pub status: Option<String>,
+
+    // Comma separated supplier ids
+    pub supplier_id: Option<String>,
+
+    // "true" for received orders still waiting to be paid
+    pub unpaid: Option<String>,
+
+    // YYYY-MM-DD (interpreted in `timezone`) or RFC 3339
+    pub created_from: Option<String>,
+
+    pub created_to: Option<String>,
+
+    // UTC offset such as +07:00, defaults to UTC
+    pub timezone: Option<String>,
+}
@@ -0,0 +1,4 @@
+pub mod create_purchase_order_request;
+pub mod update_purchase_order_request;
+pub mod receive_purchase_order_request;
+pub mod list_purchase_order_request;
\ No newline at end of file
@@ -0,0 +1,38 @@
+use chrono::NaiveDate;
+use validator::{Validate, ValidationError};
+use serde::{Serialize, Deserialize};
+
+use crate::module::purchase::v1::entity::model::purchase_order::PAYMENT_METHODS;
+
+#[derive(Debug, Serialize, Deserialize, Validate)]
+pub struct ReceivePurchaseOrderRequest {
+    // "cash" posts a cashflow entry now, "payable" leaves it to be paid later
+    #[validate(custom = "validate_payment_method")]
+    pub payment_method: String,
+
+    // Expiry of perishable lines; other lines come in without a lot
+    #[serde(default)]
+    #[validate]
+    pub lots: Vec<ReceiveLotRequest>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Validate)]
+pub struct ReceiveLotRequest {
+    #[validate(range(min = 1, message = "Item ID must be positive"))]
+    pub item_id: i32,
+
+    #[validate(length(min = 1, max = 64, message = "Lot code must be between 1 and 64 characters"))]
+    pub lot_code: Option<String>,
+
+    pub expires_at: NaiveDate,
+}
+
+fn validate_payment_method(payment_method: &str) -> Result<(), ValidationError> {
+    if PAYMENT_METHODS.contains(&payment_method) {
+        Ok(())
+    } else {
+        let mut error = ValidationError::new("payment_method");
+        error.message = Some(format!("Payment method must be one of: {}", PAYMENT_METHODS.join(", ")).into());
+        Err(error)
+    }
+}
@@ -0,0 +1,21 @@
+use validator::{Validate};
+use serde::{Serialize, Deserialize};
+
+use crate::module::purchase::v1::entity::request::create_purchase_order_request::PurchaseOrderItemRequest;
+use crate::pkg::helper::deserialize_some;
+
+// Only draft orders can be changed
+#[derive(Debug, Serialize, Deserialize, Validate)]
+pub struct UpdatePurchaseOrderRequest {
+    #[validate(range(min = 1, message = "Supplier ID must be positive"))]
+    pub supplier_id: Option<i32>,
+
+    // Omitted keeps the note, null removes it
+    #[serde(default, deserialize_with = "deserialize_some")]
+    pub note: Option<Option<String>>,
+
+    // Replaces all items
+    #[validate(length(min = 1, max = 200, message = "Between 1 and 200 items per purchase order"))]
+    #[validate]
+    pub items: Option<Vec<PurchaseOrderItemRequest>>,
+}
@@ -0,0 +1 @@
+pub mod purchase_order_response;
\ No newline at end of file
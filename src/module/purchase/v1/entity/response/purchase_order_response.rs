@@ -0,0 +1,74 @@
+use rust_decimal::Decimal;
+use serde::{Serialize, Deserialize};
+use chrono::{Utc, DateTime};
+
+use crate::pkg::quantity;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PurchaseOrderItemResponse {
+    pub id: i32,
+    pub product_id: i32,
+    pub variant_id: Option<i32>,
+    pub qty: Decimal,
+    pub unit: String,
+    pub unit_factor: Decimal,
+    pub unit_cost: i32,
+    // `unit_cost` times `qty`
+    pub total_amount: i64,
+}
+
+impl From<crate::module::purchase::v1::entity::model::purchase_order_item::Model> for PurchaseOrderItemResponse {
+    fn from(model: crate::module::purchase::v1::entity::model::purchase_order_item::Model) -> Self {
+        Self {
+            id: model.id,
+            product_id: model.product_id,
+            variant_id: model.variant_id,
+            qty: model.qty,
+            unit: model.unit,
+            unit_factor: model.unit_factor,
+            unit_cost: model.unit_cost,
+            total_amount: quantity::line_amount(i64::from(model.unit_cost), model.qty),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PurchaseOrderResponse {
+    pub id: i32,
+    pub supplier_id: i32,
+    pub status: String,
+    pub note: Option<String>,
+    pub total_amount: i64,
+    pub payment_method: Option<String>,
+    pub cashflow_id: Option<i32>,
+    pub items: Vec<PurchaseOrderItemResponse>,
+    pub ordered_at: Option<DateTime<Utc>>,
+    pub received_at: Option<DateTime<Utc>>,
+    pub paid_at: Option<DateTime<Utc>>,
+    pub cancelled_at: Option<DateTime<Utc>>,
+    pub created_by: Option<i32>,
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl From<crate::module::purchase::v1::entity::model::purchase_order::Model> for PurchaseOrderResponse {
+    fn from(model: crate::module::purchase::v1::entity::model::purchase_order::Model) -> Self {
+        Self {
+            id: model.id,
+            supplier_id: model.supplier_id,
+            status: model.status,
+            note: model.note,
+            total_amount: model.total_amount,
+            payment_method: model.payment_method,
+            cashflow_id: model.cashflow_id,
+            items: Vec::new(),
+            ordered_at: model.ordered_at,
+            received_at: model.received_at,
+            paid_at: model.paid_at,
+            cancelled_at: model.cancelled_at,
+            created_by: model.created_by,
+            created_at: model.created_at,
+            updated_at: model.updated_at,
+        }
+    }
+}
@@ -0,0 +1,3 @@
+pub mod entity;
+pub mod purchase_usecase;
+pub mod purchase_handler;
\ No newline at end of file
@@ -0,0 +1,175 @@
+use axum::{
+	extract::{Extension, OriginalUri, Path, Query}, 
+    http::StatusCode, 
+    response::IntoResponse, 
+    routing::{get, post, put, delete}, 
+    Json, 
+    Router
+};
+use serde_json::json;
+use validator::Validate;
+use std::sync::Arc;
+
+use crate::module::purchase::v1::entity::{
+    request::{
+        create_purchase_order_request::CreatePurchaseOrderRequest,
+        update_purchase_order_request::UpdatePurchaseOrderRequest,
+        receive_purchase_order_request::ReceivePurchaseOrderRequest,
+        list_purchase_order_request::GetListPurchaseOrderQuery,
+    },
+};
+use crate::pkg::auth::{AuthUser, ROLE_OWNER, ROLE_SUPERVISOR};
+use crate::pkg::custom_error::AppError;
+use crate::pkg::response::ApiResponse;
+use crate::di::AppModule;
+
+
+pub fn routes() -> Router {
+	Router::new()
+		.route("/", post(create_purchase_order))
+		.route("/", get(list_purchase_orders))
+		.route("/:id", get(get_purchase_order))
+		.route("/:id", put(update_purchase_order))
+		.route("/:id", delete(delete_purchase_order))
+		.route("/:id/order", post(order_purchase_order))
+		.route("/:id/cancel", post(cancel_purchase_order))
+		.route("/:id/receive", post(receive_purchase_order))
+		.route("/:id/pay", post(pay_purchase_order))
+}
+
+async fn create_purchase_order(
+    Extension(app_module): Extension<Arc<AppModule>>,
+    auth_user: Option<AuthUser>,
+    Json(payload): Json<CreatePurchaseOrderRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    payload.validate().map_err(|e| {
+        AppError::BadRequest(e.to_string())
+    })?;
+
+    let purchase_order = app_module.purchase_usecase.create_purchase_order(payload, auth_user.map(|user| user.id)).await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(ApiResponse::success("Purchase order created successfully", Some(json!(purchase_order)), None)),
+    ))
+}
+
+async fn get_purchase_order(
+    Extension(app_module): Extension<Arc<AppModule>>,
+    Path(id): Path<i32>,
+) -> Result<impl IntoResponse, AppError> {
+    let purchase_order = app_module.purchase_usecase.get_purchase_order(id).await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse::success("Purchase order retrieved successfully", Some(json!(purchase_order)), None)),
+    ))
+}
+
+async fn list_purchase_orders(
+    Extension(app_module): Extension<Arc<AppModule>>,
+    OriginalUri(uri): OriginalUri,
+    Query(query): Query<GetListPurchaseOrderQuery>,
+) -> Result<impl IntoResponse, AppError> {
+    let purchase_orders = app_module.purchase_usecase.list_purchase_orders(query).await?;
+
+    Ok((
+        StatusCode::OK,
+        purchase_orders.headers(&uri),
+        Json(ApiResponse::success("Purchase orders retrieved successfully", Some(json!(purchase_orders.data)), Some(json!(purchase_orders.meta)))),
+    ))
+}
+
+async fn update_purchase_order(
+    Extension(app_module): Extension<Arc<AppModule>>,
+    auth_user: Option<AuthUser>,
+    Path(id): Path<i32>,
+    Json(payload): Json<UpdatePurchaseOrderRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    payload.validate().map_err(|e| {
+        AppError::BadRequest(e.to_string())
+    })?;
+
+    let purchase_order = app_module.purchase_usecase.update_purchase_order(id, payload, auth_user.map(|user| user.id)).await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse::success("Purchase order updated successfully", Some(json!(purchase_order)), None)),
+    ))
+}
+
+async fn order_purchase_order(
+    Extension(app_module): Extension<Arc<AppModule>>,
+    auth_user: Option<AuthUser>,
+    Path(id): Path<i32>,
+) -> Result<impl IntoResponse, AppError> {
+    let purchase_order = app_module.purchase_usecase.order_purchase_order(id, auth_user.map(|user| user.id)).await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse::success("Purchase order placed successfully", Some(json!(purchase_order)), None)),
+    ))
+}
+
+async fn cancel_purchase_order(
+    Extension(app_module): Extension<Arc<AppModule>>,
+    auth_user: AuthUser,
+    Path(id): Path<i32>,
+) -> Result<impl IntoResponse, AppError> {
+    auth_user.require_role(&[ROLE_OWNER, ROLE_SUPERVISOR])?;
+
+    let purchase_order = app_module.purchase_usecase.cancel_purchase_order(id, Some(auth_user.id)).await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse::success("Purchase order cancelled successfully", Some(json!(purchase_order)), None)),
+    ))
+}
+
+async fn receive_purchase_order(
+    Extension(app_module): Extension<Arc<AppModule>>,
+    auth_user: AuthUser,
+    Path(id): Path<i32>,
+    Json(payload): Json<ReceivePurchaseOrderRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    auth_user.require_role(&[ROLE_OWNER, ROLE_SUPERVISOR])?;
+
+    payload.validate().map_err(|e| {
+        AppError::BadRequest(e.to_string())
+    })?;
+
+    let purchase_order = app_module.purchase_usecase.receive_purchase_order(id, payload, Some(auth_user.id)).await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse::success("Purchase order received successfully", Some(json!(purchase_order)), None)),
+    ))
+}
+
+async fn pay_purchase_order(
+    Extension(app_module): Extension<Arc<AppModule>>,
+    auth_user: AuthUser,
+    Path(id): Path<i32>,
+) -> Result<impl IntoResponse, AppError> {
+    auth_user.require_role(&[ROLE_OWNER, ROLE_SUPERVISOR])?;
+
+    let purchase_order = app_module.purchase_usecase.pay_purchase_order(id, Some(auth_user.id)).await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse::success("Purchase order paid successfully", Some(json!(purchase_order)), None)),
+    ))
+}
+
+async fn delete_purchase_order(
+    Extension(app_module): Extension<Arc<AppModule>>,
+    auth_user: Option<AuthUser>,
+    Path(id): Path<i32>,
+) -> Result<impl IntoResponse, AppError> {
+    app_module.purchase_usecase.delete_purchase_order(id, auth_user.map(|user| user.id)).await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse::success("Purchase order deleted successfully", Some(json!({})), None)),
+    ))
+}
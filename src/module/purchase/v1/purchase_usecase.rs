@@ -0,0 +1,513 @@
+use async_trait::async_trait;
+use crate::module::purchase::v1::entity::{
+    request::{
+        create_purchase_order_request::{CreatePurchaseOrderRequest, PurchaseOrderItemRequest},
+        update_purchase_order_request::UpdatePurchaseOrderRequest,
+        receive_purchase_order_request::ReceivePurchaseOrderRequest,
+        list_purchase_order_request::GetListPurchaseOrderQuery,
+    },
+    response::purchase_order_response::{PurchaseOrderResponse, PurchaseOrderItemResponse},
+    model::purchase_order::{
+        ActiveModel, Model as PurchaseOrderModel,
+        STATUS_DRAFT, STATUS_ORDERED, STATUS_RECEIVED, STATUS_CANCELLED, PAYMENT_CASH, PAYMENT_PAYABLE,
+    },
+};
+use crate::module::cashflow::v1::entity::model::cashflow::{
+    ActiveModel as CashflowActiveModel, Model as CashflowModel, CASHFLOW_OUT, RECAP_PURCHASE,
+};
+use crate::module::inventory::v1::entity::model::stock_movement::{MOVEMENT_PURCHASE, REFERENCE_PURCHASE_ORDER};
+use crate::module::repository::purchase_order_repository::PurchaseOrderRepository;
+use crate::module::repository::purchase_order_item_repository::{PurchaseOrderItemRepository, NewPurchaseOrderItem};
+use crate::module::repository::supplier_repository::SupplierRepository;
+use crate::module::repository::product_repository::ProductRepository;
+use crate::module::repository::product_variant_repository::ProductVariantRepository;
+use crate::module::repository::product_unit_repository::ProductUnitRepository;
+use crate::module::repository::recipe_item_repository::RecipeItemRepository;
+use crate::module::repository::bundle_item_repository::BundleItemRepository;
+use crate::module::repository::stock_repository::{StockRepository, NewStockMovement, NewStockLot};
+use crate::module::repository::cashflow_repository::CashflowRepository;
+use crate::module::repository::audit_log_repository::{AuditLogRepository, NewAuditLog};
+use crate::module::product::v1::product_usecase::ProductUsecase;
+use crate::pkg::audit::{ENTITY_PURCHASE_ORDER, ACTION_CREATE, ACTION_UPDATE, ACTION_DELETE};
+use crate::pkg::costing::CostReceipt;
+use crate::pkg::custom_error::AppError;
+use crate::pkg::db;
+use crate::pkg::pagination::Paginated;
+use crate::pkg::quantity;
+use rust_decimal::Decimal;
+use sea_orm::{DatabaseTransaction, Set};
+use serde_json::json;
+use std::collections::HashMap;
+use std::sync::Arc;
+use chrono::Utc;
+
+#[async_trait]
+pub trait PurchaseUsecase: Send + Sync {
+    async fn create_purchase_order(&self, request: CreatePurchaseOrderRequest, actor_id: Option<i32>) -> Result<PurchaseOrderResponse, AppError>;
+    async fn get_purchase_order(&self, id: i32) -> Result<PurchaseOrderResponse, AppError>;
+    async fn list_purchase_orders(&self, query: GetListPurchaseOrderQuery) -> Result<Paginated<PurchaseOrderResponse>, AppError>;
+    async fn update_purchase_order(&self, id: i32, request: UpdatePurchaseOrderRequest, actor_id: Option<i32>) -> Result<PurchaseOrderResponse, AppError>;
+    async fn order_purchase_order(&self, id: i32, actor_id: Option<i32>) -> Result<PurchaseOrderResponse, AppError>;
+    async fn cancel_purchase_order(&self, id: i32, actor_id: Option<i32>) -> Result<PurchaseOrderResponse, AppError>;
+    async fn receive_purchase_order(&self, id: i32, request: ReceivePurchaseOrderRequest, actor_id: Option<i32>) -> Result<PurchaseOrderResponse, AppError>;
+    async fn pay_purchase_order(&self, id: i32, actor_id: Option<i32>) -> Result<PurchaseOrderResponse, AppError>;
+    async fn delete_purchase_order(&self, id: i32, actor_id: Option<i32>) -> Result<(), AppError>;
+}
+
+pub struct PurchaseUsecaseImpl {
+    purchase_order_repository: Arc<dyn PurchaseOrderRepository>,
+    purchase_order_item_repository: Arc<dyn PurchaseOrderItemRepository>,
+    supplier_repository: Arc<dyn SupplierRepository>,
+    product_repository: Arc<dyn ProductRepository>,
+    product_variant_repository: Arc<dyn ProductVariantRepository>,
+    product_unit_repository: Arc<dyn ProductUnitRepository>,
+    recipe_item_repository: Arc<dyn RecipeItemRepository>,
+    bundle_item_repository: Arc<dyn BundleItemRepository>,
+    stock_repository: Arc<dyn StockRepository>,
+    cashflow_repository: Arc<dyn CashflowRepository>,
+    product_usecase: Arc<dyn ProductUsecase>,
+    audit_log_repository: Arc<dyn AuditLogRepository>,
+}
+
+impl PurchaseUsecaseImpl {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        purchase_order_repository: Arc<dyn PurchaseOrderRepository>,
+        purchase_order_item_repository: Arc<dyn PurchaseOrderItemRepository>,
+        supplier_repository: Arc<dyn SupplierRepository>,
+        product_repository: Arc<dyn ProductRepository>,
+        product_variant_repository: Arc<dyn ProductVariantRepository>,
+        product_unit_repository: Arc<dyn ProductUnitRepository>,
+        recipe_item_repository: Arc<dyn RecipeItemRepository>,
+        bundle_item_repository: Arc<dyn BundleItemRepository>,
+        stock_repository: Arc<dyn StockRepository>,
+        cashflow_repository: Arc<dyn CashflowRepository>,
+        product_usecase: Arc<dyn ProductUsecase>,
+        audit_log_repository: Arc<dyn AuditLogRepository>,
+    ) -> Self {
+        Self {
+            purchase_order_repository,
+            purchase_order_item_repository,
+            supplier_repository,
+            product_repository,
+            product_variant_repository,
+            product_unit_repository,
+            recipe_item_repository,
+            bundle_item_repository,
+            stock_repository,
+            cashflow_repository,
+            product_usecase,
+            audit_log_repository,
+        }
+    }
+
+    async fn find_order(&self, id: i32) -> Result<PurchaseOrderModel, AppError> {
+        self.purchase_order_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Purchase order with id {} not found", id)))
+    }
+
+    async fn check_supplier(&self, supplier_id: i32) -> Result<(), AppError> {
+        self.supplier_repository
+            .find_by_id(supplier_id)
+            .await?
+            .ok_or_else(|| AppError::BadRequest(format!("Supplier with id {} not found", supplier_id)))?;
+
+        Ok(())
+    }
+
+    /// Lines to store for the requested items and their total cost
+    async fn resolve_items(&self, items: Vec<PurchaseOrderItemRequest>) -> Result<(Vec<NewPurchaseOrderItem>, i64), AppError> {
+        let mut order_items = Vec::with_capacity(items.len());
+        let mut total_amount: i64 = 0;
+
+        for item in items {
+            let product = self.product_repository
+                .find_by_id(item.product_id)
+                .await?
+                .ok_or_else(|| AppError::BadRequest(format!("Product with id {} not found", item.product_id)))?;
+
+            // Recipe and bundle products hold no stock of their own
+            if !self.recipe_item_repository.find_by_product(product.id).await?.is_empty()
+                || !self.bundle_item_repository.find_by_bundle(product.id).await?.is_empty()
+            {
+                return Err(AppError::BadRequest(format!("Product {} is a recipe or bundle; purchase its parts instead", product.id)));
+            }
+
+            // Products with variants keep stock on the variants only
+            match item.variant_id {
+                Some(variant_id) => {
+                    self.product_variant_repository
+                        .find_by_id(variant_id)
+                        .await?
+                        .filter(|variant| variant.product_id == product.id)
+                        .ok_or_else(|| AppError::BadRequest(format!("Variant with id {} not found for product {}", variant_id, product.id)))?;
+                }
+                None => {
+                    if self.product_variant_repository.count_by_product(product.id).await? > 0 {
+                        return Err(AppError::BadRequest("variant_id is required for products with variants".to_string()));
+                    }
+                }
+            }
+
+            let (unit, unit_factor) = match item.unit.filter(|unit| *unit != product.base_unit) {
+                Some(name) => {
+                    let unit = self.product_unit_repository
+                        .find_by_name(product.id, &name)
+                        .await?
+                        .ok_or_else(|| AppError::BadRequest(format!("Unit {} is not configured for product {}", name, product.id)))?;
+                    (unit.name, unit.factor)
+                }
+                None => (product.base_unit.clone(), Decimal::ONE),
+            };
+
+            if quantity::to_base(item.qty, unit_factor).is_zero() {
+                return Err(AppError::BadRequest(format!("Quantity of product {} is too small for its base unit", product.id)));
+            }
+
+            total_amount += quantity::line_amount(i64::from(item.unit_cost), item.qty);
+
+            order_items.push(NewPurchaseOrderItem {
+                product_id: product.id,
+                variant_id: item.variant_id,
+                qty: item.qty,
+                unit,
+                unit_factor,
+                unit_cost: item.unit_cost,
+            });
+        }
+
+        Ok((order_items, total_amount))
+    }
+
+    /// Fill in `items` of each purchase order
+    async fn attach_items(&self, purchase_orders: &mut [PurchaseOrderResponse]) -> Result<(), AppError> {
+        let purchase_order_ids: Vec<i32> = purchase_orders.iter().map(|purchase_order| purchase_order.id).collect();
+        let items = self.purchase_order_item_repository.find_by_orders(&purchase_order_ids).await?;
+
+        for purchase_order in purchase_orders.iter_mut() {
+            purchase_order.items = items
+                .iter()
+                .filter(|item| item.purchase_order_id == purchase_order.id)
+                .cloned()
+                .map(PurchaseOrderItemResponse::from)
+                .collect();
+        }
+
+        Ok(())
+    }
+
+    async fn response_with_items(&self, purchase_order: PurchaseOrderModel) -> Result<PurchaseOrderResponse, AppError> {
+        let mut response = PurchaseOrderResponse::from(purchase_order);
+        self.attach_items(std::slice::from_mut(&mut response)).await?;
+        Ok(response)
+    }
+
+    /// Cashflow "out" entry paying for the order
+    async fn post_cashflow(&self, txn: &DatabaseTransaction, purchase_order: &PurchaseOrderModel) -> Result<CashflowModel, AppError> {
+        let nominal = i32::try_from(purchase_order.total_amount)
+            .map_err(|_| AppError::BadRequest("Purchase order total is too large for a cashflow entry".to_string()))?;
+
+        let cashflow_active_model = CashflowActiveModel {
+            note: Set(format!("Purchase order #{}", purchase_order.id)),
+            nominal: Set(nominal),
+            r#type: Set(CASHFLOW_OUT.to_string()),
+            recap_type: Set(RECAP_PURCHASE.to_string()),
+            created_at: Set(Some(Utc::now())),
+            updated_at: Set(Some(Utc::now())),
+            deleted_at: Set(None),
+            ..Default::default()
+        };
+
+        self.cashflow_repository.create_in(txn, cashflow_active_model).await
+    }
+
+    /// Move the order to `to` when it is in one of `from`, recording the change
+    async fn transition(
+        &self,
+        txn: &DatabaseTransaction,
+        existing_order: PurchaseOrderModel,
+        from: &[&str],
+        to: &str,
+        mut purchase_order_active_model: ActiveModel,
+        actor_id: Option<i32>,
+    ) -> Result<PurchaseOrderModel, AppError> {
+        let id = existing_order.id;
+        purchase_order_active_model.status = Set(to.to_string());
+
+        let updated_order = self.purchase_order_repository
+            .claim_status(txn, id, from, purchase_order_active_model)
+            .await?
+            .ok_or_else(|| AppError::BadRequest(format!("Only {} purchase orders can become {}", from.join(" or "), to)))?;
+
+        self.audit_log_repository.record_in(txn, NewAuditLog {
+            actor_id,
+            entity: ENTITY_PURCHASE_ORDER,
+            entity_id: id,
+            action: ACTION_UPDATE,
+            before: Some(json!(existing_order)),
+            after: Some(json!(updated_order)),
+        }).await?;
+
+        Ok(updated_order)
+    }
+}
+
+#[async_trait]
+impl PurchaseUsecase for PurchaseUsecaseImpl {
+    async fn create_purchase_order(&self, request: CreatePurchaseOrderRequest, actor_id: Option<i32>) -> Result<PurchaseOrderResponse, AppError> {
+        self.check_supplier(request.supplier_id).await?;
+
+        let (items, total_amount) = self.resolve_items(request.items).await?;
+
+        // Create active model
+        let purchase_order_active_model = ActiveModel {
+            supplier_id: Set(request.supplier_id),
+            status: Set(STATUS_DRAFT.to_string()),
+            note: Set(request.note),
+            total_amount: Set(total_amount),
+            created_by: Set(actor_id),
+            created_at: Set(Some(Utc::now())),
+            updated_at: Set(Some(Utc::now())),
+            deleted_at: Set(None),
+            ..Default::default()
+        };
+
+        let created_order = self.purchase_order_repository.create(purchase_order_active_model).await?;
+        self.purchase_order_item_repository.replace(created_order.id, items).await?;
+
+        self.audit_log_repository.record(NewAuditLog {
+            actor_id,
+            entity: ENTITY_PURCHASE_ORDER,
+            entity_id: created_order.id,
+            action: ACTION_CREATE,
+            before: None,
+            after: Some(json!(created_order)),
+        }).await?;
+
+        self.response_with_items(created_order).await
+    }
+
+    async fn get_purchase_order(&self, id: i32) -> Result<PurchaseOrderResponse, AppError> {
+        let purchase_order = self.find_order(id).await?;
+        self.response_with_items(purchase_order).await
+    }
+
+    async fn list_purchase_orders(&self, query: GetListPurchaseOrderQuery) -> Result<Paginated<PurchaseOrderResponse>, AppError> {
+        let paginated_result = self.purchase_order_repository.find_with_pagination(query).await?;
+        let mut purchase_orders = Paginated::from(paginated_result);
+        self.attach_items(&mut purchase_orders.data).await?;
+        Ok(purchase_orders)
+    }
+
+    async fn update_purchase_order(&self, id: i32, request: UpdatePurchaseOrderRequest, actor_id: Option<i32>) -> Result<PurchaseOrderResponse, AppError> {
+        // Check if purchase order exists
+        let existing_order = self.find_order(id).await?;
+
+        if !existing_order.is_editable() {
+            return Err(AppError::BadRequest("Only draft purchase orders can be changed".to_string()));
+        }
+
+        if let Some(supplier_id) = request.supplier_id {
+            self.check_supplier(supplier_id).await?;
+        }
+
+        // Create active model with only changed fields
+        let mut purchase_order_active_model = ActiveModel {
+            id: Set(id),
+            ..Default::default()
+        };
+
+        if let Some(supplier_id) = request.supplier_id {
+            purchase_order_active_model.supplier_id = Set(supplier_id);
+        }
+        if let Some(note) = request.note {
+            purchase_order_active_model.note = Set(note);
+        }
+        if let Some(items) = request.items {
+            let (items, total_amount) = self.resolve_items(items).await?;
+            self.purchase_order_item_repository.replace(id, items).await?;
+            purchase_order_active_model.total_amount = Set(total_amount);
+        }
+
+        let updated_order = self.purchase_order_repository.update(id, purchase_order_active_model).await?;
+
+        self.audit_log_repository.record(NewAuditLog {
+            actor_id,
+            entity: ENTITY_PURCHASE_ORDER,
+            entity_id: id,
+            action: ACTION_UPDATE,
+            before: Some(json!(existing_order)),
+            after: Some(json!(updated_order)),
+        }).await?;
+
+        self.response_with_items(updated_order).await
+    }
+
+    async fn order_purchase_order(&self, id: i32, actor_id: Option<i32>) -> Result<PurchaseOrderResponse, AppError> {
+        let existing_order = self.find_order(id).await?;
+
+        let purchase_order_active_model = ActiveModel {
+            id: Set(id),
+            ordered_at: Set(Some(Utc::now())),
+            ..Default::default()
+        };
+
+        let txn = self.purchase_order_repository.begin().await?;
+        let updated_order = self
+            .transition(&txn, existing_order, &[STATUS_DRAFT], STATUS_ORDERED, purchase_order_active_model, actor_id)
+            .await?;
+        db::commit(txn).await?;
+
+        self.response_with_items(updated_order).await
+    }
+
+    async fn cancel_purchase_order(&self, id: i32, actor_id: Option<i32>) -> Result<PurchaseOrderResponse, AppError> {
+        let existing_order = self.find_order(id).await?;
+
+        let purchase_order_active_model = ActiveModel {
+            id: Set(id),
+            cancelled_at: Set(Some(Utc::now())),
+            ..Default::default()
+        };
+
+        let txn = self.purchase_order_repository.begin().await?;
+        let updated_order = self
+            .transition(&txn, existing_order, &[STATUS_DRAFT, STATUS_ORDERED], STATUS_CANCELLED, purchase_order_active_model, actor_id)
+            .await?;
+        db::commit(txn).await?;
+
+        self.response_with_items(updated_order).await
+    }
+
+    async fn receive_purchase_order(&self, id: i32, request: ReceivePurchaseOrderRequest, actor_id: Option<i32>) -> Result<PurchaseOrderResponse, AppError> {
+        let existing_order = self.find_order(id).await?;
+
+        if request.payment_method == PAYMENT_CASH && i32::try_from(existing_order.total_amount).is_err() {
+            return Err(AppError::BadRequest("Purchase order total is too large for a cashflow entry".to_string()));
+        }
+
+        let items = self.purchase_order_item_repository.find_by_orders(&[id]).await?;
+
+        let mut lots = HashMap::new();
+        for lot in request.lots {
+            if !items.iter().any(|item| item.id == lot.item_id) {
+                return Err(AppError::BadRequest(format!("Item {} is not on this purchase order", lot.item_id)));
+            }
+            let new_lot = NewStockLot { lot_code: lot.lot_code, expires_at: lot.expires_at };
+            if lots.insert(lot.item_id, new_lot).is_some() {
+                return Err(AppError::BadRequest(format!("Item {} has more than one lot", lot.item_id)));
+            }
+        }
+
+        let mut purchase_order_active_model = ActiveModel {
+            id: Set(id),
+            payment_method: Set(Some(request.payment_method.clone())),
+            received_at: Set(Some(Utc::now())),
+            ..Default::default()
+        };
+
+        let txn = self.purchase_order_repository.begin().await?;
+
+        if request.payment_method == PAYMENT_CASH {
+            let cashflow = self.post_cashflow(&txn, &existing_order).await?;
+            purchase_order_active_model.cashflow_id = Set(Some(cashflow.id));
+            purchase_order_active_model.paid_at = Set(Some(Utc::now()));
+        }
+
+        let received_order = self
+            .transition(&txn, existing_order, &[STATUS_DRAFT, STATUS_ORDERED], STATUS_RECEIVED, purchase_order_active_model, actor_id)
+            .await?;
+
+        // Stock comes in in base units, carrying what the line cost into the HPP
+        let mut receipts = Vec::with_capacity(items.len());
+        for item in &items {
+            let receipt = CostReceipt {
+                qty: quantity::to_base(item.qty, item.unit_factor),
+                cost_amount: quantity::line_amount(i64::from(item.unit_cost), item.qty),
+            };
+
+            self.stock_repository.record_in(&txn, NewStockMovement {
+                product_id: item.product_id,
+                variant_id: item.variant_id,
+                qty: receipt.qty,
+                movement_type: MOVEMENT_PURCHASE,
+                reference_type: Some(REFERENCE_PURCHASE_ORDER),
+                reference_id: Some(id),
+                note: None,
+                created_by: actor_id,
+                cost_amount: Some(receipt.cost_amount),
+                lot: lots.remove(&item.id),
+                lot_id: None,
+                restores: None,
+            }).await?;
+
+            receipts.push((item.product_id, item.variant_id, receipt));
+        }
+
+        db::commit(txn).await?;
+
+        for (product_id, variant_id, receipt) in receipts {
+            self.product_usecase.revalue_cost(product_id, variant_id, Some(receipt), actor_id).await?;
+        }
+
+        self.response_with_items(received_order).await
+    }
+
+    async fn pay_purchase_order(&self, id: i32, actor_id: Option<i32>) -> Result<PurchaseOrderResponse, AppError> {
+        let existing_order = self.find_order(id).await?;
+
+        if existing_order.payment_method.as_deref() != Some(PAYMENT_PAYABLE) {
+            return Err(AppError::BadRequest("Only purchase orders received as payable can be paid later".to_string()));
+        }
+        if i32::try_from(existing_order.total_amount).is_err() {
+            return Err(AppError::BadRequest("Purchase order total is too large for a cashflow entry".to_string()));
+        }
+        let txn = self.purchase_order_repository.begin().await?;
+
+        let cashflow = self.post_cashflow(&txn, &existing_order).await?;
+
+        let paid_order = self.purchase_order_repository
+            .claim_payment(&txn, id, cashflow.id)
+            .await?
+            .ok_or_else(|| AppError::BadRequest("Purchase order is already paid".to_string()))?;
+
+        self.audit_log_repository.record_in(&txn, NewAuditLog {
+            actor_id,
+            entity: ENTITY_PURCHASE_ORDER,
+            entity_id: id,
+            action: ACTION_UPDATE,
+            before: Some(json!(existing_order)),
+            after: Some(json!(paid_order)),
+        }).await?;
+
+        db::commit(txn).await?;
+
+        self.response_with_items(paid_order).await
+    }
+
+    async fn delete_purchase_order(&self, id: i32, actor_id: Option<i32>) -> Result<(), AppError> {
+        // Check if purchase order exists
+        let existing_order = self.find_order(id).await?;
+
+        // Received orders are part of the stock ledger
+        if existing_order.status != STATUS_DRAFT && existing_order.status != STATUS_CANCELLED {
+            return Err(AppError::BadRequest("Only draft or cancelled purchase orders can be deleted".to_string()));
+        }
+
+        // Soft delete
+        let deleted_order = self.purchase_order_repository.delete(id).await?;
+
+        self.audit_log_repository.record(NewAuditLog {
+            actor_id,
+            entity: ENTITY_PURCHASE_ORDER,
+            entity_id: id,
+            action: ACTION_DELETE,
+            before: Some(json!(existing_order)),
+            after: Some(json!(deleted_order)),
+        }).await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,7 @@
+use axum::Router;
+
+use crate::module::report::v1::report_handler;
+
+pub fn configure() -> Router {
+	Router::new().nest("/api/v1/report", report_handler::routes())
+}
@@ -0,0 +1 @@
+pub mod sales_report_request;
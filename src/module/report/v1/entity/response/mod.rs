@@ -0,0 +1 @@
+pub mod sales_by_category_response;
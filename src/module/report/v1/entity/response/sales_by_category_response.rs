@@ -0,0 +1,65 @@
+use rust_decimal::Decimal;
+use serde::{Serialize, Deserialize};
+
+use crate::module::repository::report_repository::CategorySalesRow;
+
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Copy)]
+pub struct SalesTotals {
+    pub transaction_count: i64,
+    // In base units
+    pub qty: Decimal,
+    // Net of discounts and returns
+    pub revenue: i64,
+    pub hpp_amount: i64,
+    pub gross_profit: i64,
+    // Revenue given back through returns
+    pub refund_amount: i64,
+    // Given away through discounts and promotions, less what returns took back
+    pub discount_amount: i64,
+}
+
+impl SalesTotals {
+    pub fn add(&mut self, other: &SalesTotals) {
+        self.transaction_count += other.transaction_count;
+        self.qty += other.qty;
+        self.revenue += other.revenue;
+        self.hpp_amount += other.hpp_amount;
+        self.gross_profit += other.gross_profit;
+        self.refund_amount += other.refund_amount;
+        self.discount_amount += other.discount_amount;
+    }
+}
+
+impl From<&CategorySalesRow> for SalesTotals {
+    fn from(row: &CategorySalesRow) -> Self {
+        Self {
+            transaction_count: row.transaction_count,
+            qty: row.qty,
+            revenue: row.revenue,
+            hpp_amount: row.hpp_amount,
+            gross_profit: row.revenue - row.hpp_amount,
+            refund_amount: row.refund_amount,
+            discount_amount: row.discount_amount,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CategorySalesResponse {
+    pub category_id: i32,
+    pub name: String,
+    pub parent_id: Option<i32>,
+    // Names from the top-level category down, e.g. "Drinks / Coffee"
+    pub path: String,
+    // Products directly in this category
+    pub sales: SalesTotals,
+    // Including all subcategories
+    pub total: SalesTotals,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SalesByCategoryResponse {
+    pub categories: Vec<CategorySalesResponse>,
+    pub uncategorized: SalesTotals,
+    pub total: SalesTotals,
+}
@@ -0,0 +1,3 @@
+pub mod entity;
+pub mod report_usecase;
+pub mod report_handler;
\ No newline at end of file
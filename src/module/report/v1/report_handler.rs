@@ -0,0 +1,33 @@
+use axum::{
+	extract::{Extension, Query}, 
+    http::StatusCode, 
+    response::IntoResponse, 
+    routing::get, 
+    Json, 
+    Router
+};
+use serde_json::json;
+use std::sync::Arc;
+
+use crate::module::report::v1::entity::request::sales_report_request::SalesReportQuery;
+use crate::pkg::custom_error::AppError;
+use crate::pkg::response::ApiResponse;
+use crate::di::AppModule;
+
+
+pub fn routes() -> Router {
+	Router::new()
+		.route("/sales-by-category", get(sales_by_category))
+}
+
+async fn sales_by_category(
+    Extension(app_module): Extension<Arc<AppModule>>,
+    Query(query): Query<SalesReportQuery>,
+) -> Result<impl IntoResponse, AppError> {
+    let report = app_module.report_usecase.sales_by_category(query).await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse::success("Sales by category retrieved successfully", Some(json!(report)), None)),
+    ))
+}
@@ -0,0 +1,114 @@
+use async_trait::async_trait;
+use crate::module::report::v1::entity::{
+    request::sales_report_request::SalesReportQuery,
+    response::sales_by_category_response::{CategorySalesResponse, SalesByCategoryResponse, SalesTotals},
+};
+use crate::module::repository::report_repository::ReportRepository;
+use crate::module::repository::category_repository::CategoryRepository;
+use crate::pkg::custom_error::AppError;
+use crate::pkg::filter;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+#[async_trait]
+pub trait ReportUsecase: Send + Sync {
+    async fn sales_by_category(&self, query: SalesReportQuery) -> Result<SalesByCategoryResponse, AppError>;
+}
+
+pub struct ReportUsecaseImpl {
+    report_repository: Arc<dyn ReportRepository>,
+    category_repository: Arc<dyn CategoryRepository>,
+}
+
+impl ReportUsecaseImpl {
+    pub fn new(
+        report_repository: Arc<dyn ReportRepository>,
+        category_repository: Arc<dyn CategoryRepository>,
+    ) -> Self {
+        Self { report_repository, category_repository }
+    }
+}
+
+#[async_trait]
+impl ReportUsecase for ReportUsecaseImpl {
+    async fn sales_by_category(&self, query: SalesReportQuery) -> Result<SalesByCategoryResponse, AppError> {
+        let timezone = filter::parse_timezone(query.timezone.as_deref())?;
+        let created_from = query.created_from
+            .as_deref()
+            .map(|raw| filter::parse_date_from("created_from", raw, timezone))
+            .transpose()?;
+        let created_to = query.created_to
+            .as_deref()
+            .map(|raw| filter::parse_date_to("created_to", raw, timezone))
+            .transpose()?;
+
+        let rows = self.report_repository.sales_by_category(created_from, created_to).await?;
+        let categories = self.category_repository.find_all().await?;
+
+        let parents: HashMap<i32, Option<i32>> = categories
+            .iter()
+            .map(|category| (category.id, category.parent_id))
+            .collect();
+        let names: HashMap<i32, &str> = categories
+            .iter()
+            .map(|category| (category.id, category.name.as_str()))
+            .collect();
+
+        let mut sales: HashMap<i32, SalesTotals> = HashMap::new();
+        let mut totals: HashMap<i32, SalesTotals> = HashMap::new();
+        let mut uncategorized = SalesTotals::default();
+        let mut total = SalesTotals::default();
+
+        for row in &rows {
+            let row_totals = SalesTotals::from(row);
+            total.add(&row_totals);
+
+            // Products in a deleted category count as uncategorized
+            let Some(category_id) = row.category_id.filter(|id| parents.contains_key(id)) else {
+                uncategorized.add(&row_totals);
+                continue;
+            };
+
+            sales.entry(category_id).or_default().add(&row_totals);
+
+            // Roll up into every ancestor, bounded in case of a corrupt cycle
+            let mut current = Some(category_id);
+            for _ in 0..=parents.len() {
+                let Some(id) = current else { break };
+                totals.entry(id).or_default().add(&row_totals);
+                current = parents.get(&id).copied().flatten();
+            }
+        }
+
+        let path = |id: i32| {
+            let mut names_up = Vec::new();
+            let mut current = Some(id);
+            for _ in 0..=parents.len() {
+                let Some(id) = current else { break };
+                names_up.push(names.get(&id).copied().unwrap_or_default());
+                current = parents.get(&id).copied().flatten();
+            }
+            names_up.reverse();
+            names_up.join(" / ")
+        };
+
+        let mut category_sales: Vec<CategorySalesResponse> = categories
+            .iter()
+            .map(|category| CategorySalesResponse {
+                category_id: category.id,
+                name: category.name.clone(),
+                parent_id: category.parent_id,
+                path: path(category.id),
+                sales: sales.get(&category.id).copied().unwrap_or_default(),
+                total: totals.get(&category.id).copied().unwrap_or_default(),
+            })
+            .collect();
+        category_sales.sort_by(|a, b| a.path.cmp(&b.path));
+
+        Ok(SalesByCategoryResponse {
+            categories: category_sales,
+            uncategorized,
+            total,
+        })
+    }
+}
@@ -0,0 +1,142 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::Utc;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, Condition, DatabaseConnection, DatabaseTransaction, DbErr, EntityTrait, Order, QueryFilter, QueryOrder, Set,
+};
+use serde_json::Value;
+use crate::{
+    module::audit::v1::entity::{
+        model::audit_log::{ActiveModel, Entity as AuditLog, Model as AuditLogModel, Column},
+        request::list_audit_log_request::GetListAuditLogQuery,
+    },
+    pkg::{
+        audit,
+        custom_error::AppError,
+        filter,
+        pagination::{self, PageQuery, PaginatedResult},
+    }
+};
+use tracing::log::error;
+
+/// One change to record; `before` / `after` are JSON snapshots of the row
+pub struct NewAuditLog {
+    pub actor_id: Option<i32>,
+    pub entity: &'static str,
+    pub entity_id: i32,
+    pub action: &'static str,
+    pub before: Option<Value>,
+    pub after: Option<Value>,
+}
+
+fn db_error(err: DbErr) -> AppError {
+    error!("DB error: {:?}", err);
+    AppError::InternalError
+}
+
+fn active_model(entry: NewAuditLog) -> ActiveModel {
+    let changes = audit::diff(entry.before.as_ref(), entry.after.as_ref());
+
+    ActiveModel {
+        actor_id: Set(entry.actor_id),
+        entity: Set(entry.entity.to_string()),
+        entity_id: Set(entry.entity_id),
+        action: Set(entry.action.to_string()),
+        before: Set(entry.before),
+        after: Set(entry.after),
+        changes: Set(changes),
+        created_at: Set(Some(Utc::now())),
+        ..Default::default()
+    }
+}
+
+#[async_trait]
+pub trait AuditLogRepository: Send + Sync {
+    async fn record(&self, entry: NewAuditLog) -> Result<AuditLogModel, AppError>;
+    async fn record_in(&self, txn: &DatabaseTransaction, entry: NewAuditLog) -> Result<AuditLogModel, AppError>;
+    async fn find_with_pagination(&self, query: GetListAuditLogQuery) -> Result<PaginatedResult<AuditLogModel>, AppError>;
+}
+
+pub struct AuditLogRepositoryImpl {
+    db: Arc<DatabaseConnection>,
+}
+
+impl AuditLogRepositoryImpl {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl AuditLogRepository for AuditLogRepositoryImpl {
+    async fn record(&self, entry: NewAuditLog) -> Result<AuditLogModel, AppError> {
+        active_model(entry).insert(self.db.as_ref()).await.map_err(db_error)
+    }
+
+    /// Same as `record`, as part of the caller's DB transaction
+    async fn record_in(&self, txn: &DatabaseTransaction, entry: NewAuditLog) -> Result<AuditLogModel, AppError> {
+        active_model(entry).insert(txn).await.map_err(db_error)
+    }
+
+    async fn find_with_pagination(
+        &self,
+        query: GetListAuditLogQuery
+    ) -> Result<PaginatedResult<AuditLogModel>, AppError> {
+        // Build filter condition
+        let mut search_condition = Condition::all();
+
+        let entities = filter::parse_list::<String>("entity", query.entity.as_deref())?;
+        if !entities.is_empty() {
+            search_condition = search_condition.add(Column::Entity.is_in(entities));
+        }
+
+        if let Some(entity_id) = filter::parse_number::<i32>("entity_id", query.entity_id.as_deref())? {
+            search_condition = search_condition.add(Column::EntityId.eq(entity_id));
+        }
+
+        let actor_ids = filter::parse_list::<i32>("actor_id", query.actor_id.as_deref())?;
+        if !actor_ids.is_empty() {
+            search_condition = search_condition.add(Column::ActorId.is_in(actor_ids));
+        }
+
+        let actions = filter::parse_list::<String>("action", query.action.as_deref())?;
+        if !actions.is_empty() {
+            search_condition = search_condition.add(Column::Action.is_in(actions));
+        }
+
+        let timezone = filter::parse_timezone(query.timezone.as_deref())?;
+
+        if let Some(created_from) = &query.created_from {
+            let created_from = filter::parse_date_from("created_from", created_from, timezone)?;
+            search_condition = search_condition.add(Column::CreatedAt.gte(created_from));
+        }
+
+        if let Some(created_to) = &query.created_to {
+            let created_to = filter::parse_date_to("created_to", created_to, timezone)?;
+            search_condition = search_condition.add(Column::CreatedAt.lt(created_to));
+        }
+
+        let direction = match query.direction.as_deref() {
+            Some("asc") | Some("ASC") => Order::Asc,
+            _ => Order::Desc,
+        };
+
+        let page_query = PageQuery {
+            limit: query.limit,
+            page: query.page,
+            include_total: query.include_total,
+            ..Default::default()
+        };
+
+        let select = AuditLog::find()
+            .filter(search_condition)
+            .order_by(Column::Id, direction);
+
+        pagination::paginate_select(self.db.as_ref(), select, &page_query)
+            .await
+            .map_err(|err| {
+                error!("DB error: {:?}", err);
+                AppError::InternalError
+            })
+    }
+}
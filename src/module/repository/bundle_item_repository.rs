@@ -0,0 +1,93 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::Utc;
+use sea_orm::{
+    ColumnTrait, DatabaseConnection, DbErr, EntityTrait, Order, QueryFilter, QueryOrder, Set, TransactionTrait,
+};
+use crate::{
+    module::product::v1::entity::model::bundle_item::{ActiveModel, Entity as BundleItem, Model as BundleItemModel, Column},
+    pkg::custom_error::AppError,
+};
+use tracing::log::error;
+
+fn db_error(err: DbErr) -> AppError {
+    error!("DB error: {:?}", err);
+    AppError::InternalError
+}
+
+/// Component line of a bundle to store
+pub struct NewBundleItem {
+    pub component_id: i32,
+    pub variant_id: Option<i32>,
+    pub qty: i32,
+}
+
+#[async_trait]
+pub trait BundleItemRepository: Send + Sync {
+    async fn find_by_bundle(&self, bundle_id: i32) -> Result<Vec<BundleItemModel>, AppError>;
+    async fn find_by_component(&self, component_id: i32) -> Result<Vec<BundleItemModel>, AppError>;
+    async fn replace(&self, bundle_id: i32, items: Vec<NewBundleItem>) -> Result<Vec<BundleItemModel>, AppError>;
+}
+
+pub struct BundleItemRepositoryImpl {
+    db: Arc<DatabaseConnection>,
+}
+
+impl BundleItemRepositoryImpl {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl BundleItemRepository for BundleItemRepositoryImpl {
+    async fn find_by_bundle(&self, bundle_id: i32) -> Result<Vec<BundleItemModel>, AppError> {
+        BundleItem::find()
+            .filter(Column::BundleId.eq(bundle_id))
+            .order_by(Column::Id, Order::Asc)
+            .all(self.db.as_ref())
+            .await
+            .map_err(db_error)
+    }
+
+    /// Bundle lines of every bundle that contains the component
+    async fn find_by_component(&self, component_id: i32) -> Result<Vec<BundleItemModel>, AppError> {
+        BundleItem::find()
+            .filter(Column::ComponentId.eq(component_id))
+            .order_by(Column::Id, Order::Asc)
+            .all(self.db.as_ref())
+            .await
+            .map_err(db_error)
+    }
+
+    /// Set the bundle's components to exactly `items`
+    async fn replace(&self, bundle_id: i32, items: Vec<NewBundleItem>) -> Result<Vec<BundleItemModel>, AppError> {
+        let txn = self.db.begin().await.map_err(db_error)?;
+
+        BundleItem::delete_many()
+            .filter(Column::BundleId.eq(bundle_id))
+            .exec(&txn)
+            .await
+            .map_err(db_error)?;
+
+        if !items.is_empty() {
+            let bundle_items = items.into_iter().map(|item| ActiveModel {
+                bundle_id: Set(bundle_id),
+                component_id: Set(item.component_id),
+                variant_id: Set(item.variant_id),
+                qty: Set(item.qty),
+                created_at: Set(Some(Utc::now())),
+                ..Default::default()
+            });
+
+            BundleItem::insert_many(bundle_items)
+                .exec(&txn)
+                .await
+                .map_err(db_error)?;
+        }
+
+        txn.commit().await.map_err(db_error)?;
+
+        self.find_by_bundle(bundle_id).await
+    }
+}
@@ -1,207 +1,189 @@
 use std::sync::Arc;
 use async_trait::async_trait;
-use sea_orm::{ColumnTrait, Condition, DatabaseConnection, EntityTrait, Order, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, Set};
-use chrono::Utc;
+use chrono::{DateTime, Utc};
+use sea_orm::{sea_query::Query, ActiveModelTrait, ColumnTrait, Condition, DatabaseConnection, DatabaseTransaction, DbErr};
 use crate::{
     module::cashflow::v1::entity::{
         model::cashflow::{ActiveModel, Entity as Cashflow, Model as CashflowModel, Column},
         request::list_cashflow_request::GetListCashflowQuery,
     },
+    module::purchase::v1::entity::model::purchase_order::{Entity as PurchaseOrder, Column as PurchaseOrderColumn},
+    module::transaction::v1::entity::model::transaction_return::{Entity as TransactionReturn, Column as TransactionReturnColumn},
+    module::repository::soft_delete_repository::{PurgeSummary, SoftDeleteEntity, SoftDeleteRepository},
     pkg::{
         custom_error::AppError,
-        pagination::PaginatedResult,
+        filter,
+        pagination::{PageQuery, PaginatedResult},
     }
 };
 use tracing::log::error;
 
+fn db_error(err: DbErr) -> AppError {
+    error!("DB error: {:?}", err);
+    AppError::InternalError
+}
+
+impl SoftDeleteEntity for Cashflow {
+    type ActiveModel = ActiveModel;
+
+    const NAME: &'static str = "Cashflow";
+    const SEARCH_COLUMNS: &'static [Column] = &[Column::Note];
+    const SORT_COLUMNS: &'static [(&'static str, Column)] = &[
+        ("note", Column::Note),
+        ("nominal", Column::Nominal),
+        ("type", Column::Type),
+        ("recap_type", Column::RecapType),
+        ("created_at", Column::CreatedAt),
+        ("updated_at", Column::UpdatedAt),
+    ];
+
+    fn id_column() -> Column { Column::Id }
+    fn updated_at_column() -> Column { Column::UpdatedAt }
+    fn deleted_at_column() -> Column { Column::DeletedAt }
+}
 
 #[async_trait]
 pub trait CashflowRepository: Send + Sync {
-    async fn create(&self, cashflow: ActiveModel) -> Result<(), AppError>;
+    async fn create(&self, cashflow: ActiveModel) -> Result<CashflowModel, AppError>;
+    async fn create_in(&self, txn: &DatabaseTransaction, cashflow: ActiveModel) -> Result<CashflowModel, AppError>;
     async fn find_by_id(&self, id: i32) -> Result<Option<CashflowModel>, AppError>;
     async fn find_with_pagination(&self, query: GetListCashflowQuery) -> Result<PaginatedResult<CashflowModel>, AppError>;
     async fn update(&self, id: i32, cashflow: ActiveModel) -> Result<CashflowModel, AppError>;
-    async fn delete(&self, id: i32) -> Result<(), AppError>;
+    async fn delete(&self, id: i32) -> Result<CashflowModel, AppError>;
+    async fn find_deleted_by_id(&self, id: i32) -> Result<Option<CashflowModel>, AppError>;
+    async fn find_deleted_with_pagination(&self, query: GetListCashflowQuery) -> Result<PaginatedResult<CashflowModel>, AppError>;
+    async fn restore(&self, id: i32) -> Result<CashflowModel, AppError>;
+    async fn purge(&self, id: i32) -> Result<(), AppError>;
+    async fn purge_deleted_before(&self, cutoff: DateTime<Utc>, dry_run: bool) -> Result<PurgeSummary, AppError>;
 }
 
 pub struct CashflowRepositoryImpl {
-    db: Arc<DatabaseConnection>,
+    base: SoftDeleteRepository<Cashflow>,
 }
 
 impl CashflowRepositoryImpl {
     pub fn new(db: Arc<DatabaseConnection>) -> Self {
-        Self { db }
+        Self { base: SoftDeleteRepository::new(db) }
+    }
+
+    /// Paging parameters and filter condition of a list query
+    fn list_query(query: GetListCashflowQuery) -> Result<(PageQuery, Condition), AppError> {
+        // Build filter condition
+        let mut search_condition = Condition::all();
+
+        let cashflow_types = filter::parse_list::<String>("type", query.r#type.as_deref())?;
+        if !cashflow_types.is_empty() {
+            search_condition = search_condition.add(Column::Type.is_in(cashflow_types));
+        }
+
+        let recap_types = filter::parse_list::<String>("recap_type", query.recap_type.as_deref())?;
+        if !recap_types.is_empty() {
+            search_condition = search_condition.add(Column::RecapType.is_in(recap_types));
+        }
+
+        let timezone = filter::parse_timezone(query.timezone.as_deref())?;
+
+        if let Some(created_from) = &query.created_from {
+            let created_from = filter::parse_date_from("created_from", created_from, timezone)?;
+            search_condition = search_condition.add(Column::CreatedAt.gte(created_from));
+        }
+
+        if let Some(created_to) = &query.created_to {
+            let created_to = filter::parse_date_to("created_to", created_to, timezone)?;
+            search_condition = search_condition.add(Column::CreatedAt.lt(created_to));
+        }
+
+        if let Some(min_amount) = filter::parse_amount("min_amount", query.min_amount.as_deref())? {
+            search_condition = search_condition.add(Column::Nominal.gte(min_amount));
+        }
+
+        if let Some(max_amount) = filter::parse_amount("max_amount", query.max_amount.as_deref())? {
+            search_condition = search_condition.add(Column::Nominal.lte(max_amount));
+        }
+
+        let page_query = PageQuery {
+            limit: query.limit,
+            page: query.page,
+            search: query.search,
+            order_by: query.order_by,
+            direction: query.direction,
+            cursor: query.cursor,
+            include_total: query.include_total,
+        };
+
+        Ok((page_query, search_condition))
     }
 }
 
 #[async_trait]
 impl CashflowRepository for CashflowRepositoryImpl {
-    async fn create(&self, cashflow: ActiveModel) -> Result<(), AppError> {
-        Cashflow::insert(cashflow)
-            .exec(self.db.as_ref())
-            .await
-            .map_err(|err| {
-                error!("DB error: {:?}", err);
-                AppError::InternalError
-            })?;
-
-        Ok(())
+    async fn create(&self, cashflow: ActiveModel) -> Result<CashflowModel, AppError> {
+        self.base.create(cashflow).await
+    }
+
+    /// Same as `create`, as part of the caller's DB transaction
+    async fn create_in(&self, txn: &DatabaseTransaction, cashflow: ActiveModel) -> Result<CashflowModel, AppError> {
+        cashflow.insert(txn).await.map_err(db_error)
     }
 
     async fn find_by_id(&self, id: i32) -> Result<Option<CashflowModel>, AppError> {
-        Cashflow::find_by_id(id)
-            .filter(Column::DeletedAt.is_null())
-            .one(self.db.as_ref())
-            .await
-            .map_err(|err| {
-                error!("DB error: {:?}", err);
-                AppError::InternalError
-            })
+        self.base.find_by_id(id).await
     }
 
     async fn find_with_pagination(
         &self,
         query: GetListCashflowQuery
     ) -> Result<PaginatedResult<CashflowModel>, AppError> {
-        // Parse pagination parameters
-        let page = query.page
-            .and_then(|p| p.parse::<u64>().ok())
-            .unwrap_or(1)
-            .max(1);
-    
-        let limit = query.limit
-            .and_then(|l| l.parse::<u64>().ok())
-            .unwrap_or(10)
-            .min(100);
-    
-        let offset = (page - 1) * limit;
-    
-        // Build search condition
-        let mut search_condition = Condition::all().add(Column::DeletedAt.is_null());
-        
-        if let Some(search) = &query.search {
-            if !search.trim().is_empty() {
-                search_condition = search_condition.add(Column::Note.contains(search.trim()));
-            }
-        }
+        let (page_query, condition) = Self::list_query(query)?;
+        self.base.find_with_pagination(page_query, condition).await
+    }
 
-        if let Some(cashflow_type) = &query.r#type {
-            if !cashflow_type.trim().is_empty() {
-                search_condition = search_condition.add(Column::Type.eq(cashflow_type.trim()));
-            }
-        }
+    async fn update(&self, id: i32, cashflow: ActiveModel) -> Result<CashflowModel, AppError> {
+        self.base.update(id, cashflow).await
+    }
 
-        if let Some(recap_type) = &query.recap_type {
-            if !recap_type.trim().is_empty() {
-                search_condition = search_condition.add(Column::RecapType.eq(recap_type.trim()));
-            }
-        }
-    
-        let search_condition_for_count = search_condition.clone();
-    
-        // Build ordering
-        let mut query_builder = Cashflow::find().filter(search_condition);
-    
-        if let Some(order_by) = &query.order_by {
-            let direction = match query.direction.as_deref() {
-                Some("desc") | Some("DESC") => Order::Desc,
-                _ => Order::Asc,
-            };
-    
-            query_builder = match order_by.to_lowercase().as_str() {
-                "note" => query_builder.order_by(Column::Note, direction),
-                "nominal" => query_builder.order_by(Column::Nominal, direction),
-                "type" => query_builder.order_by(Column::Type, direction),
-                "recap_type" => query_builder.order_by(Column::RecapType, direction),
-                "created_at" => query_builder.order_by(Column::CreatedAt, direction),
-                "updated_at" => query_builder.order_by(Column::UpdatedAt, direction),
-                _ => query_builder.order_by(Column::Id, Order::Asc),
-            };
-        } else {
-            query_builder = query_builder.order_by(Column::Id, Order::Asc);
-        }
-    
-        // Get total count
-        let total = Cashflow::find()
-            .filter(search_condition_for_count)
-            .count(self.db.as_ref())
-            .await
-            .map_err(|err| {
-                error!("DB error: {:?}", err);
-                AppError::InternalError
-            })?;
-    
-        // Get paginated data
-        let data = query_builder
-            .offset(offset)
-            .limit(limit)
-            .all(self.db.as_ref())
-            .await
-            .map_err(|err| {
-                error!("DB error: {:?}", err);
-                AppError::InternalError
-            })?;
-    
-        let total_pages = (total as u64 as f64 / limit as f64).ceil() as u64;
-    
-        Ok(PaginatedResult {
-            data,
-            total,
-            page,
-            limit,
-            total_pages,
-        })
+    async fn delete(&self, id: i32) -> Result<CashflowModel, AppError> {
+        self.base.delete(id).await
     }
 
-    async fn update(&self, id: i32, mut cashflow: ActiveModel) -> Result<CashflowModel, AppError> {
-        // Set updated_at timestamp
-        cashflow.updated_at = Set(Some(Utc::now()));
-
-        let result = Cashflow::update_many()
-            .set(cashflow)
-            .filter(Column::Id.eq(id))
-            .filter(Column::DeletedAt.is_null())
-            .exec(self.db.as_ref())
-            .await
-            .map_err(|err| {
-                error!("DB error: {:?}", err);
-                AppError::InternalError
-            })?;
-
-        if result.rows_affected == 0 {
-            return Err(AppError::NotFound(format!("Cashflow with id {} not found", id)));
-        }
+    async fn find_deleted_by_id(&self, id: i32) -> Result<Option<CashflowModel>, AppError> {
+        self.base.find_deleted_by_id(id).await
+    }
 
-        // Fetch the updated cashflow
-        Cashflow::find_by_id(id)
-            .filter(Column::DeletedAt.is_null())
-            .one(self.db.as_ref())
-            .await
-            .map_err(|err| {
-                error!("DB error: {:?}", err);
-                AppError::InternalError
-            })?
-            .ok_or_else(|| AppError::NotFound(format!("Cashflow with id {} not found", id)))
+    async fn find_deleted_with_pagination(
+        &self,
+        query: GetListCashflowQuery
+    ) -> Result<PaginatedResult<CashflowModel>, AppError> {
+        let (page_query, condition) = Self::list_query(query)?;
+        self.base.find_deleted_with_pagination(page_query, condition).await
     }
 
-    async fn delete(&self, id: i32) -> Result<(), AppError> {
-        let now = Utc::now();
-        let result = Cashflow::update_many()
-            .col_expr(Column::DeletedAt, now.into())
-            .col_expr(Column::UpdatedAt, now.into())
-            .filter(Column::Id.eq(id))
-            .filter(Column::DeletedAt.is_null())
-            .exec(self.db.as_ref())
-            .await
-            .map_err(|err| {
-                error!("DB error: {:?}", err);
-                AppError::InternalError
-            })?;
-
-        if result.rows_affected == 0 {
-            return Err(AppError::NotFound(format!("Cashflow with id {} not found", id)));
-        }
+    async fn restore(&self, id: i32) -> Result<CashflowModel, AppError> {
+        self.base.restore(id).await
+    }
+
+    async fn purge(&self, id: i32) -> Result<(), AppError> {
+        self.base.purge(id).await
+    }
 
-        Ok(())
+    async fn purge_deleted_before(&self, cutoff: DateTime<Utc>, dry_run: bool) -> Result<PurgeSummary, AppError> {
+        // Keep payments still linked from purchase orders
+        let purchase_payments = Query::select()
+            .column(PurchaseOrderColumn::CashflowId)
+            .from(PurchaseOrder)
+            .and_where(PurchaseOrderColumn::CashflowId.is_not_null())
+            .to_owned();
+
+        // Refunds stay with their returns
+        let refunds = Query::select()
+            .column(TransactionReturnColumn::CashflowId)
+            .from(TransactionReturn)
+            .and_where(TransactionReturnColumn::CashflowId.is_not_null())
+            .to_owned();
+
+        let condition = Condition::all()
+            .add(Column::Id.not_in_subquery(purchase_payments))
+            .add(Column::Id.not_in_subquery(refunds));
+        self.base.purge_deleted_before(cutoff, condition, dry_run).await
     }
 }
\ No newline at end of file
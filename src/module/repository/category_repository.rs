@@ -0,0 +1,148 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use async_trait::async_trait;
+use sea_orm::{ColumnTrait, Condition, DatabaseConnection};
+use crate::{
+    module::category::v1::entity::{
+        model::category::{ActiveModel, Entity as Category, Model as CategoryModel, Column},
+        request::list_category_request::GetListCategoryQuery,
+    },
+    module::repository::soft_delete_repository::{SoftDeleteEntity, SoftDeleteRepository},
+    pkg::{
+        custom_error::AppError,
+        pagination::{PageQuery, PaginatedResult},
+    }
+};
+
+
+impl SoftDeleteEntity for Category {
+    type ActiveModel = ActiveModel;
+
+    const NAME: &'static str = "Category";
+    const SEARCH_COLUMNS: &'static [Column] = &[Column::Name];
+    const SORT_COLUMNS: &'static [(&'static str, Column)] = &[
+        ("name", Column::Name),
+        ("parent_id", Column::ParentId),
+        ("created_at", Column::CreatedAt),
+        ("updated_at", Column::UpdatedAt),
+    ];
+
+    fn id_column() -> Column { Column::Id }
+    fn updated_at_column() -> Column { Column::UpdatedAt }
+    fn deleted_at_column() -> Column { Column::DeletedAt }
+}
+
+#[async_trait]
+pub trait CategoryRepository: Send + Sync {
+    async fn create(&self, category: ActiveModel) -> Result<CategoryModel, AppError>;
+    async fn find_by_id(&self, id: i32) -> Result<Option<CategoryModel>, AppError>;
+    async fn find_by_name(&self, name: &str, parent_id: Option<i32>) -> Result<Option<CategoryModel>, AppError>;
+    async fn find_all(&self) -> Result<Vec<CategoryModel>, AppError>;
+    async fn find_descendant_ids(&self, ids: &[i32]) -> Result<Vec<i32>, AppError>;
+    async fn count_children(&self, id: i32) -> Result<u64, AppError>;
+    async fn find_with_pagination(&self, query: GetListCategoryQuery) -> Result<PaginatedResult<CategoryModel>, AppError>;
+    async fn update(&self, id: i32, category: ActiveModel) -> Result<CategoryModel, AppError>;
+    async fn delete(&self, id: i32) -> Result<CategoryModel, AppError>;
+}
+
+pub struct CategoryRepositoryImpl {
+    base: SoftDeleteRepository<Category>,
+}
+
+impl CategoryRepositoryImpl {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { base: SoftDeleteRepository::new(db) }
+    }
+}
+
+#[async_trait]
+impl CategoryRepository for CategoryRepositoryImpl {
+    async fn create(&self, category: ActiveModel) -> Result<CategoryModel, AppError> {
+        self.base.create(category).await
+    }
+
+    async fn find_by_id(&self, id: i32) -> Result<Option<CategoryModel>, AppError> {
+        self.base.find_by_id(id).await
+    }
+
+    /// Names are unique among siblings
+    async fn find_by_name(&self, name: &str, parent_id: Option<i32>) -> Result<Option<CategoryModel>, AppError> {
+        let parent_condition = match parent_id {
+            Some(parent_id) => Column::ParentId.eq(parent_id),
+            None => Column::ParentId.is_null(),
+        };
+
+        self.base
+            .find_one(Condition::all().add(Column::Name.eq(name)).add(parent_condition))
+            .await
+    }
+
+    async fn find_all(&self) -> Result<Vec<CategoryModel>, AppError> {
+        self.base.find_all(Condition::all()).await
+    }
+
+    /// `ids` plus every live category below them
+    async fn find_descendant_ids(&self, ids: &[i32]) -> Result<Vec<i32>, AppError> {
+        let mut children: HashMap<i32, Vec<i32>> = HashMap::new();
+        for category in self.find_all().await? {
+            if let Some(parent_id) = category.parent_id {
+                children.entry(parent_id).or_default().push(category.id);
+            }
+        }
+
+        let mut seen: HashSet<i32> = HashSet::new();
+        let mut stack = ids.to_vec();
+        while let Some(id) = stack.pop() {
+            if seen.insert(id) {
+                stack.extend(children.get(&id).into_iter().flatten());
+            }
+        }
+
+        let mut descendant_ids: Vec<i32> = seen.into_iter().collect();
+        descendant_ids.sort_unstable();
+        Ok(descendant_ids)
+    }
+
+    async fn count_children(&self, id: i32) -> Result<u64, AppError> {
+        self.base.count(Condition::all().add(Column::ParentId.eq(id))).await
+    }
+
+    async fn find_with_pagination(
+        &self,
+        query: GetListCategoryQuery
+    ) -> Result<PaginatedResult<CategoryModel>, AppError> {
+        let mut search_condition = Condition::all();
+
+        match query.parent_id.as_deref().map(str::trim) {
+            Some("root") | Some("null") => {
+                search_condition = search_condition.add(Column::ParentId.is_null());
+            }
+            Some(parent_id) if !parent_id.is_empty() => {
+                let parent_id = parent_id
+                    .parse::<i32>()
+                    .map_err(|_| AppError::BadRequest("Invalid parent_id".to_string()))?;
+                search_condition = search_condition.add(Column::ParentId.eq(parent_id));
+            }
+            _ => {}
+        }
+
+        let page_query = PageQuery {
+            limit: query.limit,
+            page: query.page,
+            search: query.search,
+            order_by: query.order_by,
+            direction: query.direction,
+            ..Default::default()
+        };
+
+        self.base.find_with_pagination(page_query, search_condition).await
+    }
+
+    async fn update(&self, id: i32, category: ActiveModel) -> Result<CategoryModel, AppError> {
+        self.base.update(id, category).await
+    }
+
+    async fn delete(&self, id: i32) -> Result<CategoryModel, AppError> {
+        self.base.delete(id).await
+    }
+}
@@ -1,3 +1,28 @@
+pub mod soft_delete_repository;
 pub mod cashflow_repository;
 pub mod transaction_repository;
-pub mod product_repository;
\ No newline at end of file
+pub mod product_repository;
+pub mod user_repository;
+pub mod audit_log_repository;
+pub mod product_price_repository;
+pub mod category_repository;
+pub mod report_repository;
+pub mod product_barcode_repository;
+pub mod product_variant_repository;
+pub mod stock_repository;
+pub mod modifier_group_repository;
+pub mod modifier_repository;
+pub mod transaction_modifier_repository;
+pub mod recipe_item_repository;
+pub mod bundle_item_repository;
+pub mod transaction_allocation_repository;
+pub mod product_unit_repository;
+pub mod supplier_repository;
+pub mod purchase_order_repository;
+pub mod purchase_order_item_repository;
+pub mod stock_count_repository;
+pub mod stock_count_item_repository;
+pub mod stock_lot_repository;
+pub mod transaction_return_repository;
+pub mod transaction_payment_repository;
+pub mod promotion_repository;
\ No newline at end of file
@@ -0,0 +1,136 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use sea_orm::{
+    ColumnTrait, Condition, DatabaseConnection, DbErr, EntityTrait, QueryFilter, QuerySelect, Set,
+    TransactionTrait,
+};
+use crate::{
+    module::modifier::v1::entity::model::{
+        modifier_group::{ActiveModel, Entity as ModifierGroup, Model as ModifierGroupModel, Column},
+        product_modifier_group::{
+            ActiveModel as ProductModifierGroupActiveModel, Entity as ProductModifierGroup,
+            Column as ProductModifierGroupColumn,
+        },
+    },
+    module::repository::soft_delete_repository::{SoftDeleteEntity, SoftDeleteRepository},
+    pkg::custom_error::AppError,
+};
+use tracing::log::error;
+
+fn db_error(err: DbErr) -> AppError {
+    error!("DB error: {:?}", err);
+    AppError::InternalError
+}
+
+impl SoftDeleteEntity for ModifierGroup {
+    type ActiveModel = ActiveModel;
+
+    const NAME: &'static str = "Modifier group";
+    const SEARCH_COLUMNS: &'static [Column] = &[Column::Name];
+    const SORT_COLUMNS: &'static [(&'static str, Column)] = &[
+        ("name", Column::Name),
+        ("created_at", Column::CreatedAt),
+        ("updated_at", Column::UpdatedAt),
+    ];
+
+    fn id_column() -> Column { Column::Id }
+    fn updated_at_column() -> Column { Column::UpdatedAt }
+    fn deleted_at_column() -> Column { Column::DeletedAt }
+}
+
+#[async_trait]
+pub trait ModifierGroupRepository: Send + Sync {
+    async fn create(&self, group: ActiveModel) -> Result<ModifierGroupModel, AppError>;
+    async fn find_by_id(&self, id: i32) -> Result<Option<ModifierGroupModel>, AppError>;
+    async fn find_by_ids(&self, ids: &[i32]) -> Result<Vec<ModifierGroupModel>, AppError>;
+    async fn find_by_name(&self, name: &str) -> Result<Option<ModifierGroupModel>, AppError>;
+    async fn find_all(&self) -> Result<Vec<ModifierGroupModel>, AppError>;
+    async fn find_by_product(&self, product_id: i32) -> Result<Vec<ModifierGroupModel>, AppError>;
+    async fn set_for_product(&self, product_id: i32, group_ids: &[i32]) -> Result<(), AppError>;
+    async fn update(&self, id: i32, group: ActiveModel) -> Result<ModifierGroupModel, AppError>;
+    async fn delete(&self, id: i32) -> Result<ModifierGroupModel, AppError>;
+}
+
+pub struct ModifierGroupRepositoryImpl {
+    base: SoftDeleteRepository<ModifierGroup>,
+}
+
+impl ModifierGroupRepositoryImpl {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { base: SoftDeleteRepository::new(db) }
+    }
+}
+
+#[async_trait]
+impl ModifierGroupRepository for ModifierGroupRepositoryImpl {
+    async fn create(&self, group: ActiveModel) -> Result<ModifierGroupModel, AppError> {
+        self.base.create(group).await
+    }
+
+    async fn find_by_id(&self, id: i32) -> Result<Option<ModifierGroupModel>, AppError> {
+        self.base.find_by_id(id).await
+    }
+
+    async fn find_by_ids(&self, ids: &[i32]) -> Result<Vec<ModifierGroupModel>, AppError> {
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        self.base.find_all(Condition::all().add(Column::Id.is_in(ids.to_vec()))).await
+    }
+
+    async fn find_by_name(&self, name: &str) -> Result<Option<ModifierGroupModel>, AppError> {
+        self.base.find_one(Condition::all().add(Column::Name.eq(name))).await
+    }
+
+    async fn find_all(&self) -> Result<Vec<ModifierGroupModel>, AppError> {
+        self.base.find_all(Condition::all()).await
+    }
+
+    /// Live groups offered on the product
+    async fn find_by_product(&self, product_id: i32) -> Result<Vec<ModifierGroupModel>, AppError> {
+        let group_ids: Vec<i32> = ProductModifierGroup::find()
+            .select_only()
+            .column(ProductModifierGroupColumn::GroupId)
+            .filter(ProductModifierGroupColumn::ProductId.eq(product_id))
+            .into_tuple()
+            .all(self.base.db())
+            .await
+            .map_err(db_error)?;
+
+        self.find_by_ids(&group_ids).await
+    }
+
+    /// Offer exactly `group_ids` on the product
+    async fn set_for_product(&self, product_id: i32, group_ids: &[i32]) -> Result<(), AppError> {
+        let txn = self.base.db().begin().await.map_err(db_error)?;
+
+        ProductModifierGroup::delete_many()
+            .filter(ProductModifierGroupColumn::ProductId.eq(product_id))
+            .exec(&txn)
+            .await
+            .map_err(db_error)?;
+
+        if !group_ids.is_empty() {
+            let links = group_ids.iter().map(|group_id| ProductModifierGroupActiveModel {
+                product_id: Set(product_id),
+                group_id: Set(*group_id),
+            });
+
+            ProductModifierGroup::insert_many(links)
+                .exec(&txn)
+                .await
+                .map_err(db_error)?;
+        }
+
+        txn.commit().await.map_err(db_error)
+    }
+
+    async fn update(&self, id: i32, group: ActiveModel) -> Result<ModifierGroupModel, AppError> {
+        self.base.update(id, group).await
+    }
+
+    async fn delete(&self, id: i32) -> Result<ModifierGroupModel, AppError> {
+        self.base.delete(id).await
+    }
+}
@@ -0,0 +1,89 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use sea_orm::{ColumnTrait, Condition, DatabaseConnection};
+use crate::{
+    module::modifier::v1::entity::model::modifier::{ActiveModel, Entity as Modifier, Model as ModifierModel, Column},
+    module::repository::soft_delete_repository::{SoftDeleteEntity, SoftDeleteRepository},
+    pkg::custom_error::AppError,
+};
+
+
+impl SoftDeleteEntity for Modifier {
+    type ActiveModel = ActiveModel;
+
+    const NAME: &'static str = "Modifier";
+    const SEARCH_COLUMNS: &'static [Column] = &[Column::Name];
+    const SORT_COLUMNS: &'static [(&'static str, Column)] = &[
+        ("name", Column::Name),
+        ("hpp_amount", Column::HppAmount),
+        ("selling_amount", Column::SellingAmount),
+        ("created_at", Column::CreatedAt),
+        ("updated_at", Column::UpdatedAt),
+    ];
+
+    fn id_column() -> Column { Column::Id }
+    fn updated_at_column() -> Column { Column::UpdatedAt }
+    fn deleted_at_column() -> Column { Column::DeletedAt }
+}
+
+#[async_trait]
+pub trait ModifierRepository: Send + Sync {
+    async fn create(&self, modifier: ActiveModel) -> Result<ModifierModel, AppError>;
+    async fn find_by_id(&self, id: i32) -> Result<Option<ModifierModel>, AppError>;
+    async fn find_by_ids(&self, ids: &[i32]) -> Result<Vec<ModifierModel>, AppError>;
+    async fn find_by_groups(&self, group_ids: &[i32]) -> Result<Vec<ModifierModel>, AppError>;
+    async fn find_by_name(&self, group_id: i32, name: &str) -> Result<Option<ModifierModel>, AppError>;
+    async fn update(&self, id: i32, modifier: ActiveModel) -> Result<ModifierModel, AppError>;
+    async fn delete(&self, id: i32) -> Result<ModifierModel, AppError>;
+}
+
+pub struct ModifierRepositoryImpl {
+    base: SoftDeleteRepository<Modifier>,
+}
+
+impl ModifierRepositoryImpl {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { base: SoftDeleteRepository::new(db) }
+    }
+}
+
+#[async_trait]
+impl ModifierRepository for ModifierRepositoryImpl {
+    async fn create(&self, modifier: ActiveModel) -> Result<ModifierModel, AppError> {
+        self.base.create(modifier).await
+    }
+
+    async fn find_by_id(&self, id: i32) -> Result<Option<ModifierModel>, AppError> {
+        self.base.find_by_id(id).await
+    }
+
+    async fn find_by_ids(&self, ids: &[i32]) -> Result<Vec<ModifierModel>, AppError> {
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        self.base.find_all(Condition::all().add(Column::Id.is_in(ids.to_vec()))).await
+    }
+
+    async fn find_by_groups(&self, group_ids: &[i32]) -> Result<Vec<ModifierModel>, AppError> {
+        if group_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        self.base.find_all(Condition::all().add(Column::GroupId.is_in(group_ids.to_vec()))).await
+    }
+
+    async fn find_by_name(&self, group_id: i32, name: &str) -> Result<Option<ModifierModel>, AppError> {
+        self.base
+            .find_one(Condition::all().add(Column::GroupId.eq(group_id)).add(Column::Name.eq(name)))
+            .await
+    }
+
+    async fn update(&self, id: i32, modifier: ActiveModel) -> Result<ModifierModel, AppError> {
+        self.base.update(id, modifier).await
+    }
+
+    async fn delete(&self, id: i32) -> Result<ModifierModel, AppError> {
+        self.base.delete(id).await
+    }
+}
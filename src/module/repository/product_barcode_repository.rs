@@ -0,0 +1,93 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::Utc;
+use sea_orm::{
+    ColumnTrait, DatabaseConnection, DbErr, EntityTrait, JoinType, Order, QueryFilter, QueryOrder, QuerySelect,
+    RelationTrait, Set, TransactionTrait,
+};
+use crate::{
+    module::product::v1::entity::model::product::Column as ProductColumn,
+    module::product::v1::entity::model::product_barcode::{ActiveModel, Entity as ProductBarcode, Model as ProductBarcodeModel, Column, Relation},
+    pkg::custom_error::AppError,
+};
+use tracing::log::error;
+
+fn db_error(err: DbErr) -> AppError {
+    error!("DB error: {:?}", err);
+    AppError::InternalError
+}
+
+#[async_trait]
+pub trait ProductBarcodeRepository: Send + Sync {
+    async fn find_by_products(&self, product_ids: &[i32]) -> Result<Vec<ProductBarcodeModel>, AppError>;
+    async fn find_live_by_codes(&self, codes: &[String]) -> Result<Vec<ProductBarcodeModel>, AppError>;
+    async fn replace(&self, product_id: i32, codes: &[String]) -> Result<(), AppError>;
+}
+
+pub struct ProductBarcodeRepositoryImpl {
+    db: Arc<DatabaseConnection>,
+}
+
+impl ProductBarcodeRepositoryImpl {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl ProductBarcodeRepository for ProductBarcodeRepositoryImpl {
+    async fn find_by_products(&self, product_ids: &[i32]) -> Result<Vec<ProductBarcodeModel>, AppError> {
+        if product_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        ProductBarcode::find()
+            .filter(Column::ProductId.is_in(product_ids.to_vec()))
+            .order_by(Column::Id, Order::Asc)
+            .all(self.db.as_ref())
+            .await
+            .map_err(db_error)
+    }
+
+    /// Barcodes among `codes` that belong to products which are not deleted
+    async fn find_live_by_codes(&self, codes: &[String]) -> Result<Vec<ProductBarcodeModel>, AppError> {
+        if codes.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        ProductBarcode::find()
+            .join(JoinType::InnerJoin, Relation::Product.def())
+            .filter(Column::Code.is_in(codes.to_vec()))
+            .filter(ProductColumn::DeletedAt.is_null())
+            .all(self.db.as_ref())
+            .await
+            .map_err(db_error)
+    }
+
+    /// Set the product's barcodes to exactly `codes`
+    async fn replace(&self, product_id: i32, codes: &[String]) -> Result<(), AppError> {
+        let txn = self.db.begin().await.map_err(db_error)?;
+
+        ProductBarcode::delete_many()
+            .filter(Column::ProductId.eq(product_id))
+            .exec(&txn)
+            .await
+            .map_err(db_error)?;
+
+        if !codes.is_empty() {
+            let barcodes = codes.iter().map(|code| ActiveModel {
+                product_id: Set(product_id),
+                code: Set(code.clone()),
+                created_at: Set(Some(Utc::now())),
+                ..Default::default()
+            });
+
+            ProductBarcode::insert_many(barcodes)
+                .exec(&txn)
+                .await
+                .map_err(db_error)?;
+        }
+
+        txn.commit().await.map_err(db_error)
+    }
+}
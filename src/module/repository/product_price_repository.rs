@@ -0,0 +1,101 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sea_orm::{
+    sea_query::Expr, ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, Order,
+    QueryFilter, QueryOrder,
+};
+use crate::{
+    module::product::v1::entity::model::product_price::{ActiveModel, Entity as ProductPrice, Model as ProductPriceModel, Column},
+    pkg::custom_error::AppError,
+};
+use tracing::log::error;
+
+fn db_error(err: DbErr) -> AppError {
+    error!("DB error: {:?}", err);
+    AppError::InternalError
+}
+
+#[async_trait]
+pub trait ProductPriceRepository: Send + Sync {
+    async fn create(&self, price: ActiveModel) -> Result<ProductPriceModel, AppError>;
+    async fn find_by_id(&self, id: i32) -> Result<Option<ProductPriceModel>, AppError>;
+    async fn find_by_product(&self, product_id: i32) -> Result<Vec<ProductPriceModel>, AppError>;
+    async fn find_due(&self, now: DateTime<Utc>) -> Result<Vec<ProductPriceModel>, AppError>;
+    async fn mark_applied(&self, id: i32, hpp_amount: i32, selling_amount: i32, applied_at: DateTime<Utc>) -> Result<bool, AppError>;
+    async fn delete_scheduled(&self, id: i32) -> Result<bool, AppError>;
+}
+
+pub struct ProductPriceRepositoryImpl {
+    db: Arc<DatabaseConnection>,
+}
+
+impl ProductPriceRepositoryImpl {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl ProductPriceRepository for ProductPriceRepositoryImpl {
+    async fn create(&self, price: ActiveModel) -> Result<ProductPriceModel, AppError> {
+        price.insert(self.db.as_ref()).await.map_err(db_error)
+    }
+
+    async fn find_by_id(&self, id: i32) -> Result<Option<ProductPriceModel>, AppError> {
+        ProductPrice::find_by_id(id)
+            .one(self.db.as_ref())
+            .await
+            .map_err(db_error)
+    }
+
+    /// Scheduled changes first, then history newest first
+    async fn find_by_product(&self, product_id: i32) -> Result<Vec<ProductPriceModel>, AppError> {
+        ProductPrice::find()
+            .filter(Column::ProductId.eq(product_id))
+            .order_by(Column::EffectiveFrom, Order::Desc)
+            .order_by(Column::Id, Order::Desc)
+            .all(self.db.as_ref())
+            .await
+            .map_err(db_error)
+    }
+
+    /// Scheduled changes whose time has come, oldest first
+    async fn find_due(&self, now: DateTime<Utc>) -> Result<Vec<ProductPriceModel>, AppError> {
+        ProductPrice::find()
+            .filter(Column::AppliedAt.is_null())
+            .filter(Column::EffectiveFrom.lte(now))
+            .order_by(Column::EffectiveFrom, Order::Asc)
+            .order_by(Column::Id, Order::Asc)
+            .all(self.db.as_ref())
+            .await
+            .map_err(db_error)
+    }
+
+    /// Claim a scheduled change; false when another run already applied it
+    async fn mark_applied(&self, id: i32, hpp_amount: i32, selling_amount: i32, applied_at: DateTime<Utc>) -> Result<bool, AppError> {
+        let result = ProductPrice::update_many()
+            .col_expr(Column::HppAmount, Expr::value(hpp_amount))
+            .col_expr(Column::SellingAmount, Expr::value(selling_amount))
+            .col_expr(Column::AppliedAt, Expr::value(applied_at))
+            .filter(Column::Id.eq(id))
+            .filter(Column::AppliedAt.is_null())
+            .exec(self.db.as_ref())
+            .await
+            .map_err(db_error)?;
+
+        Ok(result.rows_affected > 0)
+    }
+
+    /// Cancel a change that has not taken effect yet
+    async fn delete_scheduled(&self, id: i32) -> Result<bool, AppError> {
+        let result = ProductPrice::delete_many()
+            .filter(Column::Id.eq(id))
+            .filter(Column::AppliedAt.is_null())
+            .exec(self.db.as_ref())
+            .await
+            .map_err(db_error)?;
+
+        Ok(result.rows_affected > 0)
+    }
+}
@@ -1,217 +1,265 @@
 use std::sync::Arc;
 use async_trait::async_trait;
-use sea_orm::{ColumnTrait, Condition, DatabaseConnection, EntityTrait, Order, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, Set};
-use chrono::Utc;
+use chrono::{DateTime, Utc};
+use sea_orm::{prelude::Decimal, sea_query::{Expr, Query}, ColumnTrait, Condition, DatabaseConnection};
 use crate::{
     module::product::v1::entity::model::product::{ActiveModel, Entity as Product, Model as ProductModel, Column},
+    module::product::v1::entity::model::product_variant::{Entity as ProductVariant, Column as ProductVariantColumn},
+    module::product::v1::entity::model::product_unit::{Entity as ProductUnit, Column as ProductUnitColumn},
+    module::inventory::v1::entity::model::stock_movement::{Entity as StockMovement, Column as StockMovementColumn},
     module::product::v1::entity::request::list_product_request::GetListProductQuery,
+    module::transaction::v1::entity::model::transaction::{Entity as Transaction, Column as TransactionColumn},
+    module::transaction::v1::entity::model::transaction_allocation::{Entity as TransactionAllocation, Column as TransactionAllocationColumn},
+    module::purchase::v1::entity::model::purchase_order_item::{Entity as PurchaseOrderItem, Column as PurchaseOrderItemColumn},
+    module::stock_count::v1::entity::model::stock_count_item::{Entity as StockCountItem, Column as StockCountItemColumn},
+    module::inventory::v1::entity::model::stock_lot::{Entity as StockLot, Column as StockLotColumn},
+    module::promotion::v1::entity::model::promotion::{Entity as Promotion, Column as PromotionColumn},
+    module::repository::soft_delete_repository::{PurgeSummary, SoftDeleteEntity, SoftDeleteRepository},
     pkg::{
         custom_error::AppError,
-        pagination::PaginatedResult,
+        filter,
+        pagination::{PageQuery, PaginatedResult},
     }
 };
-use tracing::log::error;
 
 
+impl SoftDeleteEntity for Product {
+    type ActiveModel = ActiveModel;
+
+    const NAME: &'static str = "Product";
+    const SEARCH_COLUMNS: &'static [Column] = &[Column::Name, Column::Sku];
+    const SORT_COLUMNS: &'static [(&'static str, Column)] = &[
+        ("name", Column::Name),
+        ("sku", Column::Sku),
+        ("hpp_amount", Column::HppAmount),
+        ("selling_amount", Column::SellingAmount),
+        ("created_at", Column::CreatedAt),
+        ("updated_at", Column::UpdatedAt),
+    ];
+
+    fn id_column() -> Column { Column::Id }
+    fn updated_at_column() -> Column { Column::UpdatedAt }
+    fn deleted_at_column() -> Column { Column::DeletedAt }
+}
+
 #[async_trait]
 pub trait ProductRepository: Send + Sync {
-    async fn create(&self, product: ActiveModel) -> Result<(), AppError>;
+    async fn create(&self, product: ActiveModel) -> Result<ProductModel, AppError>;
     async fn find_by_id(&self, id: i32) -> Result<Option<ProductModel>, AppError>;
-    // async fn find_all(&self) -> Result<Vec<ProductModel>, AppError>;
+    async fn find_by_ids(&self, ids: &[i32]) -> Result<Vec<ProductModel>, AppError>;
     async fn find_by_name(&self, name: &str) -> Result<Option<ProductModel>, AppError>;
+    async fn find_by_sku(&self, sku: &str) -> Result<Option<ProductModel>, AppError>;
+    async fn count_by_category(&self, category_id: i32) -> Result<u64, AppError>;
+    async fn find_below_reorder_point(&self) -> Result<Vec<ProductModel>, AppError>;
+    async fn has_unit_history(&self, id: i32) -> Result<bool, AppError>;
     async fn find_with_pagination(&self, query: GetListProductQuery) -> Result<PaginatedResult<ProductModel>, AppError>;
     async fn update(&self, id: i32, product: ActiveModel) -> Result<ProductModel, AppError>;
-    async fn delete(&self, id: i32) -> Result<(), AppError>;
+    async fn delete(&self, id: i32) -> Result<ProductModel, AppError>;
+    async fn find_deleted_by_id(&self, id: i32) -> Result<Option<ProductModel>, AppError>;
+    async fn find_deleted_with_pagination(&self, query: GetListProductQuery) -> Result<PaginatedResult<ProductModel>, AppError>;
+    async fn restore(&self, id: i32) -> Result<ProductModel, AppError>;
+    async fn purge(&self, id: i32) -> Result<(), AppError>;
+    async fn purge_deleted_before(&self, cutoff: DateTime<Utc>, dry_run: bool) -> Result<PurgeSummary, AppError>;
 }
 
 pub struct ProductRepositoryImpl {
-    db: Arc<DatabaseConnection>,
+    base: SoftDeleteRepository<Product>,
 }
 
 impl ProductRepositoryImpl {
     pub fn new(db: Arc<DatabaseConnection>) -> Self {
-        Self { db }
+        Self { base: SoftDeleteRepository::new(db) }
+    }
+
+    /// Paging parameters and filter condition of a list query
+    fn list_query(query: GetListProductQuery) -> Result<(PageQuery, Condition), AppError> {
+        // Build filter condition
+        let mut search_condition = Condition::all();
+
+        let category_ids = filter::parse_list::<i32>("category_id", query.category_id.as_deref())?;
+        if !category_ids.is_empty() {
+            search_condition = search_condition.add(Column::CategoryId.is_in(category_ids));
+        }
+
+        let page_query = PageQuery {
+            limit: query.limit,
+            page: query.page,
+            search: query.search,
+            order_by: query.order_by,
+            direction: query.direction,
+            ..Default::default()
+        };
+
+        Ok((page_query, search_condition))
     }
 }
 
 #[async_trait]
 impl ProductRepository for ProductRepositoryImpl {
-    async fn create(&self, product: ActiveModel) -> Result<(), AppError> {
-        Product::insert(product)
-            .exec(self.db.as_ref())
-            .await
-            .map_err(|err| {
-                error!("DB error: {:?}", err);
-                AppError::InternalError
-            })?;
-
-        Ok(())
+    async fn create(&self, product: ActiveModel) -> Result<ProductModel, AppError> {
+        self.base.create(product).await
     }
 
     async fn find_by_id(&self, id: i32) -> Result<Option<ProductModel>, AppError> {
-        Product::find_by_id(id)
-            .filter(Column::DeletedAt.is_null())
-            .one(self.db.as_ref())
-            .await
-            .map_err(|err| {
-                error!("DB error: {:?}", err);
-                AppError::InternalError
-            })
-    }
-
-    // async fn find_all(&self) -> Result<Vec<ProductModel>, AppError> {
-    //     Product::find()
-    //         .filter(Column::DeletedAt.is_null())
-    //         .all(self.db.as_ref())
-    //         .await
-    //         .map_err(|err| {
-    //             error!("DB error: {:?}", err);
-    //             AppError::InternalError
-    //         })
-    // }
+        self.base.find_by_id(id).await
+    }
+
+    async fn find_by_ids(&self, ids: &[i32]) -> Result<Vec<ProductModel>, AppError> {
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        self.base.find_all(Condition::all().add(Column::Id.is_in(ids.to_vec()))).await
+    }
 
     async fn find_by_name(&self, name: &str) -> Result<Option<ProductModel>, AppError> {
-        Product::find()
-            .filter(Column::Name.eq(name))
-            .filter(Column::DeletedAt.is_null())
-            .one(self.db.as_ref())
+        self.base.find_one(Condition::all().add(Column::Name.eq(name))).await
+    }
+
+    async fn find_by_sku(&self, sku: &str) -> Result<Option<ProductModel>, AppError> {
+        self.base.find_one(Condition::all().add(Column::Sku.eq(sku))).await
+    }
+
+    async fn count_by_category(&self, category_id: i32) -> Result<u64, AppError> {
+        self.base.count(Condition::all().add(Column::CategoryId.eq(category_id))).await
+    }
+
+    /// Whether the product has stock, units, movements or sales already counted in its base unit
+    async fn has_unit_history(&self, id: i32) -> Result<bool, AppError> {
+        let units = Query::select()
+            .column(ProductUnitColumn::ProductId)
+            .from(ProductUnit)
+            .to_owned();
+        let movements = Query::select()
+            .column(StockMovementColumn::ProductId)
+            .from(StockMovement)
+            .to_owned();
+        let sales = Query::select()
+            .column(TransactionColumn::ProductId)
+            .from(Transaction)
+            .to_owned();
+
+        let count = self.base
+            .count(
+                Condition::all()
+                    .add(Column::Id.eq(id))
+                    .add(
+                        Condition::any()
+                            .add(Column::Stock.ne(Decimal::ZERO))
+                            .add(Column::Id.in_subquery(units))
+                            .add(Column::Id.in_subquery(movements))
+                            .add(Column::Id.in_subquery(sales)),
+                    ),
+            )
+            .await?;
+
+        Ok(count > 0)
+    }
+
+    /// Products without variants whose stock is at or below their reorder point
+    async fn find_below_reorder_point(&self) -> Result<Vec<ProductModel>, AppError> {
+        let with_variants = Query::select()
+            .column(ProductVariantColumn::ProductId)
+            .from(ProductVariant)
+            .and_where(ProductVariantColumn::DeletedAt.is_null())
+            .to_owned();
+
+        self.base
+            .find_all(
+                Condition::all()
+                    .add(Column::ReorderPoint.is_not_null())
+                    .add(Expr::col(Column::Stock).lte(Expr::col(Column::ReorderPoint)))
+                    .add(Column::Id.not_in_subquery(with_variants)),
+            )
             .await
-            .map_err(|err| {
-                error!("DB error: {:?}", err);
-                AppError::InternalError
-            })
     }
 
     async fn find_with_pagination(
         &self,
         query: GetListProductQuery
     ) -> Result<PaginatedResult<ProductModel>, AppError> {
-        // Parse pagination parameters
-        let page = query.page
-            .and_then(|p| p.parse::<u64>().ok())
-            .unwrap_or(1)
-            .max(1);
-    
-        let limit = query.limit
-            .and_then(|l| l.parse::<u64>().ok())
-            .unwrap_or(10)
-            .min(100);
-    
-        let offset = (page - 1) * limit;
-    
-        // Build search condition
-        let mut search_condition = Condition::all().add(Column::DeletedAt.is_null());
-        if let Some(search) = &query.search {
-            if !search.trim().is_empty() {
-                search_condition = search_condition.add(Column::Name.contains(search.trim()));
-            }
-        }
-    
-        let search_condition_for_count = search_condition.clone();
-    
-        // Build ordering
-        let mut query_builder = Product::find().filter(search_condition);
-    
-        if let Some(order_by) = &query.order_by {
-            let direction = match query.direction.as_deref() {
-                Some("desc") | Some("DESC") => Order::Desc,
-                _ => Order::Asc,
-            };
-    
-            query_builder = match order_by.to_lowercase().as_str() {
-                "name" => query_builder.order_by(Column::Name, direction),
-                "hpp_amount" => query_builder.order_by(Column::HppAmount, direction),
-                "selling_amount" => query_builder.order_by(Column::SellingAmount, direction),
-                "created_at" => query_builder.order_by(Column::CreatedAt, direction),
-                "updated_at" => query_builder.order_by(Column::UpdatedAt, direction),
-                _ => query_builder.order_by(Column::Id, Order::Asc),
-            };
-        } else {
-            query_builder = query_builder.order_by(Column::Id, Order::Asc);
-        }
-    
-        // Get total count
-        let total = Product::find()
-            .filter(search_condition_for_count)
-            .count(self.db.as_ref())
-            .await
-            .map_err(|err| {
-                error!("DB error: {:?}", err);
-                AppError::InternalError
-            })?;
-    
-        // Get paginated data
-        let data = query_builder
-            .offset(offset)
-            .limit(limit)
-            .all(self.db.as_ref())
-            .await
-            .map_err(|err| {
-                error!("DB error: {:?}", err);
-                AppError::InternalError
-            })?;
-    
-        let total_pages = (total as u64 as f64 / limit as f64).ceil() as u64;
-    
-        Ok(PaginatedResult {
-            data,
-            total,
-            page,
-            limit,
-            total_pages,
-        })
-    }
-    
-
-    async fn update(&self, id: i32, mut product: ActiveModel) -> Result<ProductModel, AppError> {
-        // Set updated_at timestamp
-        product.updated_at = Set(Some(Utc::now()));
-
-        let result = Product::update_many()
-            .set(product)
-            .filter(Column::Id.eq(id))
-            .filter(Column::DeletedAt.is_null())
-            .exec(self.db.as_ref())
-            .await
-            .map_err(|err| {
-                error!("DB error: {:?}", err);
-                AppError::InternalError
-            })?;
+        let (page_query, condition) = Self::list_query(query)?;
+        self.base.find_with_pagination(page_query, condition).await
+    }
 
-        if result.rows_affected == 0 {
-            return Err(AppError::NotFound(format!("Product with id {} not found", id)));
-        }
+    async fn update(&self, id: i32, product: ActiveModel) -> Result<ProductModel, AppError> {
+        self.base.update(id, product).await
+    }
 
-        // Fetch the updated product
-        Product::find_by_id(id)
-            .filter(Column::DeletedAt.is_null())
-            .one(self.db.as_ref())
-            .await
-            .map_err(|err| {
-                error!("DB error: {:?}", err);
-                AppError::InternalError
-            })?
-            .ok_or_else(|| AppError::NotFound(format!("Product with id {} not found", id)))
-    }
-
-    async fn delete(&self, id: i32) -> Result<(), AppError> {
-        let now = Utc::now();
-        let result = Product::update_many()
-            .col_expr(Column::DeletedAt, now.into())
-            .col_expr(Column::UpdatedAt, now.into())
-            .filter(Column::Id.eq(id))
-            .filter(Column::DeletedAt.is_null())
-            .exec(self.db.as_ref())
-            .await
-            .map_err(|err| {
-                error!("DB error: {:?}", err);
-                AppError::InternalError
-            })?;
+    async fn delete(&self, id: i32) -> Result<ProductModel, AppError> {
+        self.base.delete(id).await
+    }
 
-        if result.rows_affected == 0 {
-            return Err(AppError::NotFound(format!("Product with id {} not found", id)));
-        }
+    async fn find_deleted_by_id(&self, id: i32) -> Result<Option<ProductModel>, AppError> {
+        self.base.find_deleted_by_id(id).await
+    }
+
+    async fn find_deleted_with_pagination(
+        &self,
+        query: GetListProductQuery
+    ) -> Result<PaginatedResult<ProductModel>, AppError> {
+        let (page_query, condition) = Self::list_query(query)?;
+        self.base.find_deleted_with_pagination(page_query, condition).await
+    }
+
+    async fn restore(&self, id: i32) -> Result<ProductModel, AppError> {
+        self.base.restore(id).await
+    }
+
+    async fn purge(&self, id: i32) -> Result<(), AppError> {
+        self.base.purge(id).await
+    }
+
+    async fn purge_deleted_before(&self, cutoff: DateTime<Utc>, dry_run: bool) -> Result<PurgeSummary, AppError> {
+        // Keep products still referenced by transactions that outlive this purge
+        let referenced = Query::select()
+            .column(TransactionColumn::ProductId)
+            .from(Transaction)
+            .cond_where(
+                Condition::any()
+                    .add(TransactionColumn::DeletedAt.is_null())
+                    .add(TransactionColumn::DeletedAt.gte(cutoff)),
+            )
+            .to_owned();
+
+        // Bundle components keep the allocations of their sales
+        let allocated = Query::select()
+            .column(TransactionAllocationColumn::ProductId)
+            .from(TransactionAllocation)
+            .to_owned();
+
+        // Purchase orders keep their ordered products
+        let ordered = Query::select()
+            .column(PurchaseOrderItemColumn::ProductId)
+            .from(PurchaseOrderItem)
+            .to_owned();
+
+        // Stock counts keep their counted products
+        let counted = Query::select()
+            .column(StockCountItemColumn::ProductId)
+            .from(StockCountItem)
+            .to_owned();
+
+        // Lots keep their product, even once used up
+        let lotted = Query::select()
+            .column(StockLotColumn::ProductId)
+            .from(StockLot)
+            .to_owned();
+
+        // Promotions on a single product, deleted or not
+        let promoted = Query::select()
+            .column(PromotionColumn::ProductId)
+            .from(Promotion)
+            .and_where(PromotionColumn::ProductId.is_not_null())
+            .to_owned();
 
-        Ok(())
+        let condition = Condition::all()
+            .add(Column::Id.not_in_subquery(referenced))
+            .add(Column::Id.not_in_subquery(allocated))
+            .add(Column::Id.not_in_subquery(ordered))
+            .add(Column::Id.not_in_subquery(counted))
+            .add(Column::Id.not_in_subquery(lotted))
+            .add(Column::Id.not_in_subquery(promoted));
+        self.base.purge_deleted_before(cutoff, condition, dry_run).await
     }
 }
\ No newline at end of file
@@ -0,0 +1,81 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, Order, QueryFilter, QueryOrder,
+};
+use crate::{
+    module::product::v1::entity::model::product_unit::{ActiveModel, Entity as ProductUnit, Model as ProductUnitModel, Column},
+    pkg::custom_error::AppError,
+};
+use tracing::log::error;
+
+fn db_error(err: DbErr) -> AppError {
+    error!("DB error: {:?}", err);
+    AppError::InternalError
+}
+
+#[async_trait]
+pub trait ProductUnitRepository: Send + Sync {
+    async fn create(&self, unit: ActiveModel) -> Result<ProductUnitModel, AppError>;
+    async fn find_by_id(&self, id: i32) -> Result<Option<ProductUnitModel>, AppError>;
+    async fn find_by_product(&self, product_id: i32) -> Result<Vec<ProductUnitModel>, AppError>;
+    async fn find_by_name(&self, product_id: i32, name: &str) -> Result<Option<ProductUnitModel>, AppError>;
+    async fn update(&self, unit: ActiveModel) -> Result<ProductUnitModel, AppError>;
+    async fn delete(&self, id: i32) -> Result<(), AppError>;
+}
+
+pub struct ProductUnitRepositoryImpl {
+    db: Arc<DatabaseConnection>,
+}
+
+impl ProductUnitRepositoryImpl {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl ProductUnitRepository for ProductUnitRepositoryImpl {
+    async fn create(&self, unit: ActiveModel) -> Result<ProductUnitModel, AppError> {
+        unit.insert(self.db.as_ref()).await.map_err(db_error)
+    }
+
+    async fn find_by_id(&self, id: i32) -> Result<Option<ProductUnitModel>, AppError> {
+        ProductUnit::find_by_id(id)
+            .one(self.db.as_ref())
+            .await
+            .map_err(db_error)
+    }
+
+    async fn find_by_product(&self, product_id: i32) -> Result<Vec<ProductUnitModel>, AppError> {
+        ProductUnit::find()
+            .filter(Column::ProductId.eq(product_id))
+            .order_by(Column::Factor, Order::Asc)
+            .order_by(Column::Id, Order::Asc)
+            .all(self.db.as_ref())
+            .await
+            .map_err(db_error)
+    }
+
+    async fn find_by_name(&self, product_id: i32, name: &str) -> Result<Option<ProductUnitModel>, AppError> {
+        ProductUnit::find()
+            .filter(Column::ProductId.eq(product_id))
+            .filter(Column::Name.eq(name))
+            .one(self.db.as_ref())
+            .await
+            .map_err(db_error)
+    }
+
+    async fn update(&self, unit: ActiveModel) -> Result<ProductUnitModel, AppError> {
+        unit.update(self.db.as_ref()).await.map_err(db_error)
+    }
+
+    async fn delete(&self, id: i32) -> Result<(), AppError> {
+        ProductUnit::delete_by_id(id)
+            .exec(self.db.as_ref())
+            .await
+            .map_err(db_error)?;
+
+        Ok(())
+    }
+}
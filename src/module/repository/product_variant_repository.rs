@@ -0,0 +1,109 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use sea_orm::{sea_query::Expr, ColumnTrait, Condition, DatabaseConnection};
+use crate::{
+    module::product::v1::entity::model::product_variant::{ActiveModel, Entity as ProductVariant, Model as ProductVariantModel, Column},
+    module::repository::soft_delete_repository::{SoftDeleteEntity, SoftDeleteRepository},
+    pkg::custom_error::AppError,
+};
+
+
+impl SoftDeleteEntity for ProductVariant {
+    type ActiveModel = ActiveModel;
+
+    const NAME: &'static str = "Variant";
+    const SEARCH_COLUMNS: &'static [Column] = &[Column::Name, Column::Sku];
+    const SORT_COLUMNS: &'static [(&'static str, Column)] = &[
+        ("name", Column::Name),
+        ("sku", Column::Sku),
+        ("hpp_amount", Column::HppAmount),
+        ("selling_amount", Column::SellingAmount),
+        ("stock", Column::Stock),
+        ("created_at", Column::CreatedAt),
+        ("updated_at", Column::UpdatedAt),
+    ];
+
+    fn id_column() -> Column { Column::Id }
+    fn updated_at_column() -> Column { Column::UpdatedAt }
+    fn deleted_at_column() -> Column { Column::DeletedAt }
+}
+
+#[async_trait]
+pub trait ProductVariantRepository: Send + Sync {
+    async fn create(&self, variant: ActiveModel) -> Result<ProductVariantModel, AppError>;
+    async fn find_by_id(&self, id: i32) -> Result<Option<ProductVariantModel>, AppError>;
+    async fn find_by_ids(&self, ids: &[i32]) -> Result<Vec<ProductVariantModel>, AppError>;
+    async fn find_by_product(&self, product_id: i32) -> Result<Vec<ProductVariantModel>, AppError>;
+    async fn find_by_name(&self, product_id: i32, name: &str) -> Result<Option<ProductVariantModel>, AppError>;
+    async fn find_by_sku(&self, sku: &str) -> Result<Option<ProductVariantModel>, AppError>;
+    async fn count_by_product(&self, product_id: i32) -> Result<u64, AppError>;
+    async fn find_below_reorder_point(&self) -> Result<Vec<ProductVariantModel>, AppError>;
+    async fn update(&self, id: i32, variant: ActiveModel) -> Result<ProductVariantModel, AppError>;
+    async fn delete(&self, id: i32) -> Result<ProductVariantModel, AppError>;
+}
+
+pub struct ProductVariantRepositoryImpl {
+    base: SoftDeleteRepository<ProductVariant>,
+}
+
+impl ProductVariantRepositoryImpl {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { base: SoftDeleteRepository::new(db) }
+    }
+}
+
+#[async_trait]
+impl ProductVariantRepository for ProductVariantRepositoryImpl {
+    async fn create(&self, variant: ActiveModel) -> Result<ProductVariantModel, AppError> {
+        self.base.create(variant).await
+    }
+
+    async fn find_by_id(&self, id: i32) -> Result<Option<ProductVariantModel>, AppError> {
+        self.base.find_by_id(id).await
+    }
+
+    async fn find_by_ids(&self, ids: &[i32]) -> Result<Vec<ProductVariantModel>, AppError> {
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        self.base.find_all(Condition::all().add(Column::Id.is_in(ids.to_vec()))).await
+    }
+
+    async fn find_by_product(&self, product_id: i32) -> Result<Vec<ProductVariantModel>, AppError> {
+        self.base.find_all(Condition::all().add(Column::ProductId.eq(product_id))).await
+    }
+
+    async fn find_by_name(&self, product_id: i32, name: &str) -> Result<Option<ProductVariantModel>, AppError> {
+        self.base
+            .find_one(Condition::all().add(Column::ProductId.eq(product_id)).add(Column::Name.eq(name)))
+            .await
+    }
+
+    async fn find_by_sku(&self, sku: &str) -> Result<Option<ProductVariantModel>, AppError> {
+        self.base.find_one(Condition::all().add(Column::Sku.eq(sku))).await
+    }
+
+    async fn count_by_product(&self, product_id: i32) -> Result<u64, AppError> {
+        self.base.count(Condition::all().add(Column::ProductId.eq(product_id))).await
+    }
+
+    /// Variants whose stock is at or below their reorder point
+    async fn find_below_reorder_point(&self) -> Result<Vec<ProductVariantModel>, AppError> {
+        self.base
+            .find_all(
+                Condition::all()
+                    .add(Column::ReorderPoint.is_not_null())
+                    .add(Expr::col(Column::Stock).lte(Expr::col(Column::ReorderPoint))),
+            )
+            .await
+    }
+
+    async fn update(&self, id: i32, variant: ActiveModel) -> Result<ProductVariantModel, AppError> {
+        self.base.update(id, variant).await
+    }
+
+    async fn delete(&self, id: i32) -> Result<ProductVariantModel, AppError> {
+        self.base.delete(id).await
+    }
+}
@@ -0,0 +1,92 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use sea_orm::{ColumnTrait, Condition, DatabaseConnection};
+use crate::{
+    module::promotion::v1::entity::{
+        model::promotion::{ActiveModel, Entity as Promotion, Model as PromotionModel, Column},
+        request::list_promotion_request::GetListPromotionQuery,
+    },
+    module::repository::soft_delete_repository::{SoftDeleteEntity, SoftDeleteRepository},
+    pkg::{
+        custom_error::AppError,
+        pagination::{PageQuery, PaginatedResult},
+    }
+};
+
+
+impl SoftDeleteEntity for Promotion {
+    type ActiveModel = ActiveModel;
+
+    const NAME: &'static str = "Promotion";
+    const SEARCH_COLUMNS: &'static [Column] = &[Column::Name];
+    const SORT_COLUMNS: &'static [(&'static str, Column)] = &[
+        ("name", Column::Name),
+        ("starts_on", Column::StartsOn),
+        ("created_at", Column::CreatedAt),
+        ("updated_at", Column::UpdatedAt),
+    ];
+
+    fn id_column() -> Column { Column::Id }
+    fn updated_at_column() -> Column { Column::UpdatedAt }
+    fn deleted_at_column() -> Column { Column::DeletedAt }
+}
+
+#[async_trait]
+pub trait PromotionRepository: Send + Sync {
+    async fn create(&self, promotion: ActiveModel) -> Result<PromotionModel, AppError>;
+    async fn find_by_id(&self, id: i32) -> Result<Option<PromotionModel>, AppError>;
+    async fn find_active(&self) -> Result<Vec<PromotionModel>, AppError>;
+    async fn find_with_pagination(&self, query: GetListPromotionQuery) -> Result<PaginatedResult<PromotionModel>, AppError>;
+    async fn update(&self, id: i32, promotion: ActiveModel) -> Result<PromotionModel, AppError>;
+    async fn delete(&self, id: i32) -> Result<PromotionModel, AppError>;
+}
+
+pub struct PromotionRepositoryImpl {
+    base: SoftDeleteRepository<Promotion>,
+}
+
+impl PromotionRepositoryImpl {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { base: SoftDeleteRepository::new(db) }
+    }
+}
+
+#[async_trait]
+impl PromotionRepository for PromotionRepositoryImpl {
+    async fn create(&self, promotion: ActiveModel) -> Result<PromotionModel, AppError> {
+        self.base.create(promotion).await
+    }
+
+    async fn find_by_id(&self, id: i32) -> Result<Option<PromotionModel>, AppError> {
+        self.base.find_by_id(id).await
+    }
+
+    /// Live promotions switched on, whether or not their dates and hours apply right now
+    async fn find_active(&self) -> Result<Vec<PromotionModel>, AppError> {
+        self.base.find_all(Condition::all().add(Column::Active.eq(true))).await
+    }
+
+    async fn find_with_pagination(
+        &self,
+        query: GetListPromotionQuery
+    ) -> Result<PaginatedResult<PromotionModel>, AppError> {
+        let page_query = PageQuery {
+            limit: query.limit,
+            page: query.page,
+            search: query.search,
+            order_by: query.order_by,
+            direction: query.direction,
+            ..Default::default()
+        };
+
+        self.base.find_with_pagination(page_query, Condition::all()).await
+    }
+
+    async fn update(&self, id: i32, promotion: ActiveModel) -> Result<PromotionModel, AppError> {
+        self.base.update(id, promotion).await
+    }
+
+    async fn delete(&self, id: i32) -> Result<PromotionModel, AppError> {
+        self.base.delete(id).await
+    }
+}
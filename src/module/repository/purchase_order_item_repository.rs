@@ -0,0 +1,95 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::Utc;
+use sea_orm::{
+    prelude::Decimal, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, Order, QueryFilter, QueryOrder, Set,
+    TransactionTrait,
+};
+use crate::{
+    module::purchase::v1::entity::model::purchase_order_item::{
+        ActiveModel, Entity as PurchaseOrderItem, Model as PurchaseOrderItemModel, Column,
+    },
+    pkg::custom_error::AppError,
+};
+use tracing::log::error;
+
+fn db_error(err: DbErr) -> AppError {
+    error!("DB error: {:?}", err);
+    AppError::InternalError
+}
+
+/// Line of a purchase order to store
+pub struct NewPurchaseOrderItem {
+    pub product_id: i32,
+    pub variant_id: Option<i32>,
+    pub qty: Decimal,
+    pub unit: String,
+    pub unit_factor: Decimal,
+    pub unit_cost: i32,
+}
+
+#[async_trait]
+pub trait PurchaseOrderItemRepository: Send + Sync {
+    async fn find_by_orders(&self, purchase_order_ids: &[i32]) -> Result<Vec<PurchaseOrderItemModel>, AppError>;
+    async fn replace(&self, purchase_order_id: i32, items: Vec<NewPurchaseOrderItem>) -> Result<Vec<PurchaseOrderItemModel>, AppError>;
+}
+
+pub struct PurchaseOrderItemRepositoryImpl {
+    db: Arc<DatabaseConnection>,
+}
+
+impl PurchaseOrderItemRepositoryImpl {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl PurchaseOrderItemRepository for PurchaseOrderItemRepositoryImpl {
+    async fn find_by_orders(&self, purchase_order_ids: &[i32]) -> Result<Vec<PurchaseOrderItemModel>, AppError> {
+        if purchase_order_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        PurchaseOrderItem::find()
+            .filter(Column::PurchaseOrderId.is_in(purchase_order_ids.to_vec()))
+            .order_by(Column::Id, Order::Asc)
+            .all(self.db.as_ref())
+            .await
+            .map_err(db_error)
+    }
+
+    /// Set the order's lines to exactly `items`
+    async fn replace(&self, purchase_order_id: i32, items: Vec<NewPurchaseOrderItem>) -> Result<Vec<PurchaseOrderItemModel>, AppError> {
+        let txn = self.db.begin().await.map_err(db_error)?;
+
+        PurchaseOrderItem::delete_many()
+            .filter(Column::PurchaseOrderId.eq(purchase_order_id))
+            .exec(&txn)
+            .await
+            .map_err(db_error)?;
+
+        if !items.is_empty() {
+            let order_items = items.into_iter().map(|item| ActiveModel {
+                purchase_order_id: Set(purchase_order_id),
+                product_id: Set(item.product_id),
+                variant_id: Set(item.variant_id),
+                qty: Set(item.qty),
+                unit: Set(item.unit),
+                unit_factor: Set(item.unit_factor),
+                unit_cost: Set(item.unit_cost),
+                created_at: Set(Some(Utc::now())),
+                ..Default::default()
+            });
+
+            PurchaseOrderItem::insert_many(order_items)
+                .exec(&txn)
+                .await
+                .map_err(db_error)?;
+        }
+
+        txn.commit().await.map_err(db_error)?;
+
+        self.find_by_orders(&[purchase_order_id]).await
+    }
+}
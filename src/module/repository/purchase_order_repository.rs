@@ -0,0 +1,191 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::Utc;
+use sea_orm::{sea_query::Expr, ColumnTrait, Condition, DatabaseConnection, DatabaseTransaction, DbErr, EntityTrait, QueryFilter, TransactionTrait};
+use crate::{
+    module::purchase::v1::entity::{
+        model::purchase_order::{
+            ActiveModel, Entity as PurchaseOrder, Model as PurchaseOrderModel, Column,
+            STATUS_DRAFT, STATUS_ORDERED, STATUS_RECEIVED, PAYMENT_PAYABLE,
+        },
+        request::list_purchase_order_request::GetListPurchaseOrderQuery,
+    },
+    module::repository::soft_delete_repository::{SoftDeleteEntity, SoftDeleteRepository},
+    pkg::{
+        custom_error::AppError,
+        filter,
+        pagination::{PageQuery, PaginatedResult},
+    }
+};
+use tracing::log::error;
+
+fn db_error(err: DbErr) -> AppError {
+    error!("DB error: {:?}", err);
+    AppError::InternalError
+}
+
+
+impl SoftDeleteEntity for PurchaseOrder {
+    type ActiveModel = ActiveModel;
+
+    const NAME: &'static str = "Purchase order";
+    const SEARCH_COLUMNS: &'static [Column] = &[];
+    const SORT_COLUMNS: &'static [(&'static str, Column)] = &[
+        ("supplier_id", Column::SupplierId),
+        ("status", Column::Status),
+        ("total_amount", Column::TotalAmount),
+        ("received_at", Column::ReceivedAt),
+        ("created_at", Column::CreatedAt),
+        ("updated_at", Column::UpdatedAt),
+    ];
+
+    fn id_column() -> Column { Column::Id }
+    fn updated_at_column() -> Column { Column::UpdatedAt }
+    fn deleted_at_column() -> Column { Column::DeletedAt }
+}
+
+#[async_trait]
+pub trait PurchaseOrderRepository: Send + Sync {
+    async fn begin(&self) -> Result<DatabaseTransaction, AppError>;
+    async fn create(&self, purchase_order: ActiveModel) -> Result<PurchaseOrderModel, AppError>;
+    async fn find_by_id(&self, id: i32) -> Result<Option<PurchaseOrderModel>, AppError>;
+    async fn find_with_pagination(&self, query: GetListPurchaseOrderQuery) -> Result<PaginatedResult<PurchaseOrderModel>, AppError>;
+    async fn count_open_by_supplier(&self, supplier_id: i32) -> Result<u64, AppError>;
+    async fn update(&self, id: i32, purchase_order: ActiveModel) -> Result<PurchaseOrderModel, AppError>;
+    async fn claim_status(&self, txn: &DatabaseTransaction, id: i32, from: &[&str], purchase_order: ActiveModel) -> Result<Option<PurchaseOrderModel>, AppError>;
+    async fn claim_payment(&self, txn: &DatabaseTransaction, id: i32, cashflow_id: i32) -> Result<Option<PurchaseOrderModel>, AppError>;
+    async fn delete(&self, id: i32) -> Result<PurchaseOrderModel, AppError>;
+}
+
+pub struct PurchaseOrderRepositoryImpl {
+    base: SoftDeleteRepository<PurchaseOrder>,
+}
+
+impl PurchaseOrderRepositoryImpl {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { base: SoftDeleteRepository::new(db) }
+    }
+}
+
+#[async_trait]
+impl PurchaseOrderRepository for PurchaseOrderRepositoryImpl {
+    async fn begin(&self) -> Result<DatabaseTransaction, AppError> {
+        self.base.db().begin().await.map_err(db_error)
+    }
+
+    async fn create(&self, purchase_order: ActiveModel) -> Result<PurchaseOrderModel, AppError> {
+        self.base.create(purchase_order).await
+    }
+
+    async fn find_by_id(&self, id: i32) -> Result<Option<PurchaseOrderModel>, AppError> {
+        self.base.find_by_id(id).await
+    }
+
+    async fn find_with_pagination(
+        &self,
+        query: GetListPurchaseOrderQuery
+    ) -> Result<PaginatedResult<PurchaseOrderModel>, AppError> {
+        // Build filter condition
+        let mut search_condition = Condition::all();
+
+        let statuses = filter::parse_list::<String>("status", query.status.as_deref())?;
+        if !statuses.is_empty() {
+            search_condition = search_condition.add(Column::Status.is_in(statuses));
+        }
+
+        let supplier_ids = filter::parse_list::<i32>("supplier_id", query.supplier_id.as_deref())?;
+        if !supplier_ids.is_empty() {
+            search_condition = search_condition.add(Column::SupplierId.is_in(supplier_ids));
+        }
+
+        if matches!(query.unpaid.as_deref(), Some("true") | Some("1")) {
+            search_condition = search_condition
+                .add(Column::Status.eq(STATUS_RECEIVED))
+                .add(Column::PaidAt.is_null());
+        }
+
+        let timezone = filter::parse_timezone(query.timezone.as_deref())?;
+
+        if let Some(created_from) = &query.created_from {
+            let created_from = filter::parse_date_from("created_from", created_from, timezone)?;
+            search_condition = search_condition.add(Column::CreatedAt.gte(created_from));
+        }
+
+        if let Some(created_to) = &query.created_to {
+            let created_to = filter::parse_date_to("created_to", created_to, timezone)?;
+            search_condition = search_condition.add(Column::CreatedAt.lt(created_to));
+        }
+
+        let page_query = PageQuery {
+            limit: query.limit,
+            page: query.page,
+            order_by: query.order_by,
+            direction: query.direction,
+            cursor: query.cursor,
+            include_total: query.include_total,
+            ..Default::default()
+        };
+
+        self.base.find_with_pagination(page_query, search_condition).await
+    }
+
+    /// Orders not yet received, plus received ones still owed to the supplier
+    async fn count_open_by_supplier(&self, supplier_id: i32) -> Result<u64, AppError> {
+        self.base
+            .count(
+                Condition::all()
+                    .add(Column::SupplierId.eq(supplier_id))
+                    .add(
+                        Condition::any()
+                            .add(Column::Status.is_in([STATUS_DRAFT, STATUS_ORDERED]))
+                            .add(
+                                Condition::all()
+                                    .add(Column::PaymentMethod.eq(PAYMENT_PAYABLE))
+                                    .add(Column::PaidAt.is_null()),
+                            ),
+                    ),
+            )
+            .await
+    }
+
+    async fn update(&self, id: i32, purchase_order: ActiveModel) -> Result<PurchaseOrderModel, AppError> {
+        self.base.update(id, purchase_order).await
+    }
+
+    /// Apply `purchase_order` to a live order still in one of `from`; None when another request got there first
+    async fn claim_status(&self, txn: &DatabaseTransaction, id: i32, from: &[&str], purchase_order: ActiveModel) -> Result<Option<PurchaseOrderModel>, AppError> {
+        let claimed = PurchaseOrder::update_many()
+            .set(purchase_order)
+            .col_expr(Column::UpdatedAt, Expr::value(Utc::now()))
+            .filter(Column::Id.eq(id))
+            .filter(Column::Status.is_in(from.iter().copied()))
+            .filter(Column::DeletedAt.is_null())
+            .exec_with_returning(txn)
+            .await
+            .map_err(db_error)?;
+
+        Ok(claimed.into_iter().next())
+    }
+
+    /// Mark a received, unpaid order as paid by `cashflow_id`; None when it already was
+    async fn claim_payment(&self, txn: &DatabaseTransaction, id: i32, cashflow_id: i32) -> Result<Option<PurchaseOrderModel>, AppError> {
+        let now = Utc::now();
+        let paid = PurchaseOrder::update_many()
+            .col_expr(Column::CashflowId, Expr::value(cashflow_id))
+            .col_expr(Column::PaidAt, Expr::value(now))
+            .col_expr(Column::UpdatedAt, Expr::value(now))
+            .filter(Column::Id.eq(id))
+            .filter(Column::Status.eq(STATUS_RECEIVED))
+            .filter(Column::PaidAt.is_null())
+            .filter(Column::DeletedAt.is_null())
+            .exec_with_returning(txn)
+            .await
+            .map_err(db_error)?;
+
+        Ok(paid.into_iter().next())
+    }
+
+    async fn delete(&self, id: i32) -> Result<PurchaseOrderModel, AppError> {
+        self.base.delete(id).await
+    }
+}
@@ -0,0 +1,95 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::Utc;
+use sea_orm::{
+    prelude::Decimal, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, Order, QueryFilter, QueryOrder, Set, TransactionTrait,
+};
+use crate::{
+    module::product::v1::entity::model::recipe_item::{ActiveModel, Entity as RecipeItem, Model as RecipeItemModel, Column},
+    pkg::custom_error::AppError,
+};
+use tracing::log::error;
+
+fn db_error(err: DbErr) -> AppError {
+    error!("DB error: {:?}", err);
+    AppError::InternalError
+}
+
+/// Ingredient line of a recipe to store
+pub struct NewRecipeItem {
+    pub ingredient_id: i32,
+    pub qty: Decimal,
+    pub unit: String,
+    pub unit_factor: Decimal,
+}
+
+#[async_trait]
+pub trait RecipeItemRepository: Send + Sync {
+    async fn find_by_product(&self, product_id: i32) -> Result<Vec<RecipeItemModel>, AppError>;
+    async fn find_by_ingredient(&self, ingredient_id: i32) -> Result<Vec<RecipeItemModel>, AppError>;
+    async fn replace(&self, product_id: i32, items: Vec<NewRecipeItem>) -> Result<Vec<RecipeItemModel>, AppError>;
+}
+
+pub struct RecipeItemRepositoryImpl {
+    db: Arc<DatabaseConnection>,
+}
+
+impl RecipeItemRepositoryImpl {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl RecipeItemRepository for RecipeItemRepositoryImpl {
+    async fn find_by_product(&self, product_id: i32) -> Result<Vec<RecipeItemModel>, AppError> {
+        RecipeItem::find()
+            .filter(Column::ProductId.eq(product_id))
+            .order_by(Column::Id, Order::Asc)
+            .all(self.db.as_ref())
+            .await
+            .map_err(db_error)
+    }
+
+    /// Recipe lines of every product that uses the ingredient
+    async fn find_by_ingredient(&self, ingredient_id: i32) -> Result<Vec<RecipeItemModel>, AppError> {
+        RecipeItem::find()
+            .filter(Column::IngredientId.eq(ingredient_id))
+            .order_by(Column::Id, Order::Asc)
+            .all(self.db.as_ref())
+            .await
+            .map_err(db_error)
+    }
+
+    /// Set the product's recipe to exactly `items`
+    async fn replace(&self, product_id: i32, items: Vec<NewRecipeItem>) -> Result<Vec<RecipeItemModel>, AppError> {
+        let txn = self.db.begin().await.map_err(db_error)?;
+
+        RecipeItem::delete_many()
+            .filter(Column::ProductId.eq(product_id))
+            .exec(&txn)
+            .await
+            .map_err(db_error)?;
+
+        if !items.is_empty() {
+            let recipe_items = items.into_iter().map(|item| ActiveModel {
+                product_id: Set(product_id),
+                ingredient_id: Set(item.ingredient_id),
+                qty: Set(item.qty),
+                unit: Set(Some(item.unit)),
+                unit_factor: Set(item.unit_factor),
+                created_at: Set(Some(Utc::now())),
+                ..Default::default()
+            });
+
+            RecipeItem::insert_many(recipe_items)
+                .exec(&txn)
+                .await
+                .map_err(db_error)?;
+        }
+
+        txn.commit().await.map_err(db_error)?;
+
+        self.find_by_product(product_id).await
+    }
+}
@@ -0,0 +1,198 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sea_orm::{
+    prelude::Decimal, sea_query::Expr, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, FromQueryResult, JoinType, QueryFilter,
+    QuerySelect, RelationTrait,
+};
+use crate::{
+    module::product::v1::entity::model::product::Column as ProductColumn,
+    module::transaction::v1::entity::model::transaction::{
+        Entity as Transaction, Column as TransactionColumn, Relation as TransactionRelation, STATUS_COMPLETED,
+    },
+    module::transaction::v1::entity::model::transaction_allocation::{
+        Entity as TransactionAllocation, Relation as TransactionAllocationRelation,
+    },
+    module::transaction::v1::entity::model::transaction_return::{
+        Entity as TransactionReturn, Column as TransactionReturnColumn, Relation as TransactionReturnRelation,
+    },
+    pkg::custom_error::AppError,
+};
+use tracing::log::error;
+
+/// Sales of live, unvoided transactions summed per product category, modifiers included.
+/// Bundle lines count toward their components' categories, quantities in base units.
+/// Returns are taken off in the period they are made in, with `refund_amount` showing how much.
+/// Revenue is net of discounts, which `discount_amount` reports on their own.
+#[derive(Debug, FromQueryResult)]
+pub struct CategorySalesRow {
+    pub category_id: Option<i32>,
+    pub transaction_count: i64,
+    pub qty: Decimal,
+    pub revenue: i64,
+    pub hpp_amount: i64,
+    pub refund_amount: i64,
+    pub discount_amount: i64,
+}
+
+fn db_error(err: DbErr) -> AppError {
+    error!("DB error: {:?}", err);
+    AppError::InternalError
+}
+
+/// Add `more` into `rows`, one row per category
+fn merge_rows(rows: &mut Vec<CategorySalesRow>, more: Vec<CategorySalesRow>) {
+    for more_row in more {
+        match rows.iter_mut().find(|row| row.category_id == more_row.category_id) {
+            Some(row) => {
+                row.transaction_count += more_row.transaction_count;
+                row.qty += more_row.qty;
+                row.revenue += more_row.revenue;
+                row.hpp_amount += more_row.hpp_amount;
+                row.refund_amount += more_row.refund_amount;
+                row.discount_amount += more_row.discount_amount;
+            }
+            None => rows.push(more_row),
+        }
+    }
+}
+
+#[async_trait]
+pub trait ReportRepository: Send + Sync {
+    async fn sales_by_category(
+        &self,
+        created_from: Option<DateTime<Utc>>,
+        created_to: Option<DateTime<Utc>>,
+    ) -> Result<Vec<CategorySalesRow>, AppError>;
+}
+
+pub struct ReportRepositoryImpl {
+    db: Arc<DatabaseConnection>,
+}
+
+impl ReportRepositoryImpl {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl ReportRepository for ReportRepositoryImpl {
+    async fn sales_by_category(
+        &self,
+        created_from: Option<DateTime<Utc>>,
+        created_to: Option<DateTime<Utc>>,
+    ) -> Result<Vec<CategorySalesRow>, AppError> {
+        let mut query = Transaction::find()
+            .select_only()
+            .column_as(ProductColumn::CategoryId, "category_id")
+            .column_as(Expr::cust("COUNT(transactions.id)"), "transaction_count")
+            .column_as(Expr::cust("COALESCE(SUM(ROUND(transactions.qty * transactions.unit_factor, 3)), 0)"), "qty")
+            .column_as(
+                Expr::cust("CAST(COALESCE(SUM(ROUND(CAST(transactions.selling_amount + transactions.modifier_selling_amount AS BIGINT) * transactions.qty) - transactions.discount_amount), 0) AS BIGINT)"),
+                "revenue",
+            )
+            .column_as(
+                Expr::cust("CAST(COALESCE(SUM(ROUND(CAST(transactions.hpp_amount + transactions.modifier_hpp_amount AS BIGINT) * transactions.qty)), 0) AS BIGINT)"),
+                "hpp_amount",
+            )
+            .column_as(Expr::cust("CAST(0 AS BIGINT)"), "refund_amount")
+            .column_as(Expr::cust("CAST(COALESCE(SUM(transactions.discount_amount), 0) AS BIGINT)"), "discount_amount")
+            .join(JoinType::InnerJoin, TransactionRelation::Product.def())
+            .filter(TransactionColumn::DeletedAt.is_null())
+            .filter(TransactionColumn::Status.eq(STATUS_COMPLETED))
+            .filter(Expr::cust(
+                "NOT EXISTS (SELECT 1 FROM transaction_allocations WHERE transaction_allocations.transaction_id = transactions.id)",
+            ));
+
+        let mut allocation_query = TransactionAllocation::find()
+            .select_only()
+            .column_as(ProductColumn::CategoryId, "category_id")
+            .column_as(Expr::cust("COUNT(DISTINCT transaction_allocations.transaction_id)"), "transaction_count")
+            .column_as(Expr::cust("COALESCE(SUM(transaction_allocations.qty), 0)"), "qty")
+            .column_as(Expr::cust("CAST(COALESCE(SUM(transaction_allocations.revenue_amount), 0) AS BIGINT)"), "revenue")
+            .column_as(Expr::cust("CAST(COALESCE(SUM(transaction_allocations.hpp_amount), 0) AS BIGINT)"), "hpp_amount")
+            .column_as(Expr::cust("CAST(0 AS BIGINT)"), "refund_amount")
+            .column_as(Expr::cust("CAST(COALESCE(SUM(transaction_allocations.discount_amount), 0) AS BIGINT)"), "discount_amount")
+            .join(JoinType::InnerJoin, TransactionAllocationRelation::Transaction.def())
+            .join(JoinType::InnerJoin, TransactionAllocationRelation::Product.def())
+            .filter(TransactionColumn::DeletedAt.is_null())
+            .filter(TransactionColumn::Status.eq(STATUS_COMPLETED));
+
+        let mut return_query = TransactionReturn::find()
+            .select_only()
+            .column_as(ProductColumn::CategoryId, "category_id")
+            .column_as(Expr::cust("CAST(0 AS BIGINT)"), "transaction_count")
+            .column_as(Expr::cust("COALESCE(-SUM(ROUND(transaction_returns.qty * transactions.unit_factor, 3)), 0)"), "qty")
+            .column_as(Expr::cust("CAST(COALESCE(-SUM(transaction_returns.refund_amount), 0) AS BIGINT)"), "revenue")
+            .column_as(Expr::cust("CAST(COALESCE(-SUM(transaction_returns.hpp_amount), 0) AS BIGINT)"), "hpp_amount")
+            .column_as(Expr::cust("CAST(COALESCE(SUM(transaction_returns.refund_amount), 0) AS BIGINT)"), "refund_amount")
+            .column_as(Expr::cust("CAST(COALESCE(-SUM(transaction_returns.discount_amount), 0) AS BIGINT)"), "discount_amount")
+            .join(JoinType::InnerJoin, TransactionReturnRelation::Transaction.def())
+            .join(JoinType::InnerJoin, TransactionRelation::Product.def())
+            .filter(TransactionColumn::DeletedAt.is_null())
+            .filter(TransactionColumn::Status.eq(STATUS_COMPLETED))
+            .filter(Expr::cust(
+                "NOT EXISTS (SELECT 1 FROM transaction_allocations WHERE transaction_allocations.transaction_id = transactions.id)",
+            ));
+
+        // Returned bundles take back each component's share in proportion to the quantity returned
+        let mut allocation_return_query = TransactionReturn::find()
+            .select_only()
+            .column_as(ProductColumn::CategoryId, "category_id")
+            .column_as(Expr::cust("CAST(0 AS BIGINT)"), "transaction_count")
+            .column_as(
+                Expr::cust("COALESCE(-SUM(ROUND(transaction_allocations.qty * transaction_returns.qty / transactions.qty, 3)), 0)"),
+                "qty",
+            )
+            .column_as(
+                Expr::cust("CAST(COALESCE(-SUM(ROUND(transaction_allocations.revenue_amount * transaction_returns.qty / transactions.qty)), 0) AS BIGINT)"),
+                "revenue",
+            )
+            .column_as(
+                Expr::cust("CAST(COALESCE(-SUM(ROUND(transaction_allocations.hpp_amount * transaction_returns.qty / transactions.qty)), 0) AS BIGINT)"),
+                "hpp_amount",
+            )
+            .column_as(
+                Expr::cust("CAST(COALESCE(SUM(ROUND(transaction_allocations.revenue_amount * transaction_returns.qty / transactions.qty)), 0) AS BIGINT)"),
+                "refund_amount",
+            )
+            .column_as(
+                Expr::cust("CAST(COALESCE(-SUM(ROUND(transaction_allocations.discount_amount * transaction_returns.qty / transactions.qty)), 0) AS BIGINT)"),
+                "discount_amount",
+            )
+            .join(JoinType::InnerJoin, TransactionReturnRelation::Transaction.def())
+            .join(JoinType::InnerJoin, TransactionAllocationRelation::Transaction.def().rev())
+            .join(JoinType::InnerJoin, TransactionAllocationRelation::Product.def())
+            .filter(TransactionColumn::DeletedAt.is_null())
+            .filter(TransactionColumn::Status.eq(STATUS_COMPLETED));
+
+        if let Some(created_from) = created_from {
+            query = query.filter(TransactionColumn::CreatedAt.gte(created_from));
+            allocation_query = allocation_query.filter(TransactionColumn::CreatedAt.gte(created_from));
+            return_query = return_query.filter(TransactionReturnColumn::CreatedAt.gte(created_from));
+            allocation_return_query = allocation_return_query.filter(TransactionReturnColumn::CreatedAt.gte(created_from));
+        }
+        if let Some(created_to) = created_to {
+            query = query.filter(TransactionColumn::CreatedAt.lt(created_to));
+            allocation_query = allocation_query.filter(TransactionColumn::CreatedAt.lt(created_to));
+            return_query = return_query.filter(TransactionReturnColumn::CreatedAt.lt(created_to));
+            allocation_return_query = allocation_return_query.filter(TransactionReturnColumn::CreatedAt.lt(created_to));
+        }
+
+        let selectors = [
+            query.group_by(ProductColumn::CategoryId).into_model::<CategorySalesRow>(),
+            allocation_query.group_by(ProductColumn::CategoryId).into_model::<CategorySalesRow>(),
+            return_query.group_by(ProductColumn::CategoryId).into_model::<CategorySalesRow>(),
+            allocation_return_query.group_by(ProductColumn::CategoryId).into_model::<CategorySalesRow>(),
+        ];
+
+        let mut rows = Vec::new();
+        for selector in selectors {
+            let more_rows = selector.all(self.db.as_ref()).await.map_err(db_error)?;
+            merge_rows(&mut rows, more_rows);
+        }
+
+        Ok(rows)
+    }
+}
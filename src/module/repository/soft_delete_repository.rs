@@ -0,0 +1,395 @@
+use std::marker::PhantomData;
+use std::sync::Arc;
+use sea_orm::{
+    sea_query::{Expr, ValueType},
+    ActiveModelBehavior, ActiveModelTrait, ColumnTrait, Condition, DatabaseConnection, DbErr, EntityTrait,
+    IntoActiveModel, ModelTrait, Order, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, SqlErr,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use crate::pkg::{
+    custom_error::AppError,
+    pagination::{Cursor, PageQuery, PaginatedResult},
+};
+use tracing::log::error;
+
+/// Entity with an integer `id` and `updated_at` / `deleted_at` timestamps.
+/// Implementors plug in which columns `search` matches and which `order_by` keys are allowed.
+pub trait SoftDeleteEntity: EntityTrait {
+    type ActiveModel: ActiveModelTrait<Entity = Self> + ActiveModelBehavior + Send;
+
+    /// Used in "<NAME> with id .. not found" messages
+    const NAME: &'static str;
+
+    /// Columns matched (OR-ed) by the `search` query parameter
+    const SEARCH_COLUMNS: &'static [Self::Column];
+
+    /// `order_by` keys and the columns they sort on; anything else sorts by id ascending
+    const SORT_COLUMNS: &'static [(&'static str, Self::Column)];
+
+    fn id_column() -> Self::Column;
+    fn updated_at_column() -> Self::Column;
+    fn deleted_at_column() -> Self::Column;
+}
+
+/// Outcome of purging rows soft-deleted before a cutoff
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize)]
+pub struct PurgeSummary {
+    /// Rows deleted before the cutoff
+    pub expired: u64,
+    /// Rows hard-deleted, or that would be in a dry run
+    pub purged: u64,
+    /// Expired rows kept because they are still referenced
+    pub skipped: u64,
+}
+
+pub struct SoftDeleteRepository<E> {
+    db: Arc<DatabaseConnection>,
+    entity: PhantomData<E>,
+}
+
+fn db_error(err: DbErr) -> AppError {
+    error!("DB error: {:?}", err);
+    AppError::InternalError
+}
+
+impl<E> SoftDeleteRepository<E>
+where
+    E: SoftDeleteEntity,
+    E::Model: IntoActiveModel<E::ActiveModel> + Sync,
+{
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db, entity: PhantomData }
+    }
+
+    pub fn db(&self) -> &DatabaseConnection {
+        self.db.as_ref()
+    }
+
+    fn not_found(id: i32) -> AppError {
+        AppError::NotFound(format!("{} with id {} not found", E::NAME, id))
+    }
+
+    pub async fn create(&self, model: E::ActiveModel) -> Result<E::Model, AppError> {
+        model.insert(self.db()).await.map_err(db_error)
+    }
+
+    pub async fn find_by_id(&self, id: i32) -> Result<Option<E::Model>, AppError> {
+        E::find()
+            .filter(E::id_column().eq(id))
+            .filter(E::deleted_at_column().is_null())
+            .one(self.db())
+            .await
+            .map_err(db_error)
+    }
+
+    /// First live row matching `condition`
+    pub async fn find_one(&self, condition: Condition) -> Result<Option<E::Model>, AppError> {
+        E::find()
+            .filter(condition)
+            .filter(E::deleted_at_column().is_null())
+            .one(self.db())
+            .await
+            .map_err(db_error)
+    }
+
+    /// All live rows matching `condition`, by id
+    pub async fn find_all(&self, condition: Condition) -> Result<Vec<E::Model>, AppError> {
+        E::find()
+            .filter(condition)
+            .filter(E::deleted_at_column().is_null())
+            .order_by(E::id_column(), Order::Asc)
+            .all(self.db())
+            .await
+            .map_err(db_error)
+    }
+
+    /// Number of live rows matching `condition`
+    pub async fn count(&self, condition: Condition) -> Result<u64, AppError> {
+        E::find()
+            .filter(condition)
+            .filter(E::deleted_at_column().is_null())
+            .count(self.db())
+            .await
+            .map_err(db_error)
+    }
+
+    /// Offset or keyset page of live rows matching `condition` plus the `search` columns
+    pub async fn find_with_pagination(
+        &self,
+        page_query: PageQuery,
+        condition: Condition,
+    ) -> Result<PaginatedResult<E::Model>, AppError> {
+        self.paginate(page_query, condition, false).await
+    }
+
+    /// Same as `find_with_pagination` over soft-deleted rows; also sortable by `deleted_at`
+    pub async fn find_deleted_with_pagination(
+        &self,
+        page_query: PageQuery,
+        condition: Condition,
+    ) -> Result<PaginatedResult<E::Model>, AppError> {
+        self.paginate(page_query, condition, true).await
+    }
+
+    async fn paginate(
+        &self,
+        page_query: PageQuery,
+        condition: Condition,
+        deleted: bool,
+    ) -> Result<PaginatedResult<E::Model>, AppError> {
+        // Parse pagination parameters
+        let (page, limit) = page_query.page_and_limit();
+        let offset = (page - 1) * limit;
+        let include_total = page_query.include_total();
+
+        // Build search condition
+        let deleted_condition = if deleted {
+            E::deleted_at_column().is_not_null()
+        } else {
+            E::deleted_at_column().is_null()
+        };
+        let mut search_condition = Condition::all()
+            .add(deleted_condition)
+            .add(condition);
+
+        if let Some(search) = &page_query.search {
+            if !search.trim().is_empty() && !E::SEARCH_COLUMNS.is_empty() {
+                let search_any = E::SEARCH_COLUMNS
+                    .iter()
+                    .fold(Condition::any(), |any, column| any.add(column.contains(search.trim())));
+                search_condition = search_condition.add(search_any);
+            }
+        }
+
+        let search_condition_for_count = search_condition.clone();
+
+        // Resolve sort key
+        let direction = match page_query.direction.as_deref() {
+            Some("desc") | Some("DESC") => Order::Desc,
+            _ => Order::Asc,
+        };
+
+        let order_by = page_query.order_by.as_deref().map(str::to_lowercase);
+        let sort = match order_by.as_deref() {
+            Some("deleted_at") if deleted => Some(("deleted_at", E::deleted_at_column())),
+            Some(order_by) => E::SORT_COLUMNS.iter().find(|(key, _)| *key == order_by).copied(),
+            None => None,
+        };
+        let (sort_key, sort_column, direction) = match sort {
+            Some((key, column)) => (key, column, direction),
+            None => ("id", E::id_column(), Order::Asc),
+        };
+
+        // Keyset pagination continues after the cursor instead of skipping rows
+        let cursor = match page_query.cursor.as_deref() {
+            Some(raw) if !raw.is_empty() => {
+                let cursor = Cursor::decode(raw)?;
+                if cursor.key != sort_key {
+                    return Err(AppError::BadRequest("Cursor does not match order_by".to_string()));
+                }
+                Some(cursor)
+            }
+            _ => None,
+        };
+        let keyset = page_query.cursor.is_some();
+
+        let mut data_condition = search_condition;
+        if let Some(cursor) = &cursor {
+            data_condition = data_condition.add(cursor.condition(sort_column, E::id_column(), direction.clone())?);
+        }
+
+        // Build ordering
+        let mut query_builder = E::find()
+            .filter(data_condition)
+            .order_by(sort_column, direction.clone());
+        if sort_key != "id" {
+            query_builder = query_builder.order_by(E::id_column(), direction);
+        }
+        if !keyset {
+            query_builder = query_builder.offset(offset);
+        }
+
+        // Get total count
+        let total = if include_total {
+            let total = E::find()
+                .filter(search_condition_for_count)
+                .count(self.db())
+                .await
+                .map_err(db_error)?;
+            Some(total)
+        } else {
+            None
+        };
+
+        // Get paginated data, one extra row tells whether a next page exists
+        let mut data = query_builder
+            .limit(limit + 1)
+            .all(self.db())
+            .await
+            .map_err(db_error)?;
+
+        let has_next = data.len() as u64 > limit;
+        data.truncate(limit as usize);
+
+        let next_cursor = match data.last() {
+            Some(last) if keyset && has_next => {
+                let id: i32 = ValueType::try_from(ModelTrait::get(last, E::id_column())).unwrap_or_default();
+                Some(Cursor::new(sort_key, ModelTrait::get(last, sort_column), id).encode())
+            }
+            _ => None,
+        };
+
+        let total_pages = total.map(|total| (total as f64 / limit as f64).ceil() as u64);
+
+        Ok(PaginatedResult {
+            data,
+            total,
+            page: if keyset { None } else { Some(page) },
+            limit,
+            total_pages,
+            has_next,
+            next_cursor,
+        })
+    }
+
+    pub async fn update(&self, id: i32, mut model: E::ActiveModel) -> Result<E::Model, AppError> {
+        // Set updated_at timestamp
+        model.set(E::updated_at_column(), Some(Utc::now()).into());
+
+        let result = E::update_many()
+            .set(model)
+            .filter(E::id_column().eq(id))
+            .filter(E::deleted_at_column().is_null())
+            .exec(self.db())
+            .await
+            .map_err(db_error)?;
+
+        if result.rows_affected == 0 {
+            return Err(Self::not_found(id));
+        }
+
+        // Fetch the updated row
+        self.find_by_id(id)
+            .await?
+            .ok_or_else(|| Self::not_found(id))
+    }
+
+    pub async fn delete(&self, id: i32) -> Result<E::Model, AppError> {
+        let now = Utc::now();
+        let result = E::update_many()
+            .col_expr(E::deleted_at_column(), Expr::value(now))
+            .col_expr(E::updated_at_column(), Expr::value(now))
+            .filter(E::id_column().eq(id))
+            .filter(E::deleted_at_column().is_null())
+            .exec(self.db())
+            .await
+            .map_err(db_error)?;
+
+        if result.rows_affected == 0 {
+            return Err(Self::not_found(id));
+        }
+
+        // Fetch the deleted row
+        self.find_deleted_by_id(id)
+            .await?
+            .ok_or_else(|| Self::not_found(id))
+    }
+
+    pub async fn find_deleted_by_id(&self, id: i32) -> Result<Option<E::Model>, AppError> {
+        E::find()
+            .filter(E::id_column().eq(id))
+            .filter(E::deleted_at_column().is_not_null())
+            .one(self.db())
+            .await
+            .map_err(db_error)
+    }
+
+    /// Clear `deleted_at` on a soft-deleted row
+    pub async fn restore(&self, id: i32) -> Result<E::Model, AppError> {
+        let result = E::update_many()
+            .col_expr(E::deleted_at_column(), Expr::value(Option::<DateTime<Utc>>::None))
+            .col_expr(E::updated_at_column(), Expr::value(Utc::now()))
+            .filter(E::id_column().eq(id))
+            .filter(E::deleted_at_column().is_not_null())
+            .exec(self.db())
+            .await
+            .map_err(db_error)?;
+
+        if result.rows_affected == 0 {
+            return Err(Self::not_found(id));
+        }
+
+        self.find_by_id(id)
+            .await?
+            .ok_or_else(|| Self::not_found(id))
+    }
+
+    /// Permanently remove a soft-deleted row
+    pub async fn purge(&self, id: i32) -> Result<(), AppError> {
+        let result = E::delete_many()
+            .filter(E::id_column().eq(id))
+            .filter(E::deleted_at_column().is_not_null())
+            .exec(self.db())
+            .await
+            .map_err(|err| match err.sql_err() {
+                Some(SqlErr::ForeignKeyConstraintViolation(_)) => AppError::BadRequest(
+                    format!("{} with id {} is still referenced by other records", E::NAME, id),
+                ),
+                _ => db_error(err),
+            })?;
+
+        if result.rows_affected == 0 {
+            return Err(Self::not_found(id));
+        }
+
+        Ok(())
+    }
+
+    /// Hard-delete rows soft-deleted before `cutoff` that also match `condition`.
+    /// With `dry_run` nothing is deleted and the summary reports what would be.
+    pub async fn purge_deleted_before(
+        &self,
+        cutoff: DateTime<Utc>,
+        condition: Condition,
+        dry_run: bool,
+    ) -> Result<PurgeSummary, AppError> {
+        let expired_condition = Condition::all()
+            .add(E::deleted_at_column().is_not_null())
+            .add(E::deleted_at_column().lt(cutoff));
+
+        let expired = E::find()
+            .filter(expired_condition.clone())
+            .count(self.db())
+            .await
+            .map_err(db_error)?;
+
+        let purge_condition = expired_condition.add(condition);
+
+        let purged = if dry_run {
+            E::find()
+                .filter(purge_condition)
+                .count(self.db())
+                .await
+                .map_err(db_error)?
+        } else {
+            E::delete_many()
+                .filter(purge_condition)
+                .exec(self.db())
+                .await
+                .map_err(|err| match err.sql_err() {
+                    Some(SqlErr::ForeignKeyConstraintViolation(_)) => AppError::BadRequest(
+                        format!("Some expired {} rows are still referenced by other records", E::NAME),
+                    ),
+                    _ => db_error(err),
+                })?
+                .rows_affected
+        };
+
+        Ok(PurgeSummary {
+            expired,
+            purged,
+            skipped: expired.saturating_sub(purged),
+        })
+    }
+}
@@ -0,0 +1,98 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::Utc;
+use sea_orm::{
+    prelude::Decimal, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, Order, QueryFilter, QueryOrder, Set,
+    TransactionTrait,
+};
+use crate::{
+    module::stock_count::v1::entity::model::stock_count_item::{
+        ActiveModel, Entity as StockCountItem, Model as StockCountItemModel, Column,
+    },
+    pkg::custom_error::AppError,
+};
+use tracing::log::error;
+
+fn db_error(err: DbErr) -> AppError {
+    error!("DB error: {:?}", err);
+    AppError::InternalError
+}
+
+/// Counted line to store
+pub struct NewStockCountItem {
+    pub product_id: i32,
+    pub variant_id: Option<i32>,
+    pub expected_qty: Decimal,
+    pub counted_qty: Decimal,
+    pub hpp_amount: i32,
+    pub counted_by: Option<i32>,
+}
+
+#[async_trait]
+pub trait StockCountItemRepository: Send + Sync {
+    async fn find_by_counts(&self, stock_count_ids: &[i32]) -> Result<Vec<StockCountItemModel>, AppError>;
+    async fn save(&self, stock_count_id: i32, items: Vec<NewStockCountItem>) -> Result<(), AppError>;
+}
+
+pub struct StockCountItemRepositoryImpl {
+    db: Arc<DatabaseConnection>,
+}
+
+impl StockCountItemRepositoryImpl {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl StockCountItemRepository for StockCountItemRepositoryImpl {
+    async fn find_by_counts(&self, stock_count_ids: &[i32]) -> Result<Vec<StockCountItemModel>, AppError> {
+        if stock_count_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        StockCountItem::find()
+            .filter(Column::StockCountId.is_in(stock_count_ids.to_vec()))
+            .order_by(Column::Id, Order::Asc)
+            .all(self.db.as_ref())
+            .await
+            .map_err(db_error)
+    }
+
+    /// Store the lines, replacing earlier counts of the same product or variant
+    async fn save(&self, stock_count_id: i32, items: Vec<NewStockCountItem>) -> Result<(), AppError> {
+        let txn = self.db.begin().await.map_err(db_error)?;
+
+        for item in items {
+            let variant_filter = match item.variant_id {
+                Some(variant_id) => Column::VariantId.eq(variant_id),
+                None => Column::VariantId.is_null(),
+            };
+
+            StockCountItem::delete_many()
+                .filter(Column::StockCountId.eq(stock_count_id))
+                .filter(Column::ProductId.eq(item.product_id))
+                .filter(variant_filter)
+                .exec(&txn)
+                .await
+                .map_err(db_error)?;
+
+            StockCountItem::insert(ActiveModel {
+                stock_count_id: Set(stock_count_id),
+                product_id: Set(item.product_id),
+                variant_id: Set(item.variant_id),
+                expected_qty: Set(item.expected_qty),
+                counted_qty: Set(item.counted_qty),
+                hpp_amount: Set(item.hpp_amount),
+                counted_by: Set(item.counted_by),
+                counted_at: Set(Some(Utc::now())),
+                ..Default::default()
+            })
+            .exec(&txn)
+            .await
+            .map_err(db_error)?;
+        }
+
+        txn.commit().await.map_err(db_error)
+    }
+}
@@ -0,0 +1,129 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::Utc;
+use sea_orm::{sea_query::Expr, ColumnTrait, Condition, DatabaseConnection, DatabaseTransaction, DbErr, EntityTrait, QueryFilter, TransactionTrait};
+use crate::{
+    module::stock_count::v1::entity::{
+        model::stock_count::{ActiveModel, Entity as StockCount, Model as StockCountModel, Column},
+        request::list_stock_count_request::GetListStockCountQuery,
+    },
+    module::repository::soft_delete_repository::{SoftDeleteEntity, SoftDeleteRepository},
+    pkg::{
+        custom_error::AppError,
+        filter,
+        pagination::{PageQuery, PaginatedResult},
+    }
+};
+use tracing::log::error;
+
+fn db_error(err: DbErr) -> AppError {
+    error!("DB error: {:?}", err);
+    AppError::InternalError
+}
+
+
+impl SoftDeleteEntity for StockCount {
+    type ActiveModel = ActiveModel;
+
+    const NAME: &'static str = "Stock count";
+    const SEARCH_COLUMNS: &'static [Column] = &[];
+    const SORT_COLUMNS: &'static [(&'static str, Column)] = &[
+        ("status", Column::Status),
+        ("approved_at", Column::ApprovedAt),
+        ("created_at", Column::CreatedAt),
+        ("updated_at", Column::UpdatedAt),
+    ];
+
+    fn id_column() -> Column { Column::Id }
+    fn updated_at_column() -> Column { Column::UpdatedAt }
+    fn deleted_at_column() -> Column { Column::DeletedAt }
+}
+
+#[async_trait]
+pub trait StockCountRepository: Send + Sync {
+    async fn begin(&self) -> Result<DatabaseTransaction, AppError>;
+    async fn create(&self, stock_count: ActiveModel) -> Result<StockCountModel, AppError>;
+    async fn find_by_id(&self, id: i32) -> Result<Option<StockCountModel>, AppError>;
+    async fn find_with_pagination(&self, query: GetListStockCountQuery) -> Result<PaginatedResult<StockCountModel>, AppError>;
+    async fn claim_status(&self, txn: &DatabaseTransaction, id: i32, from: &str, stock_count: ActiveModel) -> Result<Option<StockCountModel>, AppError>;
+    async fn delete(&self, id: i32) -> Result<StockCountModel, AppError>;
+}
+
+pub struct StockCountRepositoryImpl {
+    base: SoftDeleteRepository<StockCount>,
+}
+
+impl StockCountRepositoryImpl {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { base: SoftDeleteRepository::new(db) }
+    }
+}
+
+#[async_trait]
+impl StockCountRepository for StockCountRepositoryImpl {
+    async fn begin(&self) -> Result<DatabaseTransaction, AppError> {
+        self.base.db().begin().await.map_err(db_error)
+    }
+
+    async fn create(&self, stock_count: ActiveModel) -> Result<StockCountModel, AppError> {
+        self.base.create(stock_count).await
+    }
+
+    async fn find_by_id(&self, id: i32) -> Result<Option<StockCountModel>, AppError> {
+        self.base.find_by_id(id).await
+    }
+
+    async fn find_with_pagination(
+        &self,
+        query: GetListStockCountQuery
+    ) -> Result<PaginatedResult<StockCountModel>, AppError> {
+        // Build filter condition
+        let mut search_condition = Condition::all();
+
+        let statuses = filter::parse_list::<String>("status", query.status.as_deref())?;
+        if !statuses.is_empty() {
+            search_condition = search_condition.add(Column::Status.is_in(statuses));
+        }
+
+        let timezone = filter::parse_timezone(query.timezone.as_deref())?;
+
+        if let Some(created_from) = &query.created_from {
+            let created_from = filter::parse_date_from("created_from", created_from, timezone)?;
+            search_condition = search_condition.add(Column::CreatedAt.gte(created_from));
+        }
+
+        if let Some(created_to) = &query.created_to {
+            let created_to = filter::parse_date_to("created_to", created_to, timezone)?;
+            search_condition = search_condition.add(Column::CreatedAt.lt(created_to));
+        }
+
+        let page_query = PageQuery {
+            limit: query.limit,
+            page: query.page,
+            order_by: query.order_by,
+            direction: query.direction,
+            ..Default::default()
+        };
+
+        self.base.find_with_pagination(page_query, search_condition).await
+    }
+
+    /// Apply `stock_count` to a live session still in `from`; None when another request got there first
+    async fn claim_status(&self, txn: &DatabaseTransaction, id: i32, from: &str, stock_count: ActiveModel) -> Result<Option<StockCountModel>, AppError> {
+        let claimed = StockCount::update_many()
+            .set(stock_count)
+            .col_expr(Column::UpdatedAt, Expr::value(Utc::now()))
+            .filter(Column::Id.eq(id))
+            .filter(Column::Status.eq(from))
+            .filter(Column::DeletedAt.is_null())
+            .exec_with_returning(txn)
+            .await
+            .map_err(db_error)?;
+
+        Ok(claimed.into_iter().next())
+    }
+
+    async fn delete(&self, id: i32) -> Result<StockCountModel, AppError> {
+        self.base.delete(id).await
+    }
+}
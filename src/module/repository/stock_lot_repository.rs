@@ -0,0 +1,59 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use sea_orm::{prelude::Decimal, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, Order, QueryFilter, QueryOrder};
+use crate::{
+    module::inventory::v1::entity::model::stock_lot::{Entity as StockLot, Model as StockLotModel, Column},
+    pkg::custom_error::AppError,
+};
+use tracing::log::error;
+
+fn db_error(err: DbErr) -> AppError {
+    error!("DB error: {:?}", err);
+    AppError::InternalError
+}
+
+// Lots are opened and drawn down by `StockRepository::record` alongside the movements
+#[async_trait]
+pub trait StockLotRepository: Send + Sync {
+    async fn find_by_id(&self, id: i32) -> Result<Option<StockLotModel>, AppError>;
+    async fn find_expiring(&self, until: NaiveDate, product_ids: &[i32]) -> Result<Vec<StockLotModel>, AppError>;
+}
+
+pub struct StockLotRepositoryImpl {
+    db: Arc<DatabaseConnection>,
+}
+
+impl StockLotRepositoryImpl {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl StockLotRepository for StockLotRepositoryImpl {
+    async fn find_by_id(&self, id: i32) -> Result<Option<StockLotModel>, AppError> {
+        StockLot::find_by_id(id)
+            .one(self.db.as_ref())
+            .await
+            .map_err(db_error)
+    }
+
+    /// Lots with stock left expiring on or before `until`, soonest first; already expired lots included
+    async fn find_expiring(&self, until: NaiveDate, product_ids: &[i32]) -> Result<Vec<StockLotModel>, AppError> {
+        let mut select = StockLot::find()
+            .filter(Column::RemainingQty.gt(Decimal::ZERO))
+            .filter(Column::ExpiresAt.lte(until));
+
+        if !product_ids.is_empty() {
+            select = select.filter(Column::ProductId.is_in(product_ids.to_vec()));
+        }
+
+        select
+            .order_by(Column::ExpiresAt, Order::Asc)
+            .order_by(Column::Id, Order::Asc)
+            .all(self.db.as_ref())
+            .await
+            .map_err(db_error)
+    }
+}
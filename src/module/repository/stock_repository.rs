@@ -0,0 +1,484 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use sea_orm::{
+    prelude::Decimal, sea_query::Expr, ActiveModelTrait, ColumnTrait, Condition, DatabaseConnection, DatabaseTransaction, DbErr, EntityTrait,
+    FromQueryResult, Order, QueryFilter, QueryOrder, QuerySelect, QueryTrait, Set, TransactionTrait,
+};
+use crate::{
+    module::inventory::v1::entity::{
+        model::stock_movement::{
+            ActiveModel, Entity as StockMovement, Model as StockMovementModel, Column,
+            MOVEMENT_SALE, MOVEMENT_SALE_REVERSAL, MOVEMENT_RETURN,
+        },
+        model::stock_lot::{ActiveModel as StockLotActiveModel, Entity as StockLot, Column as StockLotColumn},
+        model::stock_consumption::{
+            ActiveModel as StockConsumptionActiveModel, Entity as StockConsumption, Column as StockConsumptionColumn,
+        },
+        request::list_stock_movement_request::GetListStockMovementQuery,
+    },
+    module::product::v1::entity::model::{
+        product::{Entity as Product, Column as ProductColumn},
+        product_variant::{Entity as ProductVariant, Column as ProductVariantColumn},
+    },
+    pkg::{
+        costing,
+        custom_error::AppError,
+        filter,
+        pagination::{self, PageQuery, PaginatedResult},
+    }
+};
+use rust_decimal::{prelude::ToPrimitive, RoundingStrategy};
+use tracing::log::error;
+
+fn db_error(err: DbErr) -> AppError {
+    error!("DB error: {:?}", err);
+    AppError::InternalError
+}
+
+/// Net base quantity of a product or variant sold over a period
+#[derive(Debug, FromQueryResult)]
+pub struct SoldQtyRow {
+    pub product_id: i32,
+    pub variant_id: Option<i32>,
+    pub qty: Decimal,
+}
+
+/// Open cost layers of exactly the product, or of one of its variants
+fn layer_condition(product_id: i32, variant_id: Option<i32>) -> Condition {
+    let condition = Condition::all()
+        .add(Column::ProductId.eq(product_id))
+        .add(Column::RemainingQty.gt(Decimal::ZERO));
+
+    match variant_id {
+        Some(variant_id) => condition.add(Column::VariantId.eq(variant_id)),
+        None => condition.add(Column::VariantId.is_null()),
+    }
+}
+
+/// Lots with stock left of exactly the product, or of one of its variants
+fn lot_condition(product_id: i32, variant_id: Option<i32>) -> Condition {
+    let condition = Condition::all()
+        .add(StockLotColumn::ProductId.eq(product_id))
+        .add(StockLotColumn::RemainingQty.gt(Decimal::ZERO));
+
+    match variant_id {
+        Some(variant_id) => condition.add(StockLotColumn::VariantId.eq(variant_id)),
+        None => condition.add(StockLotColumn::VariantId.is_null()),
+    }
+}
+
+/// Expiry of the stock a receipt brings in
+pub struct NewStockLot {
+    pub lot_code: Option<String>,
+    pub expires_at: NaiveDate,
+}
+
+/// Stock change to record; `qty` is signed
+pub struct NewStockMovement {
+    pub product_id: i32,
+    pub variant_id: Option<i32>,
+    pub qty: Decimal,
+    pub movement_type: &'static str,
+    pub reference_type: Option<&'static str>,
+    pub reference_id: Option<i32>,
+    pub note: Option<String>,
+    pub created_by: Option<i32>,
+    pub cost_amount: Option<i64>,
+    // Incoming stock only: opens a lot with this expiry
+    pub lot: Option<NewStockLot>,
+    // Outgoing stock only: taken entirely from this lot instead of the earliest expiring ones
+    pub lot_id: Option<i32>,
+    // Incoming stock only: put back into the layers and lots taken by the outgoing movements with this reference
+    pub restores: Option<(&'static str, i32)>,
+}
+
+/// Append the movement and apply it to the lots, cost layers and product or variant stock;
+/// outgoing stock without a given cost is recorded at the cost of the layers it used up, and remembers what it took
+async fn record_movement(txn: &DatabaseTransaction, movement: NewStockMovement) -> Result<StockMovementModel, AppError> {
+    let mut consumptions: Vec<StockConsumptionActiveModel> = Vec::new();
+
+    let mut lot_id = None;
+    if let Some(lot) = movement.lot.filter(|_| movement.qty.is_sign_positive()) {
+        let stock_lot = StockLotActiveModel {
+            product_id: Set(movement.product_id),
+            variant_id: Set(movement.variant_id),
+            lot_code: Set(lot.lot_code),
+            expires_at: Set(lot.expires_at),
+            qty: Set(movement.qty),
+            remaining_qty: Set(movement.qty),
+            unit_cost: Set(movement.cost_amount.and_then(|cost| costing::unit_cost(cost, movement.qty))),
+            created_at: Set(Some(Utc::now())),
+            ..Default::default()
+        }
+        .insert(txn)
+        .await
+        .map_err(db_error)?;
+
+        lot_id = Some(stock_lot.id);
+    }
+
+    // Outgoing stock leaves the lots expiring first, or the one named
+    if movement.qty.is_sign_negative() {
+        let lots = match movement.lot_id {
+            Some(id) => StockLot::find_by_id(id)
+                .filter(lot_condition(movement.product_id, movement.variant_id))
+                .lock_exclusive()
+                .all(txn)
+                .await
+                .map_err(db_error)?,
+            None => StockLot::find()
+                .filter(lot_condition(movement.product_id, movement.variant_id))
+                .order_by(StockLotColumn::ExpiresAt, Order::Asc)
+                .order_by(StockLotColumn::Id, Order::Asc)
+                .lock_exclusive()
+                .all(txn)
+                .await
+                .map_err(db_error)?,
+        };
+
+        if let Some(id) = movement.lot_id {
+            if lots.first().map(|lot| lot.remaining_qty) < Some(-movement.qty) {
+                return Err(AppError::BadRequest(format!("Lot {} does not hold that much stock", id)));
+            }
+            lot_id = Some(id);
+        }
+
+        let mut outstanding = -movement.qty;
+        for lot in lots {
+            if outstanding.is_zero() {
+                break;
+            }
+
+            let taken = lot.remaining_qty.min(outstanding);
+            outstanding -= taken;
+            consumptions.push(StockConsumptionActiveModel {
+                lot_id: Set(Some(lot.id)),
+                qty: Set(taken),
+                ..Default::default()
+            });
+
+            StockLotActiveModel {
+                id: Set(lot.id),
+                remaining_qty: Set(lot.remaining_qty - taken),
+                ..Default::default()
+            }
+            .update(txn)
+            .await
+            .map_err(db_error)?;
+        }
+    }
+
+    // Outgoing stock uses up the oldest costed receipts first and is costed at what it took
+    let mut cost_amount = movement.cost_amount;
+    if movement.qty.is_sign_negative() {
+        let layers = StockMovement::find()
+            .filter(layer_condition(movement.product_id, movement.variant_id))
+            .order_by(Column::Id, Order::Asc)
+            .lock_exclusive()
+            .all(txn)
+            .await
+            .map_err(db_error)?;
+
+        let mut consumed = Decimal::ZERO;
+        let mut outstanding = -movement.qty;
+        for layer in layers {
+            if outstanding.is_zero() {
+                break;
+            }
+
+            let remaining = layer.remaining_qty.unwrap_or_default();
+            let taken = remaining.min(outstanding);
+            outstanding -= taken;
+            consumed += taken * Decimal::from(layer.cost_amount.unwrap_or_default()) / layer.qty;
+            consumptions.push(StockConsumptionActiveModel {
+                layer_id: Set(Some(layer.id)),
+                qty: Set(taken),
+                ..Default::default()
+            });
+
+            ActiveModel {
+                id: Set(layer.id),
+                remaining_qty: Set(Some(remaining - taken)),
+                ..Default::default()
+            }
+            .update(txn)
+            .await
+            .map_err(db_error)?;
+        }
+
+        // Stock from before any costed receipt goes at the current HPP
+        if !outstanding.is_zero() {
+            let hpp_amount = match movement.variant_id {
+                Some(variant_id) => ProductVariant::find_by_id(variant_id)
+                    .one(txn)
+                    .await
+                    .map_err(db_error)?
+                    .map(|variant| variant.hpp_amount),
+                None => Product::find_by_id(movement.product_id)
+                    .one(txn)
+                    .await
+                    .map_err(db_error)?
+                    .map(|product| product.hpp_amount),
+            };
+            consumed += outstanding * Decimal::from(hpp_amount.unwrap_or_default());
+        }
+
+        cost_amount = cost_amount.or_else(|| {
+            consumed
+                .round_dp_with_strategy(0, RoundingStrategy::MidpointAwayFromZero)
+                .to_i64()
+        });
+    }
+
+    let stock_movement_active_model = ActiveModel {
+        product_id: Set(movement.product_id),
+        variant_id: Set(movement.variant_id),
+        qty: Set(movement.qty),
+        r#type: Set(movement.movement_type.to_string()),
+        reference_type: Set(movement.reference_type.map(str::to_string)),
+        reference_id: Set(movement.reference_id),
+        note: Set(movement.note),
+        created_by: Set(movement.created_by),
+        cost_amount: Set(cost_amount),
+        remaining_qty: Set(
+            Some(movement.qty).filter(|qty| qty.is_sign_positive() && cost_amount.is_some())
+        ),
+        lot_id: Set(lot_id),
+        created_at: Set(Some(Utc::now())),
+        ..Default::default()
+    };
+
+    let stock_movement = stock_movement_active_model.insert(txn).await.map_err(db_error)?;
+
+    if !consumptions.is_empty() {
+        let consumptions = consumptions.into_iter().map(|consumption| StockConsumptionActiveModel {
+            movement_id: Set(stock_movement.id),
+            created_at: Set(Some(Utc::now())),
+            ..consumption
+        });
+
+        StockConsumption::insert_many(consumptions)
+            .exec(txn)
+            .await
+            .map_err(db_error)?;
+    }
+
+    // Stock coming back goes into the layers and lots it was taken from, most recently taken first
+    if let Some((reference_type, reference_id)) = movement.restores.filter(|_| movement.qty.is_sign_positive()) {
+        let mut taken_by = Condition::all()
+            .add(Column::ProductId.eq(movement.product_id))
+            .add(Column::ReferenceType.eq(reference_type))
+            .add(Column::ReferenceId.eq(reference_id))
+            .add(Column::Qty.lt(Decimal::ZERO));
+        taken_by = match movement.variant_id {
+            Some(variant_id) => taken_by.add(Column::VariantId.eq(variant_id)),
+            None => taken_by.add(Column::VariantId.is_null()),
+        };
+        let taken_by = StockMovement::find()
+            .select_only()
+            .column(Column::Id)
+            .filter(taken_by)
+            .into_query();
+
+        let taken = StockConsumption::find()
+            .filter(StockConsumptionColumn::MovementId.in_subquery(taken_by))
+            .filter(Expr::col(StockConsumptionColumn::RestoredQty).lt(Expr::col(StockConsumptionColumn::Qty)))
+            .order_by(StockConsumptionColumn::Id, Order::Desc)
+            .lock_exclusive()
+            .all(txn)
+            .await
+            .map_err(db_error)?;
+
+        // Layers and lots are tracked apart, so each gets the full quantity back
+        let mut layer_outstanding = movement.qty;
+        let mut lot_outstanding = movement.qty;
+        for consumption in taken {
+            let outstanding = match consumption.layer_id {
+                Some(_) => &mut layer_outstanding,
+                None => &mut lot_outstanding,
+            };
+            let restored = (consumption.qty - consumption.restored_qty).min(*outstanding);
+            if restored.is_zero() {
+                continue;
+            }
+            *outstanding -= restored;
+
+            StockConsumptionActiveModel {
+                id: Set(consumption.id),
+                restored_qty: Set(consumption.restored_qty + restored),
+                ..Default::default()
+            }
+            .update(txn)
+            .await
+            .map_err(db_error)?;
+
+            if let Some(layer_id) = consumption.layer_id {
+                StockMovement::update_many()
+                    .col_expr(Column::RemainingQty, Expr::col(Column::RemainingQty).add(restored))
+                    .filter(Column::Id.eq(layer_id))
+                    .exec(txn)
+                    .await
+                    .map_err(db_error)?;
+            }
+            if let Some(lot_id) = consumption.lot_id {
+                StockLot::update_many()
+                    .col_expr(StockLotColumn::RemainingQty, Expr::col(StockLotColumn::RemainingQty).add(restored))
+                    .filter(StockLotColumn::Id.eq(lot_id))
+                    .exec(txn)
+                    .await
+                    .map_err(db_error)?;
+            }
+        }
+    }
+
+    match movement.variant_id {
+        Some(variant_id) => {
+            ProductVariant::update_many()
+                .col_expr(ProductVariantColumn::Stock, Expr::col(ProductVariantColumn::Stock).add(movement.qty))
+                .filter(ProductVariantColumn::Id.eq(variant_id))
+                .exec(txn)
+                .await
+                .map_err(db_error)?;
+        }
+        None => {
+            Product::update_many()
+                .col_expr(ProductColumn::Stock, Expr::col(ProductColumn::Stock).add(movement.qty))
+                .filter(ProductColumn::Id.eq(movement.product_id))
+                .exec(txn)
+                .await
+                .map_err(db_error)?;
+        }
+    }
+
+    Ok(stock_movement)
+}
+
+#[async_trait]
+pub trait StockRepository: Send + Sync {
+    async fn record(&self, movement: NewStockMovement) -> Result<StockMovementModel, AppError>;
+    async fn record_in(&self, txn: &DatabaseTransaction, movement: NewStockMovement) -> Result<StockMovementModel, AppError>;
+    async fn find_by_reference(&self, reference_type: &str, reference_id: i32) -> Result<Vec<StockMovementModel>, AppError>;
+    async fn find_open_layers(&self, product_id: i32, variant_id: Option<i32>) -> Result<Vec<StockMovementModel>, AppError>;
+    async fn sold_since(&self, since: DateTime<Utc>) -> Result<Vec<SoldQtyRow>, AppError>;
+    async fn find_with_pagination(&self, query: GetListStockMovementQuery) -> Result<PaginatedResult<StockMovementModel>, AppError>;
+}
+
+pub struct StockRepositoryImpl {
+    db: Arc<DatabaseConnection>,
+}
+
+impl StockRepositoryImpl {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl StockRepository for StockRepositoryImpl {
+    /// Append the movement and apply it to the product or variant stock in one DB transaction
+    async fn record(&self, movement: NewStockMovement) -> Result<StockMovementModel, AppError> {
+        let txn = self.db.begin().await.map_err(db_error)?;
+        let stock_movement = record_movement(&txn, movement).await?;
+        txn.commit().await.map_err(db_error)?;
+
+        Ok(stock_movement)
+    }
+
+    /// Same as `record`, as part of the caller's DB transaction
+    async fn record_in(&self, txn: &DatabaseTransaction, movement: NewStockMovement) -> Result<StockMovementModel, AppError> {
+        record_movement(txn, movement).await
+    }
+
+    async fn find_by_reference(&self, reference_type: &str, reference_id: i32) -> Result<Vec<StockMovementModel>, AppError> {
+        StockMovement::find()
+            .filter(Column::ReferenceType.eq(reference_type))
+            .filter(Column::ReferenceId.eq(reference_id))
+            .order_by(Column::Id, Order::Asc)
+            .all(self.db.as_ref())
+            .await
+            .map_err(db_error)
+    }
+
+    /// Costed receipts of the product or variant with stock left, oldest first
+    async fn find_open_layers(&self, product_id: i32, variant_id: Option<i32>) -> Result<Vec<StockMovementModel>, AppError> {
+        StockMovement::find()
+            .filter(layer_condition(product_id, variant_id))
+            .order_by(Column::Id, Order::Asc)
+            .all(self.db.as_ref())
+            .await
+            .map_err(db_error)
+    }
+
+    /// Stock taken by sales since `since`, net of reversals and returns; covers recipe ingredients and bundle components
+    async fn sold_since(&self, since: DateTime<Utc>) -> Result<Vec<SoldQtyRow>, AppError> {
+        StockMovement::find()
+            .select_only()
+            .column(Column::ProductId)
+            .column(Column::VariantId)
+            .column_as(Expr::cust("COALESCE(-SUM(stock_movements.qty), 0)"), "qty")
+            .filter(Column::Type.is_in([MOVEMENT_SALE, MOVEMENT_SALE_REVERSAL, MOVEMENT_RETURN]))
+            .filter(Column::CreatedAt.gte(since))
+            .group_by(Column::ProductId)
+            .group_by(Column::VariantId)
+            .into_model::<SoldQtyRow>()
+            .all(self.db.as_ref())
+            .await
+            .map_err(db_error)
+    }
+
+    async fn find_with_pagination(
+        &self,
+        query: GetListStockMovementQuery
+    ) -> Result<PaginatedResult<StockMovementModel>, AppError> {
+        // Build filter condition
+        let mut search_condition = Condition::all();
+
+        let product_ids = filter::parse_list::<i32>("product_id", query.product_id.as_deref())?;
+        if !product_ids.is_empty() {
+            search_condition = search_condition.add(Column::ProductId.is_in(product_ids));
+        }
+
+        let variant_ids = filter::parse_list::<i32>("variant_id", query.variant_id.as_deref())?;
+        if !variant_ids.is_empty() {
+            search_condition = search_condition.add(Column::VariantId.is_in(variant_ids));
+        }
+
+        let types = filter::parse_list::<String>("type", query.r#type.as_deref())?;
+        if !types.is_empty() {
+            search_condition = search_condition.add(Column::Type.is_in(types));
+        }
+
+        let timezone = filter::parse_timezone(query.timezone.as_deref())?;
+
+        if let Some(created_from) = &query.created_from {
+            let created_from = filter::parse_date_from("created_from", created_from, timezone)?;
+            search_condition = search_condition.add(Column::CreatedAt.gte(created_from));
+        }
+
+        if let Some(created_to) = &query.created_to {
+            let created_to = filter::parse_date_to("created_to", created_to, timezone)?;
+            search_condition = search_condition.add(Column::CreatedAt.lt(created_to));
+        }
+
+        let direction = match query.direction.as_deref() {
+            Some("asc") | Some("ASC") => Order::Asc,
+            _ => Order::Desc,
+        };
+
+        let page_query = PageQuery {
+            limit: query.limit,
+            page: query.page,
+            include_total: query.include_total,
+            ..Default::default()
+        };
+
+        let select = StockMovement::find()
+            .filter(search_condition)
+            .order_by(Column::Id, direction);
+
+        pagination::paginate_select(self.db.as_ref(), select, &page_query)
+            .await
+            .map_err(db_error)
+    }
+}
@@ -0,0 +1,99 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use sea_orm::{ColumnTrait, Condition, DatabaseConnection};
+use crate::{
+    module::supplier::v1::entity::{
+        model::supplier::{ActiveModel, Entity as Supplier, Model as SupplierModel, Column},
+        request::list_supplier_request::GetListSupplierQuery,
+    },
+    module::repository::soft_delete_repository::{SoftDeleteEntity, SoftDeleteRepository},
+    pkg::{
+        custom_error::AppError,
+        pagination::{PageQuery, PaginatedResult},
+    }
+};
+
+
+impl SoftDeleteEntity for Supplier {
+    type ActiveModel = ActiveModel;
+
+    const NAME: &'static str = "Supplier";
+    const SEARCH_COLUMNS: &'static [Column] = &[Column::Name, Column::Phone, Column::Email];
+    const SORT_COLUMNS: &'static [(&'static str, Column)] = &[
+        ("name", Column::Name),
+        ("created_at", Column::CreatedAt),
+        ("updated_at", Column::UpdatedAt),
+    ];
+
+    fn id_column() -> Column { Column::Id }
+    fn updated_at_column() -> Column { Column::UpdatedAt }
+    fn deleted_at_column() -> Column { Column::DeletedAt }
+}
+
+#[async_trait]
+pub trait SupplierRepository: Send + Sync {
+    async fn create(&self, supplier: ActiveModel) -> Result<SupplierModel, AppError>;
+    async fn find_by_id(&self, id: i32) -> Result<Option<SupplierModel>, AppError>;
+    async fn find_by_ids(&self, ids: &[i32]) -> Result<Vec<SupplierModel>, AppError>;
+    async fn find_by_name(&self, name: &str) -> Result<Option<SupplierModel>, AppError>;
+    async fn find_with_pagination(&self, query: GetListSupplierQuery) -> Result<PaginatedResult<SupplierModel>, AppError>;
+    async fn update(&self, id: i32, supplier: ActiveModel) -> Result<SupplierModel, AppError>;
+    async fn delete(&self, id: i32) -> Result<SupplierModel, AppError>;
+}
+
+pub struct SupplierRepositoryImpl {
+    base: SoftDeleteRepository<Supplier>,
+}
+
+impl SupplierRepositoryImpl {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { base: SoftDeleteRepository::new(db) }
+    }
+}
+
+#[async_trait]
+impl SupplierRepository for SupplierRepositoryImpl {
+    async fn create(&self, supplier: ActiveModel) -> Result<SupplierModel, AppError> {
+        self.base.create(supplier).await
+    }
+
+    async fn find_by_id(&self, id: i32) -> Result<Option<SupplierModel>, AppError> {
+        self.base.find_by_id(id).await
+    }
+
+    async fn find_by_ids(&self, ids: &[i32]) -> Result<Vec<SupplierModel>, AppError> {
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        self.base.find_all(Condition::all().add(Column::Id.is_in(ids.to_vec()))).await
+    }
+
+    async fn find_by_name(&self, name: &str) -> Result<Option<SupplierModel>, AppError> {
+        self.base.find_one(Condition::all().add(Column::Name.eq(name))).await
+    }
+
+    async fn find_with_pagination(
+        &self,
+        query: GetListSupplierQuery
+    ) -> Result<PaginatedResult<SupplierModel>, AppError> {
+        let page_query = PageQuery {
+            limit: query.limit,
+            page: query.page,
+            search: query.search,
+            order_by: query.order_by,
+            direction: query.direction,
+            ..Default::default()
+        };
+
+        self.base.find_with_pagination(page_query, Condition::all()).await
+    }
+
+    async fn update(&self, id: i32, supplier: ActiveModel) -> Result<SupplierModel, AppError> {
+        self.base.update(id, supplier).await
+    }
+
+    async fn delete(&self, id: i32) -> Result<SupplierModel, AppError> {
+        self.base.delete(id).await
+    }
+}
@@ -0,0 +1,71 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use sea_orm::{prelude::Decimal, ColumnTrait, DatabaseTransaction, DbErr, EntityTrait, QueryFilter, Set};
+use crate::{
+    module::transaction::v1::entity::model::transaction_allocation::{ActiveModel, Entity as TransactionAllocation, Column},
+    pkg::custom_error::AppError,
+};
+use tracing::log::error;
+
+fn db_error(err: DbErr) -> AppError {
+    error!("DB error: {:?}", err);
+    AppError::InternalError
+}
+
+/// Component share of a bundle line to store
+pub struct NewTransactionAllocation {
+    pub product_id: i32,
+    pub variant_id: Option<i32>,
+    pub qty: Decimal,
+    pub revenue_amount: i64,
+    pub discount_amount: i64,
+    pub hpp_amount: i64,
+}
+
+#[async_trait]
+pub trait TransactionAllocationRepository: Send + Sync {
+    async fn replace(&self, txn: &DatabaseTransaction, transaction_id: i32, allocations: Vec<NewTransactionAllocation>) -> Result<(), AppError>;
+}
+
+// Allocations are only ever written along with their sale, inside its DB transaction
+#[derive(Default)]
+pub struct TransactionAllocationRepositoryImpl;
+
+impl TransactionAllocationRepositoryImpl {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+#[async_trait]
+impl TransactionAllocationRepository for TransactionAllocationRepositoryImpl {
+    /// Set the line's allocations to exactly `allocations`, empty for lines that are not bundles
+    async fn replace(&self, txn: &DatabaseTransaction, transaction_id: i32, allocations: Vec<NewTransactionAllocation>) -> Result<(), AppError> {
+        TransactionAllocation::delete_many()
+            .filter(Column::TransactionId.eq(transaction_id))
+            .exec(txn)
+            .await
+            .map_err(db_error)?;
+
+        if !allocations.is_empty() {
+            let allocations = allocations.into_iter().map(|allocation| ActiveModel {
+                transaction_id: Set(transaction_id),
+                product_id: Set(allocation.product_id),
+                variant_id: Set(allocation.variant_id),
+                qty: Set(allocation.qty),
+                revenue_amount: Set(allocation.revenue_amount),
+                discount_amount: Set(allocation.discount_amount),
+                hpp_amount: Set(allocation.hpp_amount),
+                created_at: Set(Some(Utc::now())),
+                ..Default::default()
+            });
+
+            TransactionAllocation::insert_many(allocations)
+                .exec(txn)
+                .await
+                .map_err(db_error)?;
+        }
+
+        Ok(())
+    }
+}
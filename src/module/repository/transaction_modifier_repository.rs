@@ -0,0 +1,80 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::Utc;
+use sea_orm::{
+    ColumnTrait, DatabaseConnection, DatabaseTransaction, DbErr, EntityTrait, Order, QueryFilter, QueryOrder, Set,
+};
+use crate::{
+    module::modifier::v1::entity::model::modifier::Model as ModifierModel,
+    module::transaction::v1::entity::model::transaction_modifier::{
+        ActiveModel, Entity as TransactionModifier, Model as TransactionModifierModel, Column,
+    },
+    pkg::custom_error::AppError,
+};
+use tracing::log::error;
+
+fn db_error(err: DbErr) -> AppError {
+    error!("DB error: {:?}", err);
+    AppError::InternalError
+}
+
+#[async_trait]
+pub trait TransactionModifierRepository: Send + Sync {
+    async fn find_by_transactions(&self, transaction_ids: &[i32]) -> Result<Vec<TransactionModifierModel>, AppError>;
+    async fn replace(&self, txn: &DatabaseTransaction, transaction_id: i32, modifiers: &[ModifierModel]) -> Result<(), AppError>;
+}
+
+pub struct TransactionModifierRepositoryImpl {
+    db: Arc<DatabaseConnection>,
+}
+
+impl TransactionModifierRepositoryImpl {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl TransactionModifierRepository for TransactionModifierRepositoryImpl {
+    async fn find_by_transactions(&self, transaction_ids: &[i32]) -> Result<Vec<TransactionModifierModel>, AppError> {
+        if transaction_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        TransactionModifier::find()
+            .filter(Column::TransactionId.is_in(transaction_ids.to_vec()))
+            .order_by(Column::Id, Order::Asc)
+            .all(self.db.as_ref())
+            .await
+            .map_err(db_error)
+    }
+
+    /// Snapshot `modifiers` as the line's only modifiers
+    async fn replace(&self, txn: &DatabaseTransaction, transaction_id: i32, modifiers: &[ModifierModel]) -> Result<(), AppError> {
+        TransactionModifier::delete_many()
+            .filter(Column::TransactionId.eq(transaction_id))
+            .exec(txn)
+            .await
+            .map_err(db_error)?;
+
+        if !modifiers.is_empty() {
+            let snapshots = modifiers.iter().map(|modifier| ActiveModel {
+                transaction_id: Set(transaction_id),
+                modifier_id: Set(Some(modifier.id)),
+                group_id: Set(Some(modifier.group_id)),
+                name: Set(modifier.name.clone()),
+                hpp_amount: Set(modifier.hpp_amount),
+                selling_amount: Set(modifier.selling_amount),
+                created_at: Set(Some(Utc::now())),
+                ..Default::default()
+            });
+
+            TransactionModifier::insert_many(snapshots)
+                .exec(txn)
+                .await
+                .map_err(db_error)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,81 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::Utc;
+use sea_orm::{ColumnTrait, DatabaseConnection, DatabaseTransaction, DbErr, EntityTrait, Order, QueryFilter, QueryOrder, Set};
+use crate::{
+    module::transaction::v1::entity::model::transaction_payment::{
+        ActiveModel, Entity as TransactionPayment, Model as TransactionPaymentModel, Column,
+    },
+    pkg::custom_error::AppError,
+};
+use tracing::log::error;
+
+fn db_error(err: DbErr) -> AppError {
+    error!("DB error: {:?}", err);
+    AppError::InternalError
+}
+
+/// Settled payment to store
+pub struct NewTransactionPayment {
+    pub method: String,
+    pub amount: i64,
+    pub tendered_amount: Option<i64>,
+    pub change_amount: i64,
+    pub reference: Option<String>,
+}
+
+#[async_trait]
+pub trait TransactionPaymentRepository: Send + Sync {
+    async fn find_by_transactions(&self, transaction_ids: &[i32]) -> Result<Vec<TransactionPaymentModel>, AppError>;
+    async fn create(&self, txn: &DatabaseTransaction, transaction_id: i32, payments: Vec<NewTransactionPayment>) -> Result<(), AppError>;
+}
+
+pub struct TransactionPaymentRepositoryImpl {
+    db: Arc<DatabaseConnection>,
+}
+
+impl TransactionPaymentRepositoryImpl {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl TransactionPaymentRepository for TransactionPaymentRepositoryImpl {
+    async fn find_by_transactions(&self, transaction_ids: &[i32]) -> Result<Vec<TransactionPaymentModel>, AppError> {
+        if transaction_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        TransactionPayment::find()
+            .filter(Column::TransactionId.is_in(transaction_ids.to_vec()))
+            .order_by(Column::Id, Order::Asc)
+            .all(self.db.as_ref())
+            .await
+            .map_err(db_error)
+    }
+
+    async fn create(&self, txn: &DatabaseTransaction, transaction_id: i32, payments: Vec<NewTransactionPayment>) -> Result<(), AppError> {
+        if payments.is_empty() {
+            return Ok(());
+        }
+
+        let payments = payments.into_iter().map(|payment| ActiveModel {
+            transaction_id: Set(transaction_id),
+            method: Set(payment.method),
+            amount: Set(payment.amount),
+            tendered_amount: Set(payment.tendered_amount),
+            change_amount: Set(payment.change_amount),
+            reference: Set(payment.reference),
+            created_at: Set(Some(Utc::now())),
+            ..Default::default()
+        });
+
+        TransactionPayment::insert_many(payments)
+            .exec(txn)
+            .await
+            .map_err(db_error)?;
+
+        Ok(())
+    }
+}
@@ -1,195 +1,223 @@
 use std::sync::Arc;
 use async_trait::async_trait;
-use sea_orm::{ColumnTrait, Condition, DatabaseConnection, EntityTrait, Order, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, Set};
-use chrono::Utc;
+use chrono::{DateTime, Utc};
+use sea_orm::{
+    prelude::Decimal, sea_query::{Expr, Query}, ActiveModelTrait, ColumnTrait, Condition, DatabaseConnection, DatabaseTransaction, DbErr,
+    EntityTrait, QueryFilter, Set, TransactionTrait,
+};
 use crate::{
     module::transaction::v1::entity::{
-        model::transaction::{ActiveModel, Entity as Transaction, Model as TransactionModel, Column},
+        model::transaction::{ActiveModel, Entity as Transaction, Model as TransactionModel, Column, STATUS_COMPLETED, STATUS_VOIDED},
+        model::transaction_return::{Entity as TransactionReturn, Column as TransactionReturnColumn},
         request::list_transaction_request::GetListTransactionQuery,
     },
+    module::repository::soft_delete_repository::{PurgeSummary, SoftDeleteEntity, SoftDeleteRepository},
     pkg::{
         custom_error::AppError,
-        pagination::PaginatedResult,
+        filter,
+        pagination::{PageQuery, PaginatedResult},
     }
 };
 use tracing::log::error;
 
+fn db_error(err: DbErr) -> AppError {
+    error!("DB error: {:?}", err);
+    AppError::InternalError
+}
+
+
+impl SoftDeleteEntity for Transaction {
+    type ActiveModel = ActiveModel;
+
+    const NAME: &'static str = "Transaction";
+    const SEARCH_COLUMNS: &'static [Column] = &[];
+    const SORT_COLUMNS: &'static [(&'static str, Column)] = &[
+        ("product_id", Column::ProductId),
+        ("hpp_amount", Column::HppAmount),
+        ("selling_amount", Column::SellingAmount),
+        ("qty", Column::Qty),
+        ("created_at", Column::CreatedAt),
+        ("updated_at", Column::UpdatedAt),
+    ];
+
+    fn id_column() -> Column { Column::Id }
+    fn updated_at_column() -> Column { Column::UpdatedAt }
+    fn deleted_at_column() -> Column { Column::DeletedAt }
+}
 
+/// Who voided a sale, who approved it and why
+pub struct NewVoid {
+    pub reason: String,
+    pub voided_by: Option<i32>,
+    pub approved_by: i32,
+}
+
+// Sales are immutable once created: only returns and a void change them
 #[async_trait]
 pub trait TransactionRepository: Send + Sync {
-    async fn create(&self, transaction: ActiveModel) -> Result<(), AppError>;
+    async fn begin(&self) -> Result<DatabaseTransaction, AppError>;
+    async fn create_in(&self, txn: &DatabaseTransaction, transaction: ActiveModel) -> Result<TransactionModel, AppError>;
     async fn find_by_id(&self, id: i32) -> Result<Option<TransactionModel>, AppError>;
     async fn find_with_pagination(&self, query: GetListTransactionQuery) -> Result<PaginatedResult<TransactionModel>, AppError>;
-    async fn update(&self, id: i32, transaction: ActiveModel) -> Result<TransactionModel, AppError>;
-    async fn delete(&self, id: i32) -> Result<(), AppError>;
+    async fn claim_return(&self, txn: &DatabaseTransaction, id: i32, qty: Decimal) -> Result<bool, AppError>;
+    async fn claim_void_in(&self, txn: &DatabaseTransaction, id: i32, void: NewVoid) -> Result<Option<TransactionModel>, AppError>;
+    async fn find_deleted_by_id(&self, id: i32) -> Result<Option<TransactionModel>, AppError>;
+    async fn set_hpp_in(&self, txn: &DatabaseTransaction, id: i32, hpp_amount: i32) -> Result<TransactionModel, AppError>;
+    async fn purge(&self, id: i32) -> Result<(), AppError>;
+    async fn purge_deleted_before(&self, cutoff: DateTime<Utc>, dry_run: bool) -> Result<PurgeSummary, AppError>;
 }
 
 pub struct TransactionRepositoryImpl {
-    db: Arc<DatabaseConnection>,
+    base: SoftDeleteRepository<Transaction>,
 }
 
 impl TransactionRepositoryImpl {
     pub fn new(db: Arc<DatabaseConnection>) -> Self {
-        Self { db }
+        Self { base: SoftDeleteRepository::new(db) }
+    }
+
+    /// Paging parameters and filter condition of a list query
+    fn list_query(query: GetListTransactionQuery) -> Result<(PageQuery, Condition), AppError> {
+        // Build filter condition
+        let mut search_condition = Condition::all();
+
+        let product_ids = filter::parse_list::<i32>("product_id", query.product_id.as_deref())?;
+        if !product_ids.is_empty() {
+            search_condition = search_condition.add(Column::ProductId.is_in(product_ids));
+        }
+
+        let statuses = filter::parse_list::<String>("status", query.status.as_deref())?;
+        if !statuses.is_empty() {
+            search_condition = search_condition.add(Column::Status.is_in(statuses));
+        }
+
+        let timezone = filter::parse_timezone(query.timezone.as_deref())?;
+
+        if let Some(created_from) = &query.created_from {
+            let created_from = filter::parse_date_from("created_from", created_from, timezone)?;
+            search_condition = search_condition.add(Column::CreatedAt.gte(created_from));
+        }
+
+        if let Some(created_to) = &query.created_to {
+            let created_to = filter::parse_date_to("created_to", created_to, timezone)?;
+            search_condition = search_condition.add(Column::CreatedAt.lt(created_to));
+        }
+
+        // Net line total, as the sales reports count it
+        let amount = Expr::cust(
+            "ROUND(CAST(transactions.selling_amount + transactions.modifier_selling_amount AS BIGINT) * transactions.qty) - transactions.discount_amount",
+        );
+
+        if let Some(min_amount) = filter::parse_amount("min_amount", query.min_amount.as_deref())? {
+            search_condition = search_condition.add(Expr::expr(amount.clone()).gte(min_amount));
+        }
+
+        if let Some(max_amount) = filter::parse_amount("max_amount", query.max_amount.as_deref())? {
+            search_condition = search_condition.add(Expr::expr(amount).lte(max_amount));
+        }
+
+        let page_query = PageQuery {
+            limit: query.limit,
+            page: query.page,
+            search: query.search,
+            order_by: query.order_by,
+            direction: query.direction,
+            cursor: query.cursor,
+            include_total: query.include_total,
+        };
+
+        Ok((page_query, search_condition))
     }
 }
 
 #[async_trait]
 impl TransactionRepository for TransactionRepositoryImpl {
-    async fn create(&self, transaction: ActiveModel) -> Result<(), AppError> {
-        Transaction::insert(transaction)
-            .exec(self.db.as_ref())
-            .await
-            .map_err(|err| {
-                error!("DB error: {:?}", err);
-                AppError::InternalError
-            })?;
+    /// Start a DB transaction for a sale and everything written along with it
+    async fn begin(&self) -> Result<DatabaseTransaction, AppError> {
+        self.base.db().begin().await.map_err(db_error)
+    }
 
-        Ok(())
+    async fn create_in(&self, txn: &DatabaseTransaction, transaction: ActiveModel) -> Result<TransactionModel, AppError> {
+        transaction.insert(txn).await.map_err(db_error)
     }
 
     async fn find_by_id(&self, id: i32) -> Result<Option<TransactionModel>, AppError> {
-        Transaction::find_by_id(id)
-            .filter(Column::DeletedAt.is_null())
-            .one(self.db.as_ref())
-            .await
-            .map_err(|err| {
-                error!("DB error: {:?}", err);
-                AppError::InternalError
-            })
+        self.base.find_by_id(id).await
     }
 
     async fn find_with_pagination(
         &self,
         query: GetListTransactionQuery
     ) -> Result<PaginatedResult<TransactionModel>, AppError> {
-        // Parse pagination parameters
-        let page = query.page
-            .and_then(|p| p.parse::<u64>().ok())
-            .unwrap_or(1)
-            .max(1);
-    
-        let limit = query.limit
-            .and_then(|l| l.parse::<u64>().ok())
-            .unwrap_or(10)
-            .min(100);
-    
-        let offset = (page - 1) * limit;
-    
-        // Build search condition
-        let mut search_condition = Condition::all().add(Column::DeletedAt.is_null());
-        
-        if let Some(product_id_str) = &query.product_id {
-            if let Ok(product_id) = product_id_str.parse::<i32>() {
-                search_condition = search_condition.add(Column::ProductId.eq(product_id));
-            }
-        }
-    
-        let search_condition_for_count = search_condition.clone();
-    
-        // Build ordering
-        let mut query_builder = Transaction::find().filter(search_condition);
-    
-        if let Some(order_by) = &query.order_by {
-            let direction = match query.direction.as_deref() {
-                Some("desc") | Some("DESC") => Order::Desc,
-                _ => Order::Asc,
-            };
-    
-            query_builder = match order_by.to_lowercase().as_str() {
-                "product_id" => query_builder.order_by(Column::ProductId, direction),
-                "hpp_amount" => query_builder.order_by(Column::HppAmount, direction),
-                "selling_amount" => query_builder.order_by(Column::SellingAmount, direction),
-                "qty" => query_builder.order_by(Column::Qty, direction),
-                "created_at" => query_builder.order_by(Column::CreatedAt, direction),
-                "updated_at" => query_builder.order_by(Column::UpdatedAt, direction),
-                _ => query_builder.order_by(Column::Id, Order::Asc),
-            };
-        } else {
-            query_builder = query_builder.order_by(Column::Id, Order::Asc);
-        }
-    
-        // Get total count
-        let total = Transaction::find()
-            .filter(search_condition_for_count)
-            .count(self.db.as_ref())
-            .await
-            .map_err(|err| {
-                error!("DB error: {:?}", err);
-                AppError::InternalError
-            })?;
-    
-        // Get paginated data
-        let data = query_builder
-            .offset(offset)
-            .limit(limit)
-            .all(self.db.as_ref())
-            .await
-            .map_err(|err| {
-                error!("DB error: {:?}", err);
-                AppError::InternalError
-            })?;
-    
-        let total_pages = (total as u64 as f64 / limit as f64).ceil() as u64;
-    
-        Ok(PaginatedResult {
-            data,
-            total,
-            page,
-            limit,
-            total_pages,
-        })
+        let (page_query, condition) = Self::list_query(query)?;
+        self.base.find_with_pagination(page_query, condition).await
     }
 
-    async fn update(&self, id: i32, mut transaction: ActiveModel) -> Result<TransactionModel, AppError> {
-        // Set updated_at timestamp
-        transaction.updated_at = Set(Some(Utc::now()));
-
+    /// Add `qty` to the returned quantity unless that would return more than was sold
+    async fn claim_return(&self, txn: &DatabaseTransaction, id: i32, qty: Decimal) -> Result<bool, AppError> {
         let result = Transaction::update_many()
-            .set(transaction)
+            .col_expr(Column::ReturnedQty, Expr::col(Column::ReturnedQty).add(qty))
+            .col_expr(Column::UpdatedAt, Expr::value(Utc::now()))
             .filter(Column::Id.eq(id))
+            .filter(Expr::col(Column::ReturnedQty).lte(Expr::col(Column::Qty).sub(qty)))
+            .filter(Column::Status.eq(STATUS_COMPLETED))
             .filter(Column::DeletedAt.is_null())
-            .exec(self.db.as_ref())
+            .exec(txn)
             .await
-            .map_err(|err| {
-                error!("DB error: {:?}", err);
-                AppError::InternalError
-            })?;
-
-        if result.rows_affected == 0 {
-            return Err(AppError::NotFound(format!("Transaction with id {} not found", id)));
-        }
+            .map_err(db_error)?;
 
-        // Fetch the updated transaction
-        Transaction::find_by_id(id)
-            .filter(Column::DeletedAt.is_null())
-            .one(self.db.as_ref())
-            .await
-            .map_err(|err| {
-                error!("DB error: {:?}", err);
-                AppError::InternalError
-            })?
-            .ok_or_else(|| AppError::NotFound(format!("Transaction with id {} not found", id)))
+        Ok(result.rows_affected > 0)
     }
 
-    async fn delete(&self, id: i32) -> Result<(), AppError> {
+    /// Void a completed sale with nothing returned yet, returning it as voided
+    async fn claim_void_in(&self, txn: &DatabaseTransaction, id: i32, void: NewVoid) -> Result<Option<TransactionModel>, AppError> {
         let now = Utc::now();
-        let result = Transaction::update_many()
-            .col_expr(Column::DeletedAt, now.into())
-            .col_expr(Column::UpdatedAt, now.into())
+        let voided = Transaction::update_many()
+            .col_expr(Column::Status, Expr::value(STATUS_VOIDED))
+            .col_expr(Column::VoidReason, Expr::value(void.reason))
+            .col_expr(Column::VoidedBy, Expr::value(void.voided_by))
+            .col_expr(Column::VoidApprovedBy, Expr::value(void.approved_by))
+            .col_expr(Column::VoidedAt, Expr::value(now))
+            .col_expr(Column::UpdatedAt, Expr::value(now))
             .filter(Column::Id.eq(id))
+            .filter(Column::Status.eq(STATUS_COMPLETED))
+            .filter(Column::ReturnedQty.eq(Decimal::ZERO))
             .filter(Column::DeletedAt.is_null())
-            .exec(self.db.as_ref())
+            .exec_with_returning(txn)
             .await
-            .map_err(|err| {
-                error!("DB error: {:?}", err);
-                AppError::InternalError
-            })?;
+            .map_err(db_error)?;
+
+        Ok(voided.into_iter().next())
+    }
+
+    async fn find_deleted_by_id(&self, id: i32) -> Result<Option<TransactionModel>, AppError> {
+        self.base.find_deleted_by_id(id).await
+    }
 
-        if result.rows_affected == 0 {
-            return Err(AppError::NotFound(format!("Transaction with id {} not found", id)));
+    async fn set_hpp_in(&self, txn: &DatabaseTransaction, id: i32, hpp_amount: i32) -> Result<TransactionModel, AppError> {
+        ActiveModel {
+            id: Set(id),
+            hpp_amount: Set(hpp_amount),
+            updated_at: Set(Some(Utc::now())),
+            ..Default::default()
         }
+        .update(txn)
+        .await
+        .map_err(db_error)
+    }
 
-        Ok(())
+    async fn purge(&self, id: i32) -> Result<(), AppError> {
+        self.base.purge(id).await
     }
-}
+
+    async fn purge_deleted_before(&self, cutoff: DateTime<Utc>, dry_run: bool) -> Result<PurgeSummary, AppError> {
+        // Returns keep the sale they refunded
+        let returned = Query::select()
+            .column(TransactionReturnColumn::TransactionId)
+            .from(TransactionReturn)
+            .to_owned();
+
+        let condition = Condition::all().add(Column::Id.not_in_subquery(returned));
+        self.base.purge_deleted_before(cutoff, condition, dry_run).await
+    }
+}
\ No newline at end of file
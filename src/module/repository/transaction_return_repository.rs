@@ -0,0 +1,58 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use sea_orm::{ActiveModelTrait, ColumnTrait, DatabaseConnection, DatabaseTransaction, DbErr, EntityTrait, Order, QueryFilter, QueryOrder};
+use crate::{
+    module::transaction::v1::entity::model::transaction_return::{
+        ActiveModel, Entity as TransactionReturn, Model as TransactionReturnModel, Column,
+    },
+    pkg::custom_error::AppError,
+};
+use tracing::log::error;
+
+fn db_error(err: DbErr) -> AppError {
+    error!("DB error: {:?}", err);
+    AppError::InternalError
+}
+
+// Returns are never edited or deleted; a mistaken return is corrected by a new sale
+#[async_trait]
+pub trait TransactionReturnRepository: Send + Sync {
+    async fn create(&self, txn: &DatabaseTransaction, transaction_return: ActiveModel) -> Result<TransactionReturnModel, AppError>;
+    async fn find_by_transaction(&self, transaction_id: i32) -> Result<Vec<TransactionReturnModel>, AppError>;
+    async fn find_by_cashflow(&self, cashflow_id: i32) -> Result<Option<TransactionReturnModel>, AppError>;
+}
+
+pub struct TransactionReturnRepositoryImpl {
+    db: Arc<DatabaseConnection>,
+}
+
+impl TransactionReturnRepositoryImpl {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl TransactionReturnRepository for TransactionReturnRepositoryImpl {
+    async fn create(&self, txn: &DatabaseTransaction, transaction_return: ActiveModel) -> Result<TransactionReturnModel, AppError> {
+        transaction_return.insert(txn).await.map_err(db_error)
+    }
+
+    async fn find_by_transaction(&self, transaction_id: i32) -> Result<Vec<TransactionReturnModel>, AppError> {
+        TransactionReturn::find()
+            .filter(Column::TransactionId.eq(transaction_id))
+            .order_by(Column::Id, Order::Asc)
+            .all(self.db.as_ref())
+            .await
+            .map_err(db_error)
+    }
+
+    /// The return whose refund is the cashflow entry
+    async fn find_by_cashflow(&self, cashflow_id: i32) -> Result<Option<TransactionReturnModel>, AppError> {
+        TransactionReturn::find()
+            .filter(Column::CashflowId.eq(cashflow_id))
+            .one(self.db.as_ref())
+            .await
+            .map_err(db_error)
+    }
+}
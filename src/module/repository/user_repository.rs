@@ -0,0 +1,95 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use sea_orm::{ColumnTrait, Condition, DatabaseConnection};
+use crate::{
+    module::user::v1::entity::{
+        model::user::{ActiveModel, Entity as User, Model as UserModel, Column},
+        request::list_user_request::GetListUserQuery,
+    },
+    module::repository::soft_delete_repository::{SoftDeleteEntity, SoftDeleteRepository},
+    pkg::{
+        custom_error::AppError,
+        filter,
+        pagination::{PageQuery, PaginatedResult},
+    }
+};
+
+
+impl SoftDeleteEntity for User {
+    type ActiveModel = ActiveModel;
+
+    const NAME: &'static str = "User";
+    const SEARCH_COLUMNS: &'static [Column] = &[Column::Name, Column::Username];
+    const SORT_COLUMNS: &'static [(&'static str, Column)] = &[
+        ("name", Column::Name),
+        ("username", Column::Username),
+        ("role", Column::Role),
+        ("created_at", Column::CreatedAt),
+        ("updated_at", Column::UpdatedAt),
+    ];
+
+    fn id_column() -> Column { Column::Id }
+    fn updated_at_column() -> Column { Column::UpdatedAt }
+    fn deleted_at_column() -> Column { Column::DeletedAt }
+}
+
+#[async_trait]
+pub trait UserRepository: Send + Sync {
+    async fn create(&self, user: ActiveModel) -> Result<UserModel, AppError>;
+    async fn find_by_id(&self, id: i32) -> Result<Option<UserModel>, AppError>;
+    async fn find_by_username(&self, username: &str) -> Result<Option<UserModel>, AppError>;
+    async fn find_with_pagination(&self, query: GetListUserQuery) -> Result<PaginatedResult<UserModel>, AppError>;
+    async fn update(&self, id: i32, user: ActiveModel) -> Result<UserModel, AppError>;
+}
+
+pub struct UserRepositoryImpl {
+    base: SoftDeleteRepository<User>,
+}
+
+impl UserRepositoryImpl {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { base: SoftDeleteRepository::new(db) }
+    }
+}
+
+#[async_trait]
+impl UserRepository for UserRepositoryImpl {
+    async fn create(&self, user: ActiveModel) -> Result<UserModel, AppError> {
+        self.base.create(user).await
+    }
+
+    async fn find_by_id(&self, id: i32) -> Result<Option<UserModel>, AppError> {
+        self.base.find_by_id(id).await
+    }
+
+    async fn find_by_username(&self, username: &str) -> Result<Option<UserModel>, AppError> {
+        self.base.find_one(Condition::all().add(Column::Username.eq(username))).await
+    }
+
+    async fn find_with_pagination(
+        &self,
+        query: GetListUserQuery
+    ) -> Result<PaginatedResult<UserModel>, AppError> {
+        let mut search_condition = Condition::all();
+
+        let roles = filter::parse_list::<String>("role", query.role.as_deref())?;
+        if !roles.is_empty() {
+            search_condition = search_condition.add(Column::Role.is_in(roles));
+        }
+
+        let page_query = PageQuery {
+            limit: query.limit,
+            page: query.page,
+            search: query.search,
+            order_by: query.order_by,
+            direction: query.direction,
+            ..Default::default()
+        };
+
+        self.base.find_with_pagination(page_query, search_condition).await
+    }
+
+    async fn update(&self, id: i32, user: ActiveModel) -> Result<UserModel, AppError> {
+        self.base.update(id, user).await
+    }
+}
@@ -0,0 +1,3 @@
+pub mod retention_module;
+pub mod retention_job;
+pub mod v1;
\ No newline at end of file
@@ -0,0 +1,35 @@
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::log::{error, info};
+
+use crate::module::retention::v1::retention_usecase::RetentionUsecase;
+use crate::pkg::config::Config;
+
+/// Purge expired soft-deleted rows every `RETENTION_INTERVAL_SECONDS`.
+/// Does nothing unless `RETENTION_DAYS` is set.
+pub fn spawn(retention_usecase: Arc<dyn RetentionUsecase>, config: Arc<Config>) {
+    let retention = config.retention.clone();
+    if retention.days <= 0 {
+        return;
+    }
+
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(retention.interval_seconds.max(60)));
+
+        loop {
+            interval.tick().await;
+
+            match retention_usecase.purge_expired(None, retention.dry_run, None).await {
+                Ok(report) => info!(
+                    "Retention {}: transactions {:?}, cashflows {:?}, products {:?} (deleted before {})",
+                    if report.dry_run { "dry run" } else { "purge" },
+                    report.transactions,
+                    report.cashflows,
+                    report.products,
+                    report.cutoff,
+                ),
+                Err(err) => error!("Retention purge failed: {:?}", err),
+            }
+        }
+    });
+}
@@ -0,0 +1,7 @@
+use axum::Router;
+
+use crate::module::retention::v1::retention_handler;
+
+pub fn configure() -> Router {
+	Router::new().nest("/api/v1/retention", retention_handler::routes())
+}
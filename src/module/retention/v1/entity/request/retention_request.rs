@@ -0,0 +1,9 @@
+use validator::{Validate};
+use serde::{Serialize, Deserialize};
+
+#[derive(Debug, Serialize, Deserialize, Validate)]
+pub struct RetentionQuery {
+    // Overrides RETENTION_DAYS
+    #[validate(range(min = 1, message = "Days must be at least 1"))]
+    pub days: Option<i64>,
+}
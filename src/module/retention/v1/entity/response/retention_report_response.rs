@@ -0,0 +1,14 @@
+use serde::{Serialize, Deserialize};
+use chrono::{Utc, DateTime};
+
+use crate::module::repository::soft_delete_repository::PurgeSummary;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RetentionReportResponse {
+    pub dry_run: bool,
+    pub retention_days: i64,
+    pub cutoff: DateTime<Utc>,
+    pub transactions: PurgeSummary,
+    pub cashflows: PurgeSummary,
+    pub products: PurgeSummary,
+}
@@ -0,0 +1,3 @@
+pub mod entity;
+pub mod retention_usecase;
+pub mod retention_handler;
\ No newline at end of file
@@ -0,0 +1,62 @@
+use axum::{
+	extract::{Extension, Query}, 
+    http::StatusCode, 
+    response::IntoResponse, 
+    routing::{get, post}, 
+    Json, 
+    Router
+};
+use serde_json::json;
+use validator::Validate;
+use std::sync::Arc;
+
+use crate::module::retention::v1::entity::request::retention_request::RetentionQuery;
+use crate::pkg::auth::{AuthUser, ROLE_OWNER};
+use crate::pkg::custom_error::AppError;
+use crate::pkg::response::ApiResponse;
+use crate::di::AppModule;
+
+
+pub fn routes() -> Router {
+	Router::new()
+		.route("/report", get(retention_report))
+		.route("/purge", post(purge_expired))
+}
+
+async fn retention_report(
+    Extension(app_module): Extension<Arc<AppModule>>,
+    auth_user: AuthUser,
+    Query(query): Query<RetentionQuery>,
+) -> Result<impl IntoResponse, AppError> {
+    auth_user.require_role(&[ROLE_OWNER])?;
+
+    query.validate().map_err(|e| {
+        AppError::BadRequest(e.to_string())
+    })?;
+
+    let report = app_module.retention_usecase.purge_expired(query.days, true, Some(auth_user.id)).await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse::success("Retention report generated successfully", Some(json!(report)), None)),
+    ))
+}
+
+async fn purge_expired(
+    Extension(app_module): Extension<Arc<AppModule>>,
+    auth_user: AuthUser,
+    Query(query): Query<RetentionQuery>,
+) -> Result<impl IntoResponse, AppError> {
+    auth_user.require_role(&[ROLE_OWNER])?;
+
+    query.validate().map_err(|e| {
+        AppError::BadRequest(e.to_string())
+    })?;
+
+    let report = app_module.retention_usecase.purge_expired(query.days, false, Some(auth_user.id)).await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse::success("Expired records purged successfully", Some(json!(report)), None)),
+    ))
+}
@@ -0,0 +1,77 @@
+use async_trait::async_trait;
+use crate::module::retention::v1::entity::response::retention_report_response::RetentionReportResponse;
+use crate::module::repository::product_repository::ProductRepository;
+use crate::module::repository::cashflow_repository::CashflowRepository;
+use crate::module::repository::transaction_repository::TransactionRepository;
+use crate::module::repository::audit_log_repository::{AuditLogRepository, NewAuditLog};
+use crate::pkg::audit::{ENTITY_RETENTION, ACTION_PURGE};
+use crate::pkg::config::Config;
+use crate::pkg::custom_error::AppError;
+use std::sync::Arc;
+use chrono::{Duration, Utc};
+use serde_json::json;
+
+#[async_trait]
+pub trait RetentionUsecase: Send + Sync {
+    async fn purge_expired(&self, days: Option<i64>, dry_run: bool, actor_id: Option<i32>) -> Result<RetentionReportResponse, AppError>;
+}
+
+pub struct RetentionUsecaseImpl {
+    product_repository: Arc<dyn ProductRepository>,
+    cashflow_repository: Arc<dyn CashflowRepository>,
+    transaction_repository: Arc<dyn TransactionRepository>,
+    audit_log_repository: Arc<dyn AuditLogRepository>,
+    config: Arc<Config>,
+}
+
+impl RetentionUsecaseImpl {
+    pub fn new(
+        product_repository: Arc<dyn ProductRepository>,
+        cashflow_repository: Arc<dyn CashflowRepository>,
+        transaction_repository: Arc<dyn TransactionRepository>,
+        audit_log_repository: Arc<dyn AuditLogRepository>,
+        config: Arc<Config>,
+    ) -> Self {
+        Self { product_repository, cashflow_repository, transaction_repository, audit_log_repository, config }
+    }
+}
+
+#[async_trait]
+impl RetentionUsecase for RetentionUsecaseImpl {
+    async fn purge_expired(&self, days: Option<i64>, dry_run: bool, actor_id: Option<i32>) -> Result<RetentionReportResponse, AppError> {
+        let retention_days = days.unwrap_or(self.config.retention.days);
+        if retention_days <= 0 {
+            return Err(AppError::BadRequest("Retention is disabled, set RETENTION_DAYS or pass days".to_string()));
+        }
+
+        let cutoff = Utc::now() - Duration::days(retention_days);
+
+        // Transactions go first so products they referenced become purgeable
+        let transactions = self.transaction_repository.purge_deleted_before(cutoff, dry_run).await?;
+        let cashflows = self.cashflow_repository.purge_deleted_before(cutoff, dry_run).await?;
+        let products = self.product_repository.purge_deleted_before(cutoff, dry_run).await?;
+
+        let report = RetentionReportResponse {
+            dry_run,
+            retention_days,
+            cutoff,
+            transactions,
+            cashflows,
+            products,
+        };
+
+        // Hard deletes leave no rows behind, so the run itself is the trace
+        if !dry_run {
+            self.audit_log_repository.record(NewAuditLog {
+                actor_id,
+                entity: ENTITY_RETENTION,
+                entity_id: 0,
+                action: ACTION_PURGE,
+                before: None,
+                after: Some(json!(report)),
+            }).await?;
+        }
+
+        Ok(report)
+    }
+}
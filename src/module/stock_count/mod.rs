@@ -0,0 +1,2 @@
+pub mod stock_count_module;
+pub mod v1;
\ No newline at end of file
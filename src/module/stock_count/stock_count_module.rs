@@ -0,0 +1,7 @@
+use axum::Router;
+
+use crate::module::stock_count::v1::stock_count_handler;
+
+pub fn configure() -> Router {
+	Router::new().nest("/api/v1/stock-count", stock_count_handler::routes())
+}
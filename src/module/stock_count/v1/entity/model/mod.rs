@@ -0,0 +1,2 @@
+pub mod stock_count;
+pub mod stock_count_item;
\ No newline at end of file
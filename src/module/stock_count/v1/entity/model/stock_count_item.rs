@@ -0,0 +1,38 @@
+use sea_orm::entity::prelude::*;
+use serde::{Serialize, Deserialize};
+use chrono::{Utc, DateTime};
+
+/// Counted base quantity of a product or variant next to the stock the system expected at that moment
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "stock_count_items")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = true)]
+    pub id: i32,
+    pub stock_count_id: i32,
+    pub product_id: i32,
+    pub variant_id: Option<i32>,
+    pub expected_qty: Decimal,
+    pub counted_qty: Decimal,
+    // HPP per base unit when counted, used to value the variance
+    pub hpp_amount: i32,
+    pub counted_by: Option<i32>,
+    pub counted_at: Option<DateTime<Utc>>,
+}
+
+impl Model {
+    /// Counted minus expected; negative is shrinkage
+    pub fn variance_qty(&self) -> Decimal {
+        self.counted_qty - self.expected_qty
+    }
+}
+
+#[derive(Copy, Clone, Debug, EnumIter)]
+pub enum Relation {}
+
+impl RelationTrait for Relation {
+    fn def(&self) -> RelationDef {
+        unimplemented!()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
\ No newline at end of file
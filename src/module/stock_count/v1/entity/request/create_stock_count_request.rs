@@ -0,0 +1,8 @@
+use validator::{Validate};
+use serde::{Serialize, Deserialize};
+
+#[derive(Debug, Serialize, Deserialize, Validate)]
+pub struct CreateStockCountRequest {
+    #[validate(length(min = 1, max = 500, message = "Note must be between 1 and 500 characters"))]
+    pub note: Option<String>,
+}
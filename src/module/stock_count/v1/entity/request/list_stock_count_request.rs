@@ -0,0 +1,24 @@
+use validator::{Validate};
+use serde::{Serialize, Deserialize};
+
+#[derive(Debug, Serialize, Deserialize, Validate)]
+pub struct GetListStockCountQuery {
+    pub limit: Option<String>,
+
+    pub page: Option<String>,
+
+    pub order_by: Option<String>,
+
+    pub direction: Option<String>,
+
+    // Comma separated statuses
+    pub status: Option<String>,
+
+    // YYYY-MM-DD (interpreted in `timezone`) or RFC 3339
+    pub created_from: Option<String>,
+
+    pub created_to: Option<String>,
+
+    // UTC offset such as +07:00, defaults to UTC
+    pub timezone: Option<String>,
+}
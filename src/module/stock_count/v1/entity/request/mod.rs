@@ -0,0 +1,3 @@
+pub mod create_stock_count_request;
+pub mod submit_count_request;
+pub mod list_stock_count_request;
\ No newline at end of file
@@ -0,0 +1,27 @@
+use rust_decimal::Decimal;
+use validator::{Validate};
+use serde::{Serialize, Deserialize};
+
+use crate::pkg::quantity::validate_non_negative_qty;
+
+// Lines already counted for the same item are replaced
+#[derive(Debug, Serialize, Deserialize, Validate)]
+pub struct SubmitCountRequest {
+    #[validate(length(min = 1, max = 500, message = "Between 1 and 500 items per submission"))]
+    #[validate]
+    pub items: Vec<CountItemRequest>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Validate)]
+pub struct CountItemRequest {
+    #[validate(range(min = 1, message = "Product ID must be positive"))]
+    pub product_id: i32,
+
+    // Required when the product has variants
+    #[validate(range(min = 1, message = "Variant ID must be positive"))]
+    pub variant_id: Option<i32>,
+
+    // In the product's base unit
+    #[validate(custom = "validate_non_negative_qty")]
+    pub counted_qty: Decimal,
+}
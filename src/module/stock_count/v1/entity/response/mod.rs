@@ -0,0 +1,2 @@
+pub mod stock_count_response;
+pub mod variance_report_response;
\ No newline at end of file
@@ -0,0 +1,69 @@
+use rust_decimal::Decimal;
+use serde::{Serialize, Deserialize};
+use chrono::{Utc, DateTime};
+
+use crate::pkg::quantity;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct StockCountItemResponse {
+    pub id: i32,
+    pub product_id: i32,
+    pub variant_id: Option<i32>,
+    pub expected_qty: Decimal,
+    pub counted_qty: Decimal,
+    pub variance_qty: Decimal,
+    pub hpp_amount: i32,
+    // `variance_qty` valued at `hpp_amount`
+    pub variance_amount: i64,
+    pub counted_by: Option<i32>,
+    pub counted_at: Option<DateTime<Utc>>,
+}
+
+impl From<crate::module::stock_count::v1::entity::model::stock_count_item::Model> for StockCountItemResponse {
+    fn from(model: crate::module::stock_count::v1::entity::model::stock_count_item::Model) -> Self {
+        let variance_qty = model.variance_qty();
+        Self {
+            id: model.id,
+            product_id: model.product_id,
+            variant_id: model.variant_id,
+            expected_qty: model.expected_qty,
+            counted_qty: model.counted_qty,
+            variance_qty,
+            hpp_amount: model.hpp_amount,
+            variance_amount: quantity::line_amount(i64::from(model.hpp_amount), variance_qty),
+            counted_by: model.counted_by,
+            counted_at: model.counted_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct StockCountResponse {
+    pub id: i32,
+    pub status: String,
+    pub note: Option<String>,
+    pub items: Vec<StockCountItemResponse>,
+    pub created_by: Option<i32>,
+    pub approved_by: Option<i32>,
+    pub approved_at: Option<DateTime<Utc>>,
+    pub cancelled_at: Option<DateTime<Utc>>,
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl From<crate::module::stock_count::v1::entity::model::stock_count::Model> for StockCountResponse {
+    fn from(model: crate::module::stock_count::v1::entity::model::stock_count::Model) -> Self {
+        Self {
+            id: model.id,
+            status: model.status,
+            note: model.note,
+            items: Vec::new(),
+            created_by: model.created_by,
+            approved_by: model.approved_by,
+            approved_at: model.approved_at,
+            cancelled_at: model.cancelled_at,
+            created_at: model.created_at,
+            updated_at: model.updated_at,
+        }
+    }
+}
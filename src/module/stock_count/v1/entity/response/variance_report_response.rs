@@ -0,0 +1,29 @@
+use rust_decimal::Decimal;
+use serde::{Serialize, Deserialize};
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct VarianceLineResponse {
+    pub product_id: i32,
+    pub product_name: String,
+    pub variant_id: Option<i32>,
+    pub variant_name: Option<String>,
+    pub expected_qty: Decimal,
+    pub counted_qty: Decimal,
+    pub variance_qty: Decimal,
+    pub hpp_amount: i32,
+    pub variance_amount: i64,
+}
+
+/// Lines whose count differs from the expected stock, valued at HPP
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct VarianceReportResponse {
+    pub stock_count_id: i32,
+    pub status: String,
+    pub counted_items: usize,
+    pub lines: Vec<VarianceLineResponse>,
+    // Value of missing stock, as a positive amount
+    pub shortage_amount: i64,
+    pub surplus_amount: i64,
+    // Surplus minus shortage
+    pub net_amount: i64,
+}
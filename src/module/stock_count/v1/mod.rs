@@ -0,0 +1,3 @@
+pub mod entity;
+pub mod stock_count_usecase;
+pub mod stock_count_handler;
\ No newline at end of file
@@ -0,0 +1,152 @@
+use axum::{
+	extract::{Extension, OriginalUri, Path, Query}, 
+    http::StatusCode, 
+    response::IntoResponse, 
+    routing::{get, post, put, delete}, 
+    Json, 
+    Router
+};
+use serde_json::json;
+use validator::Validate;
+use std::sync::Arc;
+
+use crate::module::stock_count::v1::entity::{
+    request::{
+        create_stock_count_request::CreateStockCountRequest,
+        submit_count_request::SubmitCountRequest,
+        list_stock_count_request::GetListStockCountQuery,
+    },
+};
+use crate::pkg::auth::{AuthUser, ROLE_OWNER, ROLE_SUPERVISOR};
+use crate::pkg::custom_error::AppError;
+use crate::pkg::response::ApiResponse;
+use crate::di::AppModule;
+
+
+pub fn routes() -> Router {
+	Router::new()
+		.route("/", post(create_stock_count))
+		.route("/", get(list_stock_counts))
+		.route("/:id", get(get_stock_count))
+		.route("/:id", delete(delete_stock_count))
+		.route("/:id/items", put(submit_counts))
+		.route("/:id/variance", get(variance_report))
+		.route("/:id/approve", post(approve_stock_count))
+		.route("/:id/cancel", post(cancel_stock_count))
+}
+
+async fn create_stock_count(
+    Extension(app_module): Extension<Arc<AppModule>>,
+    auth_user: Option<AuthUser>,
+    Json(payload): Json<CreateStockCountRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    payload.validate().map_err(|e| {
+        AppError::BadRequest(e.to_string())
+    })?;
+
+    let stock_count = app_module.stock_count_usecase.create_stock_count(payload, auth_user.map(|user| user.id)).await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(ApiResponse::success("Stock count created successfully", Some(json!(stock_count)), None)),
+    ))
+}
+
+async fn get_stock_count(
+    Extension(app_module): Extension<Arc<AppModule>>,
+    Path(id): Path<i32>,
+) -> Result<impl IntoResponse, AppError> {
+    let stock_count = app_module.stock_count_usecase.get_stock_count(id).await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse::success("Stock count retrieved successfully", Some(json!(stock_count)), None)),
+    ))
+}
+
+async fn list_stock_counts(
+    Extension(app_module): Extension<Arc<AppModule>>,
+    OriginalUri(uri): OriginalUri,
+    Query(query): Query<GetListStockCountQuery>,
+) -> Result<impl IntoResponse, AppError> {
+    let stock_counts = app_module.stock_count_usecase.list_stock_counts(query).await?;
+
+    Ok((
+        StatusCode::OK,
+        stock_counts.headers(&uri),
+        Json(ApiResponse::success("Stock counts retrieved successfully", Some(json!(stock_counts.data)), Some(json!(stock_counts.meta)))),
+    ))
+}
+
+async fn submit_counts(
+    Extension(app_module): Extension<Arc<AppModule>>,
+    auth_user: Option<AuthUser>,
+    Path(id): Path<i32>,
+    Json(payload): Json<SubmitCountRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    payload.validate().map_err(|e| {
+        AppError::BadRequest(e.to_string())
+    })?;
+
+    let stock_count = app_module.stock_count_usecase.submit_counts(id, payload, auth_user.map(|user| user.id)).await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse::success("Counts submitted successfully", Some(json!(stock_count)), None)),
+    ))
+}
+
+async fn variance_report(
+    Extension(app_module): Extension<Arc<AppModule>>,
+    Path(id): Path<i32>,
+) -> Result<impl IntoResponse, AppError> {
+    let report = app_module.stock_count_usecase.variance_report(id).await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse::success("Variance report retrieved successfully", Some(json!(report)), None)),
+    ))
+}
+
+async fn approve_stock_count(
+    Extension(app_module): Extension<Arc<AppModule>>,
+    auth_user: AuthUser,
+    Path(id): Path<i32>,
+) -> Result<impl IntoResponse, AppError> {
+    auth_user.require_role(&[ROLE_OWNER, ROLE_SUPERVISOR])?;
+
+    let stock_count = app_module.stock_count_usecase.approve_stock_count(id, Some(auth_user.id)).await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse::success("Stock count approved successfully", Some(json!(stock_count)), None)),
+    ))
+}
+
+async fn cancel_stock_count(
+    Extension(app_module): Extension<Arc<AppModule>>,
+    auth_user: AuthUser,
+    Path(id): Path<i32>,
+) -> Result<impl IntoResponse, AppError> {
+    auth_user.require_role(&[ROLE_OWNER, ROLE_SUPERVISOR])?;
+
+    let stock_count = app_module.stock_count_usecase.cancel_stock_count(id, Some(auth_user.id)).await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse::success("Stock count cancelled successfully", Some(json!(stock_count)), None)),
+    ))
+}
+
+async fn delete_stock_count(
+    Extension(app_module): Extension<Arc<AppModule>>,
+    auth_user: Option<AuthUser>,
+    Path(id): Path<i32>,
+) -> Result<impl IntoResponse, AppError> {
+    app_module.stock_count_usecase.delete_stock_count(id, auth_user.map(|user| user.id)).await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse::success("Stock count deleted successfully", Some(json!({})), None)),
+    ))
+}
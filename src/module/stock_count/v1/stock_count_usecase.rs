@@ -0,0 +1,388 @@
+use async_trait::async_trait;
+use crate::module::stock_count::v1::entity::{
+    request::{
+        create_stock_count_request::CreateStockCountRequest,
+        submit_count_request::SubmitCountRequest,
+        list_stock_count_request::GetListStockCountQuery,
+    },
+    response::{
+        stock_count_response::{StockCountResponse, StockCountItemResponse},
+        variance_report_response::{VarianceReportResponse, VarianceLineResponse},
+    },
+    model::stock_count::{ActiveModel, Model as StockCountModel, STATUS_OPEN, STATUS_APPROVED, STATUS_CANCELLED},
+};
+use crate::module::inventory::v1::entity::model::stock_movement::{MOVEMENT_COUNT, REFERENCE_STOCK_COUNT};
+use crate::module::repository::stock_count_repository::StockCountRepository;
+use crate::module::repository::stock_count_item_repository::{StockCountItemRepository, NewStockCountItem};
+use crate::module::repository::product_repository::ProductRepository;
+use crate::module::repository::product_variant_repository::ProductVariantRepository;
+use crate::module::repository::recipe_item_repository::RecipeItemRepository;
+use crate::module::repository::bundle_item_repository::BundleItemRepository;
+use crate::module::repository::stock_repository::{StockRepository, NewStockMovement};
+use crate::module::repository::audit_log_repository::{AuditLogRepository, NewAuditLog};
+use crate::module::product::v1::product_usecase::ProductUsecase;
+use crate::pkg::audit::{ENTITY_STOCK_COUNT, ACTION_CREATE, ACTION_UPDATE, ACTION_DELETE};
+use crate::pkg::custom_error::AppError;
+use crate::pkg::db;
+use crate::pkg::pagination::Paginated;
+use crate::pkg::quantity;
+use sea_orm::{DatabaseTransaction, Set};
+use serde_json::json;
+use std::sync::Arc;
+use chrono::Utc;
+
+#[async_trait]
+pub trait StockCountUsecase: Send + Sync {
+    async fn create_stock_count(&self, request: CreateStockCountRequest, actor_id: Option<i32>) -> Result<StockCountResponse, AppError>;
+    async fn get_stock_count(&self, id: i32) -> Result<StockCountResponse, AppError>;
+    async fn list_stock_counts(&self, query: GetListStockCountQuery) -> Result<Paginated<StockCountResponse>, AppError>;
+    async fn submit_counts(&self, id: i32, request: SubmitCountRequest, actor_id: Option<i32>) -> Result<StockCountResponse, AppError>;
+    async fn variance_report(&self, id: i32) -> Result<VarianceReportResponse, AppError>;
+    async fn approve_stock_count(&self, id: i32, actor_id: Option<i32>) -> Result<StockCountResponse, AppError>;
+    async fn cancel_stock_count(&self, id: i32, actor_id: Option<i32>) -> Result<StockCountResponse, AppError>;
+    async fn delete_stock_count(&self, id: i32, actor_id: Option<i32>) -> Result<(), AppError>;
+}
+
+pub struct StockCountUsecaseImpl {
+    stock_count_repository: Arc<dyn StockCountRepository>,
+    stock_count_item_repository: Arc<dyn StockCountItemRepository>,
+    product_repository: Arc<dyn ProductRepository>,
+    product_variant_repository: Arc<dyn ProductVariantRepository>,
+    recipe_item_repository: Arc<dyn RecipeItemRepository>,
+    bundle_item_repository: Arc<dyn BundleItemRepository>,
+    stock_repository: Arc<dyn StockRepository>,
+    product_usecase: Arc<dyn ProductUsecase>,
+    audit_log_repository: Arc<dyn AuditLogRepository>,
+}
+
+impl StockCountUsecaseImpl {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        stock_count_repository: Arc<dyn StockCountRepository>,
+        stock_count_item_repository: Arc<dyn StockCountItemRepository>,
+        product_repository: Arc<dyn ProductRepository>,
+        product_variant_repository: Arc<dyn ProductVariantRepository>,
+        recipe_item_repository: Arc<dyn RecipeItemRepository>,
+        bundle_item_repository: Arc<dyn BundleItemRepository>,
+        stock_repository: Arc<dyn StockRepository>,
+        product_usecase: Arc<dyn ProductUsecase>,
+        audit_log_repository: Arc<dyn AuditLogRepository>,
+    ) -> Self {
+        Self {
+            stock_count_repository,
+            stock_count_item_repository,
+            product_repository,
+            product_variant_repository,
+            recipe_item_repository,
+            bundle_item_repository,
+            stock_repository,
+            product_usecase,
+            audit_log_repository,
+        }
+    }
+
+    async fn find_count(&self, id: i32) -> Result<StockCountModel, AppError> {
+        self.stock_count_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Stock count with id {} not found", id)))
+    }
+
+    /// Fill in `items` of each session
+    async fn attach_items(&self, stock_counts: &mut [StockCountResponse]) -> Result<(), AppError> {
+        let stock_count_ids: Vec<i32> = stock_counts.iter().map(|stock_count| stock_count.id).collect();
+        let items = self.stock_count_item_repository.find_by_counts(&stock_count_ids).await?;
+
+        for stock_count in stock_counts.iter_mut() {
+            stock_count.items = items
+                .iter()
+                .filter(|item| item.stock_count_id == stock_count.id)
+                .cloned()
+                .map(StockCountItemResponse::from)
+                .collect();
+        }
+
+        Ok(())
+    }
+
+    async fn response_with_items(&self, stock_count: StockCountModel) -> Result<StockCountResponse, AppError> {
+        let mut response = StockCountResponse::from(stock_count);
+        self.attach_items(std::slice::from_mut(&mut response)).await?;
+        Ok(response)
+    }
+
+    /// Move the session from open to `to`, recording the change
+    async fn close(
+        &self,
+        txn: &DatabaseTransaction,
+        existing_count: StockCountModel,
+        to: &str,
+        mut stock_count_active_model: ActiveModel,
+        actor_id: Option<i32>,
+    ) -> Result<StockCountModel, AppError> {
+        let id = existing_count.id;
+        stock_count_active_model.status = Set(to.to_string());
+
+        let updated_count = self.stock_count_repository
+            .claim_status(txn, id, STATUS_OPEN, stock_count_active_model)
+            .await?
+            .ok_or_else(|| AppError::BadRequest("Stock count is no longer open".to_string()))?;
+
+        self.audit_log_repository.record_in(txn, NewAuditLog {
+            actor_id,
+            entity: ENTITY_STOCK_COUNT,
+            entity_id: id,
+            action: ACTION_UPDATE,
+            before: Some(json!(existing_count)),
+            after: Some(json!(updated_count)),
+        }).await?;
+
+        Ok(updated_count)
+    }
+}
+
+#[async_trait]
+impl StockCountUsecase for StockCountUsecaseImpl {
+    async fn create_stock_count(&self, request: CreateStockCountRequest, actor_id: Option<i32>) -> Result<StockCountResponse, AppError> {
+        // Create active model
+        let stock_count_active_model = ActiveModel {
+            status: Set(STATUS_OPEN.to_string()),
+            note: Set(request.note),
+            created_by: Set(actor_id),
+            created_at: Set(Some(Utc::now())),
+            updated_at: Set(Some(Utc::now())),
+            deleted_at: Set(None),
+            ..Default::default()
+        };
+
+        let created_count = self.stock_count_repository.create(stock_count_active_model).await?;
+
+        self.audit_log_repository.record(NewAuditLog {
+            actor_id,
+            entity: ENTITY_STOCK_COUNT,
+            entity_id: created_count.id,
+            action: ACTION_CREATE,
+            before: None,
+            after: Some(json!(created_count)),
+        }).await?;
+
+        Ok(StockCountResponse::from(created_count))
+    }
+
+    async fn get_stock_count(&self, id: i32) -> Result<StockCountResponse, AppError> {
+        let stock_count = self.find_count(id).await?;
+        self.response_with_items(stock_count).await
+    }
+
+    async fn list_stock_counts(&self, query: GetListStockCountQuery) -> Result<Paginated<StockCountResponse>, AppError> {
+        let paginated_result = self.stock_count_repository.find_with_pagination(query).await?;
+        let mut stock_counts = Paginated::from(paginated_result);
+        self.attach_items(&mut stock_counts.data).await?;
+        Ok(stock_counts)
+    }
+
+    async fn submit_counts(&self, id: i32, request: SubmitCountRequest, actor_id: Option<i32>) -> Result<StockCountResponse, AppError> {
+        let stock_count = self.find_count(id).await?;
+
+        if stock_count.status != STATUS_OPEN {
+            return Err(AppError::BadRequest("Counts can only be submitted while the stock count is open".to_string()));
+        }
+
+        let mut items: Vec<NewStockCountItem> = Vec::with_capacity(request.items.len());
+        for item in request.items {
+            if items.iter().any(|counted| counted.product_id == item.product_id && counted.variant_id == item.variant_id) {
+                return Err(AppError::BadRequest(format!("Product {} is counted more than once", item.product_id)));
+            }
+
+            let product = self.product_repository
+                .find_by_id(item.product_id)
+                .await?
+                .ok_or_else(|| AppError::BadRequest(format!("Product with id {} not found", item.product_id)))?;
+
+            // Recipe and bundle products hold no stock of their own
+            if !self.recipe_item_repository.find_by_product(product.id).await?.is_empty()
+                || !self.bundle_item_repository.find_by_bundle(product.id).await?.is_empty()
+            {
+                return Err(AppError::BadRequest(format!("Product {} is a recipe or bundle; count its parts instead", product.id)));
+            }
+
+            // Products with variants keep stock on the variants only
+            let (expected_qty, hpp_amount) = match item.variant_id {
+                Some(variant_id) => {
+                    let variant = self.product_variant_repository
+                        .find_by_id(variant_id)
+                        .await?
+                        .filter(|variant| variant.product_id == product.id)
+                        .ok_or_else(|| AppError::BadRequest(format!("Variant with id {} not found for product {}", variant_id, product.id)))?;
+                    (variant.stock, variant.hpp_amount)
+                }
+                None => {
+                    if self.product_variant_repository.count_by_product(product.id).await? > 0 {
+                        return Err(AppError::BadRequest("variant_id is required for products with variants".to_string()));
+                    }
+                    (product.stock, product.hpp_amount)
+                }
+            };
+
+            items.push(NewStockCountItem {
+                product_id: product.id,
+                variant_id: item.variant_id,
+                expected_qty,
+                counted_qty: item.counted_qty,
+                hpp_amount,
+                counted_by: actor_id,
+            });
+        }
+
+        self.stock_count_item_repository.save(id, items).await?;
+
+        self.response_with_items(stock_count).await
+    }
+
+    async fn variance_report(&self, id: i32) -> Result<VarianceReportResponse, AppError> {
+        let stock_count = self.find_count(id).await?;
+        let items = self.stock_count_item_repository.find_by_counts(&[id]).await?;
+
+        let mut report = VarianceReportResponse {
+            stock_count_id: id,
+            status: stock_count.status,
+            counted_items: items.len(),
+            lines: Vec::new(),
+            shortage_amount: 0,
+            surplus_amount: 0,
+            net_amount: 0,
+        };
+
+        for item in items.into_iter().filter(|item| !item.variance_qty().is_zero()) {
+            let product_name = match self.product_repository.find_by_id(item.product_id).await? {
+                Some(product) => product.name,
+                None => self.product_repository
+                    .find_deleted_by_id(item.product_id)
+                    .await?
+                    .map(|product| product.name)
+                    .unwrap_or_default(),
+            };
+            let variant_name = match item.variant_id {
+                Some(variant_id) => self.product_variant_repository
+                    .find_by_id(variant_id)
+                    .await?
+                    .map(|variant| variant.name),
+                None => None,
+            };
+
+            let variance_qty = item.variance_qty();
+            let variance_amount = quantity::line_amount(i64::from(item.hpp_amount), variance_qty);
+            if variance_amount < 0 {
+                report.shortage_amount -= variance_amount;
+            } else {
+                report.surplus_amount += variance_amount;
+            }
+
+            report.lines.push(VarianceLineResponse {
+                product_id: item.product_id,
+                product_name,
+                variant_id: item.variant_id,
+                variant_name,
+                expected_qty: item.expected_qty,
+                counted_qty: item.counted_qty,
+                variance_qty,
+                hpp_amount: item.hpp_amount,
+                variance_amount,
+            });
+        }
+
+        report.net_amount = report.surplus_amount - report.shortage_amount;
+
+        Ok(report)
+    }
+
+    async fn approve_stock_count(&self, id: i32, actor_id: Option<i32>) -> Result<StockCountResponse, AppError> {
+        let existing_count = self.find_count(id).await?;
+
+        let items = self.stock_count_item_repository.find_by_counts(&[id]).await?;
+        if items.is_empty() {
+            return Err(AppError::BadRequest("Stock count has no counted items".to_string()));
+        }
+
+        let stock_count_active_model = ActiveModel {
+            id: Set(id),
+            approved_by: Set(actor_id),
+            approved_at: Set(Some(Utc::now())),
+            ..Default::default()
+        };
+
+        let txn = self.stock_count_repository.begin().await?;
+        let approved_count = self.close(&txn, existing_count, STATUS_APPROVED, stock_count_active_model, actor_id).await?;
+
+        // Post the variance against the stock expected at count time, so sales made since are kept
+        let mut shortages = Vec::new();
+        for item in items.iter().filter(|item| !item.variance_qty().is_zero()) {
+            let movement = self.stock_repository.record_in(&txn, NewStockMovement {
+                product_id: item.product_id,
+                variant_id: item.variant_id,
+                qty: item.variance_qty(),
+                movement_type: MOVEMENT_COUNT,
+                reference_type: Some(REFERENCE_STOCK_COUNT),
+                reference_id: Some(id),
+                note: None,
+                created_by: actor_id,
+                cost_amount: None,
+                lot: None,
+                lot_id: None,
+                restores: None,
+            }).await?;
+
+            if movement.qty.is_sign_negative() {
+                shortages.push((item.product_id, item.variant_id));
+            }
+        }
+
+        db::commit(txn).await?;
+
+        for (product_id, variant_id) in shortages {
+            self.product_usecase.revalue_cost(product_id, variant_id, None, actor_id).await?;
+        }
+
+        self.response_with_items(approved_count).await
+    }
+
+    async fn cancel_stock_count(&self, id: i32, actor_id: Option<i32>) -> Result<StockCountResponse, AppError> {
+        let existing_count = self.find_count(id).await?;
+
+        let stock_count_active_model = ActiveModel {
+            id: Set(id),
+            cancelled_at: Set(Some(Utc::now())),
+            ..Default::default()
+        };
+
+        let txn = self.stock_count_repository.begin().await?;
+        let cancelled_count = self.close(&txn, existing_count, STATUS_CANCELLED, stock_count_active_model, actor_id).await?;
+        db::commit(txn).await?;
+
+        self.response_with_items(cancelled_count).await
+    }
+
+    async fn delete_stock_count(&self, id: i32, actor_id: Option<i32>) -> Result<(), AppError> {
+        // Check if stock count exists
+        let existing_count = self.find_count(id).await?;
+
+        // Approved counts are part of the stock ledger
+        if existing_count.status == STATUS_APPROVED {
+            return Err(AppError::BadRequest("Approved stock counts cannot be deleted".to_string()));
+        }
+
+        // Soft delete
+        let deleted_count = self.stock_count_repository.delete(id).await?;
+
+        self.audit_log_repository.record(NewAuditLog {
+            actor_id,
+            entity: ENTITY_STOCK_COUNT,
+            entity_id: id,
+            action: ACTION_DELETE,
+            before: Some(json!(existing_count)),
+            after: Some(json!(deleted_count)),
+        }).await?;
+
+        Ok(())
+    }
+}
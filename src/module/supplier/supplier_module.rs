@@ -0,0 +1,7 @@
+use axum::Router;
+
+use crate::module::supplier::v1::supplier_handler;
+
+pub fn configure() -> Router {
+	Router::new().nest("/api/v1/supplier", supplier_handler::routes())
+}
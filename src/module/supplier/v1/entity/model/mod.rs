@@ -0,0 +1 @@
+pub mod supplier;
\ No newline at end of file
@@ -0,0 +1,24 @@
+use validator::{Validate};
+use serde::{Serialize, Deserialize};
+
+#[derive(Debug, Serialize, Deserialize, Validate)]
+pub struct CreateSupplierRequest {
+    #[validate(length(min = 1, max = 255, message = "Name must be between 1 and 255 characters"))]
+    pub name: String,
+
+    #[validate(length(min = 1, max = 30, message = "Phone must be between 1 and 30 characters"))]
+    pub phone: Option<String>,
+
+    #[validate(email(message = "Email must be a valid address"))]
+    pub email: Option<String>,
+
+    #[validate(length(min = 1, max = 500, message = "Address must be between 1 and 500 characters"))]
+    pub address: Option<String>,
+
+    #[validate(length(min = 1, max = 500, message = "Note must be between 1 and 500 characters"))]
+    pub note: Option<String>,
+
+    // Days from ordering until the goods arrive, defaults to 0
+    #[validate(range(min = 0, max = 365, message = "Lead time must be between 0 and 365 days"))]
+    pub lead_time_days: Option<i32>,
+}
@@ -0,0 +1,3 @@
+pub mod create_supplier_request;
+pub mod update_supplier_request;
+pub mod list_supplier_request;
\ No newline at end of file
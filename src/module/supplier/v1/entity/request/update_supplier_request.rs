@@ -0,0 +1,30 @@
+use validator::{Validate};
+use serde::{Serialize, Deserialize};
+
+use crate::pkg::helper::deserialize_some;
+
+// Omitted fields are kept, null clears the optional ones
+#[derive(Debug, Serialize, Deserialize, Validate)]
+pub struct UpdateSupplierRequest {
+    #[validate(length(min = 1, max = 255, message = "Name must be between 1 and 255 characters"))]
+    pub name: Option<String>,
+
+    #[validate(length(min = 1, max = 30, message = "Phone must be between 1 and 30 characters"))]
+    #[serde(default, deserialize_with = "deserialize_some")]
+    pub phone: Option<Option<String>>,
+
+    #[validate(email(message = "Email must be a valid address"))]
+    #[serde(default, deserialize_with = "deserialize_some")]
+    pub email: Option<Option<String>>,
+
+    #[validate(length(min = 1, max = 500, message = "Address must be between 1 and 500 characters"))]
+    #[serde(default, deserialize_with = "deserialize_some")]
+    pub address: Option<Option<String>>,
+
+    #[validate(length(min = 1, max = 500, message = "Note must be between 1 and 500 characters"))]
+    #[serde(default, deserialize_with = "deserialize_some")]
+    pub note: Option<Option<String>>,
+
+    #[validate(range(min = 0, max = 365, message = "Lead time must be between 0 and 365 days"))]
+    pub lead_time_days: Option<i32>,
+}
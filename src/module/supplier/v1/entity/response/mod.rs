@@ -0,0 +1 @@
+pub mod supplier_response;
\ No newline at end of file
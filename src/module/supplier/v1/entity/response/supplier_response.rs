@@ -0,0 +1,31 @@
+use serde::{Serialize, Deserialize};
+use chrono::{Utc, DateTime};
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SupplierResponse {
+    pub id: i32,
+    pub name: String,
+    pub phone: Option<String>,
+    pub email: Option<String>,
+    pub address: Option<String>,
+    pub note: Option<String>,
+    pub lead_time_days: i32,
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl From<crate::module::supplier::v1::entity::model::supplier::Model> for SupplierResponse {
+    fn from(model: crate::module::supplier::v1::entity::model::supplier::Model) -> Self {
+        Self {
+            id: model.id,
+            name: model.name,
+            phone: model.phone,
+            email: model.email,
+            address: model.address,
+            note: model.note,
+            lead_time_days: model.lead_time_days,
+            created_at: model.created_at,
+            updated_at: model.updated_at,
+        }
+    }
+}
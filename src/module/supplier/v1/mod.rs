@@ -0,0 +1,3 @@
+pub mod entity;
+pub mod supplier_usecase;
+pub mod supplier_handler;
\ No newline at end of file
@@ -0,0 +1,107 @@
+use axum::{
+	extract::{Extension, OriginalUri, Path, Query}, 
+    http::StatusCode, 
+    response::IntoResponse, 
+    routing::{get, post, put, delete}, 
+    Json, 
+    Router
+};
+use serde_json::json;
+use validator::Validate;
+use std::sync::Arc;
+
+use crate::module::supplier::v1::entity::{
+    request::{
+        create_supplier_request::CreateSupplierRequest,
+        update_supplier_request::UpdateSupplierRequest,
+        list_supplier_request::GetListSupplierQuery,
+    },
+};
+use crate::pkg::auth::AuthUser;
+use crate::pkg::custom_error::AppError;
+use crate::pkg::response::ApiResponse;
+use crate::di::AppModule;
+
+
+pub fn routes() -> Router {
+	Router::new()
+		.route("/", post(create_supplier))
+		.route("/", get(list_suppliers))
+		.route("/:id", get(get_supplier))
+		.route("/:id", put(update_supplier))
+		.route("/:id", delete(delete_supplier))
+}
+
+async fn create_supplier(
+    Extension(app_module): Extension<Arc<AppModule>>,
+    auth_user: Option<AuthUser>,
+    Json(payload): Json<CreateSupplierRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    payload.validate().map_err(|e| {
+        AppError::BadRequest(e.to_string())
+    })?;
+
+    let supplier = app_module.supplier_usecase.create_supplier(payload, auth_user.map(|user| user.id)).await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(ApiResponse::success("Supplier created successfully", Some(json!(supplier)), None)),
+    ))
+}
+
+async fn get_supplier(
+    Extension(app_module): Extension<Arc<AppModule>>,
+    Path(id): Path<i32>,
+) -> Result<impl IntoResponse, AppError> {
+    let supplier = app_module.supplier_usecase.get_supplier(id).await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse::success("Supplier retrieved successfully", Some(json!(supplier)), None)),
+    ))
+}
+
+async fn list_suppliers(
+    Extension(app_module): Extension<Arc<AppModule>>,
+    OriginalUri(uri): OriginalUri,
+    Query(query): Query<GetListSupplierQuery>,
+) -> Result<impl IntoResponse, AppError> {
+    let suppliers = app_module.supplier_usecase.list_suppliers(query).await?;
+
+    Ok((
+        StatusCode::OK,
+        suppliers.headers(&uri),
+        Json(ApiResponse::success("Suppliers retrieved successfully", Some(json!(suppliers.data)), Some(json!(suppliers.meta)))),
+    ))
+}
+
+async fn update_supplier(
+    Extension(app_module): Extension<Arc<AppModule>>,
+    auth_user: Option<AuthUser>,
+    Path(id): Path<i32>,
+    Json(payload): Json<UpdateSupplierRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    payload.validate().map_err(|e| {
+        AppError::BadRequest(e.to_string())
+    })?;
+
+    let supplier = app_module.supplier_usecase.update_supplier(id, payload, auth_user.map(|user| user.id)).await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse::success("Supplier updated successfully", Some(json!(supplier)), None)),
+    ))
+}
+
+async fn delete_supplier(
+    Extension(app_module): Extension<Arc<AppModule>>,
+    auth_user: Option<AuthUser>,
+    Path(id): Path<i32>,
+) -> Result<impl IntoResponse, AppError> {
+    app_module.supplier_usecase.delete_supplier(id, auth_user.map(|user| user.id)).await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse::success("Supplier deleted successfully", Some(json!({})), None)),
+    ))
+}
@@ -0,0 +1,174 @@
+use async_trait::async_trait;
+use crate::module::supplier::v1::entity::{
+    request::{
+        create_supplier_request::CreateSupplierRequest,
+        update_supplier_request::UpdateSupplierRequest,
+        list_supplier_request::GetListSupplierQuery,
+    },
+    response::supplier_response::SupplierResponse,
+    model::supplier::{ActiveModel, Model as SupplierModel},
+};
+use crate::module::repository::supplier_repository::SupplierRepository;
+use crate::module::repository::purchase_order_repository::PurchaseOrderRepository;
+use crate::module::repository::audit_log_repository::{AuditLogRepository, NewAuditLog};
+use crate::pkg::audit::{ENTITY_SUPPLIER, ACTION_CREATE, ACTION_UPDATE, ACTION_DELETE};
+use crate::pkg::custom_error::AppError;
+use crate::pkg::pagination::Paginated;
+use sea_orm::Set;
+use serde_json::json;
+use std::sync::Arc;
+use chrono::Utc;
+
+#[async_trait]
+pub trait SupplierUsecase: Send + Sync {
+    async fn create_supplier(&self, request: CreateSupplierRequest, actor_id: Option<i32>) -> Result<SupplierResponse, AppError>;
+    async fn get_supplier(&self, id: i32) -> Result<SupplierResponse, AppError>;
+    async fn list_suppliers(&self, query: GetListSupplierQuery) -> Result<Paginated<SupplierResponse>, AppError>;
+    async fn update_supplier(&self, id: i32, request: UpdateSupplierRequest, actor_id: Option<i32>) -> Result<SupplierResponse, AppError>;
+    async fn delete_supplier(&self, id: i32, actor_id: Option<i32>) -> Result<(), AppError>;
+}
+
+pub struct SupplierUsecaseImpl {
+    supplier_repository: Arc<dyn SupplierRepository>,
+    purchase_order_repository: Arc<dyn PurchaseOrderRepository>,
+    audit_log_repository: Arc<dyn AuditLogRepository>,
+}
+
+impl SupplierUsecaseImpl {
+    pub fn new(
+        supplier_repository: Arc<dyn SupplierRepository>,
+        purchase_order_repository: Arc<dyn PurchaseOrderRepository>,
+        audit_log_repository: Arc<dyn AuditLogRepository>,
+    ) -> Self {
+        Self { supplier_repository, purchase_order_repository, audit_log_repository }
+    }
+
+    async fn find_supplier(&self, id: i32) -> Result<SupplierModel, AppError> {
+        self.supplier_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Supplier with id {} not found", id)))
+    }
+}
+
+#[async_trait]
+impl SupplierUsecase for SupplierUsecaseImpl {
+    async fn create_supplier(&self, request: CreateSupplierRequest, actor_id: Option<i32>) -> Result<SupplierResponse, AppError> {
+        // Check if supplier with same name already exists
+        if self.supplier_repository.find_by_name(&request.name).await?.is_some() {
+            return Err(AppError::BadRequest("Supplier with this name already exists".to_string()));
+        }
+
+        // Create active model
+        let supplier_active_model = ActiveModel {
+            name: Set(request.name),
+            phone: Set(request.phone),
+            email: Set(request.email),
+            address: Set(request.address),
+            note: Set(request.note),
+            lead_time_days: Set(request.lead_time_days.unwrap_or_default()),
+            created_at: Set(Some(Utc::now())),
+            updated_at: Set(Some(Utc::now())),
+            deleted_at: Set(None),
+            ..Default::default()
+        };
+
+        let created_supplier = self.supplier_repository.create(supplier_active_model).await?;
+
+        self.audit_log_repository.record(NewAuditLog {
+            actor_id,
+            entity: ENTITY_SUPPLIER,
+            entity_id: created_supplier.id,
+            action: ACTION_CREATE,
+            before: None,
+            after: Some(json!(created_supplier)),
+        }).await?;
+
+        Ok(SupplierResponse::from(created_supplier))
+    }
+
+    async fn get_supplier(&self, id: i32) -> Result<SupplierResponse, AppError> {
+        let supplier = self.find_supplier(id).await?;
+        Ok(SupplierResponse::from(supplier))
+    }
+
+    async fn list_suppliers(&self, query: GetListSupplierQuery) -> Result<Paginated<SupplierResponse>, AppError> {
+        let paginated_result = self.supplier_repository.find_with_pagination(query).await?;
+        Ok(Paginated::from(paginated_result))
+    }
+
+    async fn update_supplier(&self, id: i32, request: UpdateSupplierRequest, actor_id: Option<i32>) -> Result<SupplierResponse, AppError> {
+        // Check if supplier exists
+        let existing_supplier = self.find_supplier(id).await?;
+
+        // Check if new name conflicts with another supplier
+        if let Some(name) = &request.name {
+            if let Some(other) = self.supplier_repository.find_by_name(name).await? {
+                if other.id != id {
+                    return Err(AppError::BadRequest("Supplier with this name already exists".to_string()));
+                }
+            }
+        }
+
+        // Create active model with only changed fields
+        let mut supplier_active_model = ActiveModel {
+            id: Set(id),
+            ..Default::default()
+        };
+
+        if let Some(name) = request.name {
+            supplier_active_model.name = Set(name);
+        }
+        if let Some(phone) = request.phone {
+            supplier_active_model.phone = Set(phone);
+        }
+        if let Some(email) = request.email {
+            supplier_active_model.email = Set(email);
+        }
+        if let Some(address) = request.address {
+            supplier_active_model.address = Set(address);
+        }
+        if let Some(note) = request.note {
+            supplier_active_model.note = Set(note);
+        }
+        if let Some(lead_time_days) = request.lead_time_days {
+            supplier_active_model.lead_time_days = Set(lead_time_days);
+        }
+
+        let updated_supplier = self.supplier_repository.update(id, supplier_active_model).await?;
+
+        self.audit_log_repository.record(NewAuditLog {
+            actor_id,
+            entity: ENTITY_SUPPLIER,
+            entity_id: id,
+            action: ACTION_UPDATE,
+            before: Some(json!(existing_supplier)),
+            after: Some(json!(updated_supplier)),
+        }).await?;
+
+        Ok(SupplierResponse::from(updated_supplier))
+    }
+
+    async fn delete_supplier(&self, id: i32, actor_id: Option<i32>) -> Result<(), AppError> {
+        // Check if supplier exists
+        let existing_supplier = self.find_supplier(id).await?;
+
+        if self.purchase_order_repository.count_open_by_supplier(id).await? > 0 {
+            return Err(AppError::BadRequest("Supplier still has open or unpaid purchase orders".to_string()));
+        }
+
+        // Soft delete
+        let deleted_supplier = self.supplier_repository.delete(id).await?;
+
+        self.audit_log_repository.record(NewAuditLog {
+            actor_id,
+            entity: ENTITY_SUPPLIER,
+            entity_id: id,
+            action: ACTION_DELETE,
+            before: Some(json!(existing_supplier)),
+            after: Some(json!(deleted_supplier)),
+        }).await?;
+
+        Ok(())
+    }
+}
@@ -1 +1,5 @@
-pub mod transaction;
\ No newline at end of file
+pub mod transaction;
+pub mod transaction_modifier;
+pub mod transaction_allocation;
+pub mod transaction_return;
+pub mod transaction_payment;
\ No newline at end of file
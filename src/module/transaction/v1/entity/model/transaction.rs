@@ -3,15 +3,35 @@ use serde::{Serialize, Deserialize};
 use chrono::{Utc, DateTime};
 use crate::module::product::v1::entity::model::product;
 
+pub const STATUS_COMPLETED: &str = "completed";
+pub const STATUS_VOIDED: &str = "voided";
+
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
 #[sea_orm(table_name = "transactions")]
 pub struct Model {
     #[sea_orm(primary_key, auto_increment = true)]
     pub id: i32,
     pub product_id: i32,
+    pub variant_id: Option<i32>,
     pub hpp_amount: i32,
     pub selling_amount: i32,
-    pub qty: i32,
+    // In `unit`, which holds `unit_factor` of the product's base unit; prices are per `unit`
+    pub qty: Decimal,
+    pub unit: String,
+    pub unit_factor: Decimal,
+    pub modifier_hpp_amount: i32,
+    pub modifier_selling_amount: i32,
+    // Taken off the line's amount, by `promotion_id` or by hand when that is unset
+    pub discount_amount: i64,
+    pub promotion_id: Option<i32>,
+    // Part of `qty` handed back through returns
+    pub returned_qty: Decimal,
+    // Sales are never edited; a mistake is voided with a reason and a supervisor's approval
+    pub status: String,
+    pub void_reason: Option<String>,
+    pub voided_by: Option<i32>,
+    pub void_approved_by: Option<i32>,
+    pub voided_at: Option<DateTime<Utc>>,
     pub created_at: Option<DateTime<Utc>>,
     pub updated_at: Option<DateTime<Utc>>,
     pub deleted_at: Option<DateTime<Utc>>,
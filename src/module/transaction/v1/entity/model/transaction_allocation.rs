@@ -0,0 +1,52 @@
+use sea_orm::entity::prelude::*;
+use serde::{Serialize, Deserialize};
+use chrono::{Utc, DateTime};
+use crate::module::product::v1::entity::model::product;
+use crate::module::transaction::v1::entity::model::transaction;
+
+/// Share of a bundle sale line credited to one component
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "transaction_allocations")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = true)]
+    pub id: i32,
+    pub transaction_id: i32,
+    pub product_id: i32,
+    pub variant_id: Option<i32>,
+    pub qty: Decimal,
+    // Net of the component's share of the discount
+    pub revenue_amount: i64,
+    pub discount_amount: i64,
+    pub hpp_amount: i64,
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "crate::module::transaction::v1::entity::model::transaction::Entity",
+        from = "Column::TransactionId",
+        to = "crate::module::transaction::v1::entity::model::transaction::Column::Id"
+    )]
+    Transaction,
+    #[sea_orm(
+        belongs_to = "crate::module::product::v1::entity::model::product::Entity",
+        from = "Column::ProductId",
+        to = "crate::module::product::v1::entity::model::product::Column::Id"
+    )]
+    Product,
+}
+
+impl Related<transaction::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Transaction.def()
+    }
+}
+
+impl Related<product::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Product.def()
+    }
+}
\ No newline at end of file
@@ -0,0 +1,39 @@
+use sea_orm::entity::prelude::*;
+use serde::{Serialize, Deserialize};
+use chrono::{Utc, DateTime};
+
+pub const PAYMENT_CASH: &str = "cash";
+pub const PAYMENT_CARD: &str = "card";
+pub const PAYMENT_BANK_TRANSFER: &str = "bank_transfer";
+pub const PAYMENT_E_WALLET: &str = "e_wallet";
+pub const PAYMENT_QRIS: &str = "qris";
+pub const PAYMENT_METHODS: &[&str] = &[PAYMENT_CASH, PAYMENT_CARD, PAYMENT_BANK_TRANSFER, PAYMENT_E_WALLET, PAYMENT_QRIS];
+
+/// Part of a sale's total settled by one payment method
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "transaction_payments")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = true)]
+    pub id: i32,
+    pub transaction_id: i32,
+    pub method: String,
+    // Counted toward the sale total
+    pub amount: i64,
+    // Cash handed over, `amount` plus `change_amount`
+    pub tendered_amount: Option<i64>,
+    pub change_amount: i64,
+    // Card approval code, transfer or e-wallet reference
+    pub reference: Option<String>,
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter)]
+pub enum Relation {}
+
+impl RelationTrait for Relation {
+    fn def(&self) -> RelationDef {
+        unimplemented!()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
\ No newline at end of file
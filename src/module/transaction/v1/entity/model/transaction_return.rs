@@ -0,0 +1,40 @@
+use sea_orm::entity::prelude::*;
+use serde::{Serialize, Deserialize};
+use chrono::{Utc, DateTime};
+use crate::module::transaction::v1::entity::model::transaction;
+
+/// `qty` of a sale line handed back, in the line's unit, with the revenue and HPP it reverses
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "transaction_returns")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = true)]
+    pub id: i32,
+    pub transaction_id: i32,
+    pub qty: Decimal,
+    pub reason: String,
+    // Net of `discount_amount`, the part of the sale's discount the return gives back
+    pub refund_amount: i64,
+    pub discount_amount: i64,
+    pub hpp_amount: i64,
+    pub cashflow_id: Option<i32>,
+    pub created_by: Option<i32>,
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "crate::module::transaction::v1::entity::model::transaction::Entity",
+        from = "Column::TransactionId",
+        to = "crate::module::transaction::v1::entity::model::transaction::Column::Id"
+    )]
+    Transaction,
+}
+
+impl Related<transaction::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Transaction.def()
+    }
+}
\ No newline at end of file
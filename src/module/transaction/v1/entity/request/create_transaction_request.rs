@@ -1,11 +1,89 @@
-use validator::{Validate};
+use rust_decimal::Decimal;
+use validator::{Validate, ValidationError};
 use serde::{Serialize, Deserialize};
 
+use crate::module::transaction::v1::entity::model::transaction_payment::PAYMENT_METHODS;
+use crate::pkg::discount::{KIND_FIXED, KIND_PERCENTAGE};
+use crate::pkg::quantity::validate_positive_qty;
+
 #[derive(Debug, Serialize, Deserialize, Validate)]
 pub struct CreateTransactionRequest {
     #[validate(range(min = 1, message = "Product ID must be positive"))]
     pub product_id: i32,
 
-    #[validate(range(min = 1, message = "Quantity must be positive"))]
-    pub qty: i32,
+    // Required when the product has variants
+    #[validate(range(min = 1, message = "Variant ID must be positive"))]
+    pub variant_id: Option<i32>,
+
+    // In `unit`
+    #[validate(custom = "validate_positive_qty")]
+    pub qty: Decimal,
+
+    // One of the product's units, defaults to its base unit
+    #[validate(length(min = 1, max = 20, message = "Unit must be between 1 and 20 characters"))]
+    pub unit: Option<String>,
+
+    // Chosen modifiers, validated against the product's modifier groups
+    pub modifier_ids: Option<Vec<i32>>,
+
+    // Manual discount by a supervisor or owner; otherwise the best running promotion applies
+    #[validate]
+    pub discount: Option<DiscountRequest>,
+
+    // UTC offset of the till such as +07:00, for promotion dates and happy hours; defaults to UTC
+    pub timezone: Option<String>,
+
+    // How the sale is settled, each line adding up to the total; a single exact cash payment when left out
+    #[serde(default)]
+    #[validate]
+    pub payments: Vec<PaymentRequest>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Validate)]
+pub struct PaymentRequest {
+    #[validate(custom = "validate_payment_method")]
+    pub method: String,
+
+    // Part of the total settled by this method; cash may leave it out to cover whatever the others do not
+    #[validate(range(min = 1, message = "Amount must be positive"))]
+    pub amount: Option<i64>,
+
+    // Cash handed over, the change is worked out from it
+    #[validate(range(min = 1, message = "Tendered amount must be positive"))]
+    pub tendered_amount: Option<i64>,
+
+    // Card approval code, transfer or e-wallet reference
+    #[validate(length(min = 1, max = 100, message = "Reference must be between 1 and 100 characters"))]
+    pub reference: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Validate)]
+pub struct DiscountRequest {
+    // "percentage" or "fixed"
+    #[validate(custom = "validate_discount_kind")]
+    pub kind: String,
+
+    // Percent for percentage, rupiah off each unit for fixed
+    #[validate(range(min = 1, message = "Value must be positive"))]
+    pub value: i64,
+}
+
+fn validate_discount_kind(kind: &str) -> Result<(), ValidationError> {
+    if [KIND_PERCENTAGE, KIND_FIXED].contains(&kind) {
+        Ok(())
+    } else {
+        let mut error = ValidationError::new("kind");
+        error.message = Some(format!("Discount kind must be one of: {}, {}", KIND_PERCENTAGE, KIND_FIXED).into());
+        Err(error)
+    }
+}
+
+fn validate_payment_method(method: &str) -> Result<(), ValidationError> {
+    if PAYMENT_METHODS.contains(&method) {
+        Ok(())
+    } else {
+        let mut error = ValidationError::new("method");
+        error.message = Some(format!("Payment method must be one of: {}", PAYMENT_METHODS.join(", ")).into());
+        Err(error)
+    }
 }
@@ -13,5 +13,28 @@ pub struct GetListTransactionQuery {
 
     pub direction: Option<String>,
 
+    // Switches to keyset pagination; pass an empty value for the first page
+    pub cursor: Option<String>,
+
+    // "false" skips the COUNT query
+    pub include_total: Option<String>,
+
+    // Comma separated product ids
     pub product_id: Option<String>,
+
+    // Comma separated statuses: completed, voided
+    pub status: Option<String>,
+
+    // YYYY-MM-DD (interpreted in `timezone`) or RFC 3339
+    pub created_from: Option<String>,
+
+    pub created_to: Option<String>,
+
+    // UTC offset such as +07:00, defaults to UTC
+    pub timezone: Option<String>,
+
+    // Bounds on the line total after modifiers and discount
+    pub min_amount: Option<String>,
+
+    pub max_amount: Option<String>,
 }
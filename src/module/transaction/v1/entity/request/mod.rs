@@ -1,4 +1,6 @@
 pub mod create_transaction_request;
-pub mod update_transaction_request;
 pub mod get_transaction_request;
 pub mod list_transaction_request;
+pub mod return_transaction_request;
+pub mod void_transaction_request;
+pub mod qris_request;
@@ -0,0 +1,7 @@
+use serde::{Serialize, Deserialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GetQrisQuery {
+    // "png" (default) or "svg"
+    pub format: Option<String>,
+}
@@ -0,0 +1,15 @@
+use rust_decimal::Decimal;
+use validator::{Validate};
+use serde::{Serialize, Deserialize};
+
+use crate::pkg::quantity::validate_positive_qty;
+
+#[derive(Debug, Serialize, Deserialize, Validate)]
+pub struct ReturnTransactionRequest {
+    // In the transaction's unit, up to what has not been returned yet
+    #[validate(custom = "validate_positive_qty")]
+    pub qty: Decimal,
+
+    #[validate(length(min = 1, max = 255, message = "Reason must be between 1 and 255 characters"))]
+    pub reason: String,
+}
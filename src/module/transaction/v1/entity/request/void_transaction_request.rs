@@ -0,0 +1,15 @@
+use validator::{Validate};
+use serde::{Serialize, Deserialize};
+
+#[derive(Debug, Serialize, Deserialize, Validate)]
+pub struct VoidTransactionRequest {
+    #[validate(length(min = 1, max = 255, message = "Reason must be between 1 and 255 characters"))]
+    pub reason: String,
+
+    // Approving supervisor or owner, required unless the caller is one
+    #[validate(length(min = 1, max = 50, message = "Supervisor username must be between 1 and 50 characters"))]
+    pub supervisor_username: Option<String>,
+
+    #[validate(length(min = 1, max = 8, message = "Supervisor PIN must be between 1 and 8 characters"))]
+    pub supervisor_pin: Option<String>,
+}
@@ -1,2 +1,2 @@
 pub mod transaction_response;
-pub mod list_transaction_response;
+pub mod transaction_return_response;
@@ -1,3 +1,4 @@
+use rust_decimal::Decimal;
 use serde::{Serialize, Deserialize};
 use chrono::{Utc, DateTime};
 
@@ -5,11 +6,32 @@ use chrono::{Utc, DateTime};
 pub struct TransactionResponse {
     pub id: i32,
     pub product_id: i32,
+    pub variant_id: Option<i32>,
     pub hpp_amount: i32,
     pub selling_amount: i32,
-    pub qty: i32,
+    pub qty: Decimal,
+    pub unit: String,
+    pub unit_factor: Decimal,
+    pub returned_qty: Decimal,
+    pub status: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub void_reason: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub voided_by: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub void_approved_by: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub voided_at: Option<DateTime<Utc>>,
+    pub modifier_hpp_amount: i32,
+    pub modifier_selling_amount: i32,
+    pub discount_amount: i64,
+    pub promotion_id: Option<i32>,
+    pub modifiers: Vec<TransactionModifierResponse>,
+    pub payments: Vec<TransactionPaymentResponse>,
     pub created_at: Option<DateTime<Utc>>,
     pub updated_at: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deleted_at: Option<DateTime<Utc>>,
 }
 
 impl From<crate::module::transaction::v1::entity::model::transaction::Model> for TransactionResponse {
@@ -17,11 +39,77 @@ impl From<crate::module::transaction::v1::entity::model::transaction::Model> for
         Self {
             id: model.id,
             product_id: model.product_id,
+            variant_id: model.variant_id,
             hpp_amount: model.hpp_amount,
             selling_amount: model.selling_amount,
             qty: model.qty,
+            unit: model.unit,
+            unit_factor: model.unit_factor,
+            returned_qty: model.returned_qty,
+            status: model.status,
+            void_reason: model.void_reason,
+            voided_by: model.voided_by,
+            void_approved_by: model.void_approved_by,
+            voided_at: model.voided_at,
+            modifier_hpp_amount: model.modifier_hpp_amount,
+            modifier_selling_amount: model.modifier_selling_amount,
+            discount_amount: model.discount_amount,
+            promotion_id: model.promotion_id,
+            modifiers: Vec::new(),
+            payments: Vec::new(),
             created_at: model.created_at,
             updated_at: model.updated_at,
+            deleted_at: model.deleted_at,
         }
     }
 }
+
+/// Modifier as it was priced when the line was sold
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TransactionModifierResponse {
+    pub modifier_id: Option<i32>,
+    pub group_id: Option<i32>,
+    pub name: String,
+    pub hpp_amount: i32,
+    pub selling_amount: i32,
+}
+
+impl From<crate::module::transaction::v1::entity::model::transaction_modifier::Model> for TransactionModifierResponse {
+    fn from(model: crate::module::transaction::v1::entity::model::transaction_modifier::Model) -> Self {
+        Self {
+            modifier_id: model.modifier_id,
+            group_id: model.group_id,
+            name: model.name,
+            hpp_amount: model.hpp_amount,
+            selling_amount: model.selling_amount,
+        }
+    }
+}
+
+/// One tender of the sale
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TransactionPaymentResponse {
+    pub method: String,
+    pub amount: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tendered_amount: Option<i64>,
+    pub change_amount: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reference: Option<String>,
+    // Dynamic QRIS string for the customer to scan, on QRIS payments of completed sales
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub qris_payload: Option<String>,
+}
+
+impl From<crate::module::transaction::v1::entity::model::transaction_payment::Model> for TransactionPaymentResponse {
+    fn from(model: crate::module::transaction::v1::entity::model::transaction_payment::Model) -> Self {
+        Self {
+            method: model.method,
+            amount: model.amount,
+            tendered_amount: model.tendered_amount,
+            change_amount: model.change_amount,
+            reference: model.reference,
+            qris_payload: None,
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,34 @@
+use rust_decimal::Decimal;
+use serde::{Serialize, Deserialize};
+use chrono::{Utc, DateTime};
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TransactionReturnResponse {
+    pub id: i32,
+    pub transaction_id: i32,
+    pub qty: Decimal,
+    pub reason: String,
+    pub refund_amount: i64,
+    pub discount_amount: i64,
+    pub hpp_amount: i64,
+    pub cashflow_id: Option<i32>,
+    pub created_by: Option<i32>,
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+impl From<crate::module::transaction::v1::entity::model::transaction_return::Model> for TransactionReturnResponse {
+    fn from(model: crate::module::transaction::v1::entity::model::transaction_return::Model) -> Self {
+        Self {
+            id: model.id,
+            transaction_id: model.transaction_id,
+            qty: model.qty,
+            reason: model.reason,
+            refund_amount: model.refund_amount,
+            discount_amount: model.discount_amount,
+            hpp_amount: model.hpp_amount,
+            cashflow_id: model.cashflow_id,
+            created_by: model.created_by,
+            created_at: model.created_at,
+        }
+    }
+}
@@ -1,8 +1,8 @@
 use axum::{
-	extract::{Extension, Path, Query}, 
-    http::StatusCode, 
+	extract::{Extension, OriginalUri, Path, Query}, 
+    http::{header, StatusCode}, 
     response::IntoResponse, 
-    routing::{get, post, put, delete}, 
+    routing::{get, post, delete}, 
     Json, 
     Router
 };
@@ -13,12 +13,16 @@ use std::sync::Arc;
 use crate::module::transaction::v1::entity::{
     request::{
         create_transaction_request::CreateTransactionRequest,
-        update_transaction_request::UpdateTransactionRequest,
         get_transaction_request::GetTransactionRequest,
         list_transaction_request::GetListTransactionQuery,
+        return_transaction_request::ReturnTransactionRequest,
+        void_transaction_request::VoidTransactionRequest,
+        qris_request::GetQrisQuery,
     },
 };
+use crate::pkg::auth::{AuthUser, ROLE_OWNER, ROLE_SUPERVISOR};
 use crate::pkg::custom_error::AppError;
+use crate::pkg::qris::{self, FORMAT_PNG};
 use crate::pkg::response::ApiResponse;
 use crate::di::AppModule;
 
@@ -28,19 +32,31 @@ pub fn routes() -> Router {
 		.route("/", post(create_transaction))
 		.route("/", get(list_transactions))
 		.route("/:id", get(get_transaction))
-		.route("/:id", put(update_transaction))
-		.route("/:id", delete(delete_transaction))
+		.route("/:id/void", post(void_transaction))
+		.route("/:id/qris", get(get_qris_code))
+		.route("/:id/returns", post(return_transaction))
+		.route("/:id/returns", get(list_returns))
+		.route("/trash/:id", delete(purge_transaction))
 }
 
 async fn create_transaction(
     Extension(app_module): Extension<Arc<AppModule>>,
+    auth_user: Option<AuthUser>,
     Json(payload): Json<CreateTransactionRequest>,
 ) -> Result<impl IntoResponse, AppError> {
     payload.validate().map_err(|e| {
-        AppError::BadRequest(e.to_string().replace("\n", "\n"))
+        AppError::BadRequest(e.to_string())
     })?;
 
-    let transaction = app_module.transaction_usecase.create_transaction(payload).await?;
+    // Selling below price by hand is up to a supervisor or owner
+    if payload.discount.is_some() {
+        auth_user
+            .as_ref()
+            .ok_or_else(|| AppError::AuthorizationError("A manual discount needs a supervisor or owner".to_string()))?
+            .require_role(&[ROLE_OWNER, ROLE_SUPERVISOR])?;
+    }
+
+    let transaction = app_module.transaction_usecase.create_transaction(payload, auth_user.map(|user| user.id)).await?;
 
     Ok((
         StatusCode::CREATED,
@@ -54,7 +70,7 @@ async fn get_transaction(
 ) -> Result<impl IntoResponse, AppError> {
     let request = GetTransactionRequest { id };
     request.validate().map_err(|e| {
-        AppError::BadRequest(e.to_string().replace("\n", "\n"))
+        AppError::BadRequest(e.to_string())
     })?;
 
     let transaction = app_module.transaction_usecase.get_transaction(request).await?;
@@ -67,41 +83,94 @@ async fn get_transaction(
 
 async fn list_transactions(
     Extension(app_module): Extension<Arc<AppModule>>,
+    OriginalUri(uri): OriginalUri,
     Query(query): Query<GetListTransactionQuery>,
 ) -> Result<impl IntoResponse, AppError> {
     let transactions = app_module.transaction_usecase.list_transactions(query).await?;
 
     Ok((
         StatusCode::OK,
-        Json(ApiResponse::success("Transactions retrieved successfully", Some(json!(transactions)), None)),
+        transactions.headers(&uri),
+        Json(ApiResponse::success("Transactions retrieved successfully", Some(json!(transactions.data)), Some(json!(transactions.meta)))),
     ))
 }
 
-async fn update_transaction(
+async fn void_transaction(
     Extension(app_module): Extension<Arc<AppModule>>,
+    auth_user: AuthUser,
     Path(id): Path<i32>,
-    Json(payload): Json<UpdateTransactionRequest>,
+    Json(payload): Json<VoidTransactionRequest>,
 ) -> Result<impl IntoResponse, AppError> {
     payload.validate().map_err(|e| {
-        AppError::BadRequest(e.to_string().replace("\n", "\n"))
+        AppError::BadRequest(e.to_string())
     })?;
 
-    let transaction = app_module.transaction_usecase.update_transaction(id, payload).await?;
+    let transaction = app_module.transaction_usecase.void_transaction(id, payload, &auth_user).await?;
 
     Ok((
         StatusCode::OK,
-        Json(ApiResponse::success("Transaction updated successfully", Some(json!(transaction)), None)),
+        Json(ApiResponse::success("Transaction voided successfully", Some(json!(transaction)), None)),
     ))
 }
 
-async fn delete_transaction(
+async fn get_qris_code(
     Extension(app_module): Extension<Arc<AppModule>>,
     Path(id): Path<i32>,
+    Query(query): Query<GetQrisQuery>,
 ) -> Result<impl IntoResponse, AppError> {
-    app_module.transaction_usecase.delete_transaction(id).await?;
+    let payload = app_module.transaction_usecase.qris_payload(id).await?;
+    let (content_type, image) = qris::render(&payload, query.format.as_deref().unwrap_or(FORMAT_PNG))?;
+
+    Ok((
+        StatusCode::OK,
+        [(header::CONTENT_TYPE, content_type)],
+        image,
+    ))
+}
+
+async fn return_transaction(
+    Extension(app_module): Extension<Arc<AppModule>>,
+    auth_user: AuthUser,
+    Path(id): Path<i32>,
+    Json(payload): Json<ReturnTransactionRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    auth_user.require_role(&[ROLE_OWNER, ROLE_SUPERVISOR])?;
+
+    payload.validate().map_err(|e| {
+        AppError::BadRequest(e.to_string())
+    })?;
+
+    let transaction_return = app_module.transaction_usecase.return_transaction(id, payload, Some(auth_user.id)).await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(ApiResponse::success("Return recorded successfully", Some(json!(transaction_return)), None)),
+    ))
+}
+
+async fn list_returns(
+    Extension(app_module): Extension<Arc<AppModule>>,
+    Path(id): Path<i32>,
+) -> Result<impl IntoResponse, AppError> {
+    let returns = app_module.transaction_usecase.list_returns(id).await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse::success("Returns retrieved successfully", Some(json!(returns)), None)),
+    ))
+}
+
+async fn purge_transaction(
+    Extension(app_module): Extension<Arc<AppModule>>,
+    auth_user: AuthUser,
+    Path(id): Path<i32>,
+) -> Result<impl IntoResponse, AppError> {
+    auth_user.require_role(&[ROLE_OWNER])?;
+
+    app_module.transaction_usecase.purge_transaction(id, Some(auth_user.id)).await?;
 
     Ok((
         StatusCode::OK,
-        Json(ApiResponse::success("Transaction deleted successfully", Some(json!({})), None)),
+        Json(ApiResponse::success("Transaction permanently deleted", Some(json!({})), None)),
     ))
 }
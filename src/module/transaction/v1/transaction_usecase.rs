@@ -1,70 +1,645 @@
 use async_trait::async_trait;
 use crate::module::transaction::v1::entity::{
     request::{
-        create_transaction_request::CreateTransactionRequest,
-        update_transaction_request::UpdateTransactionRequest,
+        create_transaction_request::{CreateTransactionRequest, DiscountRequest, PaymentRequest},
         get_transaction_request::GetTransactionRequest,
         list_transaction_request::GetListTransactionQuery,
+        return_transaction_request::ReturnTransactionRequest,
+        void_transaction_request::VoidTransactionRequest,
     },
     response::{
-        transaction_response::TransactionResponse,
-        list_transaction_response::ListTransactionResponse,
+        transaction_response::{TransactionResponse, TransactionModifierResponse, TransactionPaymentResponse},
+        transaction_return_response::TransactionReturnResponse,
     },
-    model::transaction::ActiveModel,
+    model::transaction::{ActiveModel, Model as TransactionModel, STATUS_COMPLETED, STATUS_VOIDED},
+    model::transaction_return::ActiveModel as TransactionReturnActiveModel,
+    model::transaction_payment::{PAYMENT_CASH, PAYMENT_QRIS},
 };
-use crate::module::repository::transaction_repository::TransactionRepository;
+use crate::module::cashflow::v1::entity::model::cashflow::{ActiveModel as CashflowActiveModel, CASHFLOW_OUT, RECAP_REFUND};
+use crate::module::repository::transaction_repository::{TransactionRepository, NewVoid};
+use crate::module::repository::transaction_return_repository::TransactionReturnRepository;
+use crate::module::repository::cashflow_repository::CashflowRepository;
+use crate::module::repository::user_repository::UserRepository;
 use crate::module::repository::product_repository::ProductRepository;
+use crate::module::repository::product_variant_repository::ProductVariantRepository;
+use crate::module::repository::stock_repository::{StockRepository, NewStockMovement};
+use crate::module::inventory::v1::entity::model::stock_movement::{
+    Model as StockMovementModel, MOVEMENT_SALE, MOVEMENT_SALE_REVERSAL, MOVEMENT_RETURN, REFERENCE_TRANSACTION, REFERENCE_TRANSACTION_RETURN,
+};
+use crate::module::product::v1::entity::model::product::Model as ProductModel;
+use crate::module::product::v1::entity::model::product_variant::Model as ProductVariantModel;
+use crate::module::repository::product_unit_repository::ProductUnitRepository;
+use crate::module::product::v1::product_usecase::ProductUsecase;
+use crate::module::repository::modifier_group_repository::ModifierGroupRepository;
+use crate::module::repository::modifier_repository::ModifierRepository;
+use crate::module::repository::transaction_modifier_repository::TransactionModifierRepository;
+use crate::module::repository::transaction_payment_repository::{TransactionPaymentRepository, NewTransactionPayment};
+use crate::module::repository::promotion_repository::PromotionRepository;
+use crate::module::repository::category_repository::CategoryRepository;
+use crate::module::repository::recipe_item_repository::RecipeItemRepository;
+use crate::module::repository::bundle_item_repository::BundleItemRepository;
+use crate::module::repository::transaction_allocation_repository::{TransactionAllocationRepository, NewTransactionAllocation};
+use crate::pkg::allocation;
+use crate::module::modifier::v1::entity::model::modifier::Model as ModifierModel;
+use crate::module::repository::audit_log_repository::{AuditLogRepository, NewAuditLog};
+use crate::pkg::audit::{ENTITY_TRANSACTION, ENTITY_TRANSACTION_RETURN, ACTION_CREATE, ACTION_PURGE, ACTION_VOID};
+use crate::pkg::auth::{AuthUser, ROLE_OWNER, ROLE_SUPERVISOR};
+use crate::pkg::bcrypt::verify_password;
+use crate::pkg::config::Config;
+use crate::pkg::costing::{self, COSTING_FIFO};
+use crate::pkg::db;
 use crate::pkg::custom_error::AppError;
-use sea_orm::{prelude::*, Set};
+use crate::pkg::discount::{self, KIND_PERCENTAGE};
+use crate::pkg::filter;
+use crate::pkg::pagination::Paginated;
+use crate::pkg::quantity;
+use crate::pkg::qris;
+use sea_orm::{prelude::*, DatabaseTransaction, Set};
+use serde_json::json;
 use std::sync::Arc;
-use chrono::Utc;
+use chrono::{FixedOffset, Utc};
+use tracing::log::error;
+
+/// Label tying a QRIS payment back to its sale
+fn bill_number(transaction_id: i32) -> String {
+    format!("TRX{}", transaction_id)
+}
 
 #[async_trait]
 pub trait TransactionUsecase: Send + Sync {
-    async fn create_transaction(&self, request: CreateTransactionRequest) -> Result<(), AppError>;
+    async fn create_transaction(&self, request: CreateTransactionRequest, actor_id: Option<i32>) -> Result<TransactionResponse, AppError>;
     async fn get_transaction(&self, request: GetTransactionRequest) -> Result<TransactionResponse, AppError>;
-    async fn list_transactions(&self, query: GetListTransactionQuery) -> Result<ListTransactionResponse, AppError>;
-    async fn update_transaction(&self, id: i32, request: UpdateTransactionRequest) -> Result<TransactionResponse, AppError>;
-    async fn delete_transaction(&self, id: i32) -> Result<(), AppError>;
+    async fn list_transactions(&self, query: GetListTransactionQuery) -> Result<Paginated<TransactionResponse>, AppError>;
+    async fn void_transaction(&self, id: i32, request: VoidTransactionRequest, actor: &AuthUser) -> Result<TransactionResponse, AppError>;
+    async fn purge_transaction(&self, id: i32, actor_id: Option<i32>) -> Result<(), AppError>;
+    async fn return_transaction(&self, id: i32, request: ReturnTransactionRequest, actor_id: Option<i32>) -> Result<TransactionReturnResponse, AppError>;
+    async fn list_returns(&self, id: i32) -> Result<Vec<TransactionReturnResponse>, AppError>;
+    async fn qris_payload(&self, id: i32) -> Result<String, AppError>;
 }
 
 pub struct TransactionUsecaseImpl {
     transaction_repository: Arc<dyn TransactionRepository>,
+    transaction_return_repository: Arc<dyn TransactionReturnRepository>,
     product_repository: Arc<dyn ProductRepository>,
+    product_variant_repository: Arc<dyn ProductVariantRepository>,
+    stock_repository: Arc<dyn StockRepository>,
+    modifier_group_repository: Arc<dyn ModifierGroupRepository>,
+    modifier_repository: Arc<dyn ModifierRepository>,
+    transaction_modifier_repository: Arc<dyn TransactionModifierRepository>,
+    transaction_payment_repository: Arc<dyn TransactionPaymentRepository>,
+    promotion_repository: Arc<dyn PromotionRepository>,
+    category_repository: Arc<dyn CategoryRepository>,
+    recipe_item_repository: Arc<dyn RecipeItemRepository>,
+    bundle_item_repository: Arc<dyn BundleItemRepository>,
+    transaction_allocation_repository: Arc<dyn TransactionAllocationRepository>,
+    product_unit_repository: Arc<dyn ProductUnitRepository>,
+    cashflow_repository: Arc<dyn CashflowRepository>,
+    user_repository: Arc<dyn UserRepository>,
+    product_usecase: Arc<dyn ProductUsecase>,
+    audit_log_repository: Arc<dyn AuditLogRepository>,
+    config: Arc<Config>,
 }
 
 impl TransactionUsecaseImpl {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         transaction_repository: Arc<dyn TransactionRepository>,
+        transaction_return_repository: Arc<dyn TransactionReturnRepository>,
         product_repository: Arc<dyn ProductRepository>,
+        product_variant_repository: Arc<dyn ProductVariantRepository>,
+        stock_repository: Arc<dyn StockRepository>,
+        modifier_group_repository: Arc<dyn ModifierGroupRepository>,
+        modifier_repository: Arc<dyn ModifierRepository>,
+        transaction_modifier_repository: Arc<dyn TransactionModifierRepository>,
+        transaction_payment_repository: Arc<dyn TransactionPaymentRepository>,
+        promotion_repository: Arc<dyn PromotionRepository>,
+        category_repository: Arc<dyn CategoryRepository>,
+        recipe_item_repository: Arc<dyn RecipeItemRepository>,
+        bundle_item_repository: Arc<dyn BundleItemRepository>,
+        transaction_allocation_repository: Arc<dyn TransactionAllocationRepository>,
+        product_unit_repository: Arc<dyn ProductUnitRepository>,
+        cashflow_repository: Arc<dyn CashflowRepository>,
+        user_repository: Arc<dyn UserRepository>,
+        product_usecase: Arc<dyn ProductUsecase>,
+        audit_log_repository: Arc<dyn AuditLogRepository>,
+        config: Arc<Config>,
     ) -> Self {
-        Self { transaction_repository, product_repository }
+        Self {
+            transaction_repository,
+            transaction_return_repository,
+            product_repository,
+            product_variant_repository,
+            stock_repository,
+            modifier_group_repository,
+            modifier_repository,
+            transaction_modifier_repository,
+            transaction_payment_repository,
+            promotion_repository,
+            category_repository,
+            recipe_item_repository,
+            bundle_item_repository,
+            transaction_allocation_repository,
+            product_unit_repository,
+            cashflow_repository,
+            user_repository,
+            product_usecase,
+            audit_log_repository,
+            config,
+        }
+    }
+
+    /// Live modifiers for `modifier_ids`, checked against the selection limits of every group on the product
+    async fn resolve_modifiers(&self, product_id: i32, modifier_ids: &[i32]) -> Result<Vec<ModifierModel>, AppError> {
+        let mut modifier_ids = modifier_ids.to_vec();
+        modifier_ids.sort_unstable();
+        modifier_ids.dedup();
+
+        let groups = self.modifier_group_repository.find_by_product(product_id).await?;
+        let modifiers = self.modifier_repository.find_by_ids(&modifier_ids).await?;
+
+        for modifier_id in &modifier_ids {
+            let modifier = modifiers
+                .iter()
+                .find(|modifier| modifier.id == *modifier_id)
+                .ok_or_else(|| AppError::BadRequest(format!("Modifier with id {} not found", modifier_id)))?;
+            if !groups.iter().any(|group| group.id == modifier.group_id) {
+                return Err(AppError::BadRequest(format!("Modifier {} is not available for product {}", modifier.name, product_id)));
+            }
+        }
+
+        for group in &groups {
+            let selected = modifiers.iter().filter(|modifier| modifier.group_id == group.id).count() as i32;
+            if selected < group.min_selections() {
+                return Err(AppError::BadRequest(format!("{} needs at least {} selection(s)", group.name, group.min_selections())));
+            }
+            if let Some(max_select) = group.max_select {
+                if selected > max_select {
+                    return Err(AppError::BadRequest(format!("{} allows at most {} selection(s)", group.name, max_select)));
+                }
+            }
+        }
+
+        Ok(modifiers)
+    }
+
+    /// Fill in `modifiers` and `payments` of each transaction
+    async fn attach_details(&self, transactions: &mut [TransactionResponse]) -> Result<(), AppError> {
+        let transaction_ids: Vec<i32> = transactions.iter().map(|transaction| transaction.id).collect();
+        let modifiers = self.transaction_modifier_repository.find_by_transactions(&transaction_ids).await?;
+        let payments = self.transaction_payment_repository.find_by_transactions(&transaction_ids).await?;
+
+        for transaction in transactions.iter_mut() {
+            transaction.modifiers = modifiers
+                .iter()
+                .filter(|modifier| modifier.transaction_id == transaction.id)
+                .cloned()
+                .map(TransactionModifierResponse::from)
+                .collect();
+            transaction.payments = payments
+                .iter()
+                .filter(|payment| payment.transaction_id == transaction.id)
+                .cloned()
+                .map(TransactionPaymentResponse::from)
+                .collect();
+
+            // Scannable only while the sale stands
+            if let (Some(merchant), STATUS_COMPLETED) = (&self.config.qris, transaction.status.as_str()) {
+                let bill_number = bill_number(transaction.id);
+                for payment in transaction.payments.iter_mut().filter(|payment| payment.method == PAYMENT_QRIS) {
+                    payment.qris_payload = Some(qris::payload(merchant, payment.amount, &bill_number));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn response_with_details(&self, transaction: TransactionModel) -> Result<TransactionResponse, AppError> {
+        let mut response = TransactionResponse::from(transaction);
+        self.attach_details(std::slice::from_mut(&mut response)).await?;
+        Ok(response)
+    }
+
+    /// Discount on `qty` units at `unit_price`: the manual one when given, otherwise whichever running
+    /// promotion for the product takes the most off
+    async fn resolve_discount(
+        &self,
+        product: &ProductModel,
+        unit_price: i64,
+        qty: Decimal,
+        manual: Option<DiscountRequest>,
+        timezone: FixedOffset,
+    ) -> Result<(i64, Option<i32>), AppError> {
+        if let Some(manual) = manual {
+            if manual.kind == KIND_PERCENTAGE && manual.value > 100 {
+                return Err(AppError::BadRequest("A percentage discount cannot exceed 100".to_string()));
+            }
+            return Ok((discount::amount(&manual.kind, manual.value, unit_price, qty), None));
+        }
+
+        let local = Utc::now().with_timezone(&timezone).naive_local();
+        let mut best: Option<(i64, i32)> = None;
+        for promotion in self.promotion_repository.find_active().await? {
+            if !promotion.is_running_at(local) {
+                continue;
+            }
+
+            // A category's promotion also covers the products of its subcategories
+            let category_ids = match promotion.category_id {
+                Some(category_id) => self.category_repository.find_descendant_ids(&[category_id]).await?,
+                None => Vec::new(),
+            };
+            if !promotion.covers(product.id, product.category_id, &category_ids) {
+                continue;
+            }
+
+            let discount_amount = promotion.discount(unit_price, qty);
+            if discount_amount > 0 && best.is_none_or(|(best_amount, _)| discount_amount > best_amount) {
+                best = Some((discount_amount, promotion.id));
+            }
+        }
+
+        Ok(best.map_or((0, None), |(discount_amount, promotion_id)| (discount_amount, Some(promotion_id))))
+    }
+
+    /// Tenders settling `total`: cash may leave its amount out to cover the rest, and only cash gives change
+    fn settle_payments(total: i64, payments: Vec<PaymentRequest>) -> Result<Vec<NewTransactionPayment>, AppError> {
+        // A free line leaves nothing to pay
+        if payments.is_empty() && total == 0 {
+            return Ok(Vec::new());
+        }
+        if payments.is_empty() {
+            return Ok(vec![NewTransactionPayment {
+                method: PAYMENT_CASH.to_string(),
+                amount: total,
+                tendered_amount: None,
+                change_amount: 0,
+                reference: None,
+            }]);
+        }
+
+        if payments.iter().filter(|payment| payment.amount.is_none()).count() > 1 {
+            return Err(AppError::BadRequest("Only one payment may leave its amount out".to_string()));
+        }
+        let covered: i64 = payments.iter().filter_map(|payment| payment.amount).sum();
+
+        let mut settled = Vec::with_capacity(payments.len());
+        for payment in payments {
+            let amount = match payment.amount {
+                Some(amount) => amount,
+                None if payment.method == PAYMENT_CASH => total - covered,
+                None => return Err(AppError::BadRequest(format!("Amount is required for {} payments", payment.method))),
+            };
+            if amount <= 0 {
+                return Err(AppError::BadRequest("The other payments already cover the total".to_string()));
+            }
+
+            let change_amount = match payment.tendered_amount {
+                Some(tendered_amount) if payment.method != PAYMENT_CASH => {
+                    if tendered_amount != amount {
+                        return Err(AppError::BadRequest(format!("Only cash payments can give change, not {}", payment.method)));
+                    }
+                    0
+                }
+                Some(tendered_amount) if tendered_amount < amount => {
+                    return Err(AppError::BadRequest(format!("Tendered cash of {} does not cover {}", tendered_amount, amount)));
+                }
+                Some(tendered_amount) => tendered_amount - amount,
+                None => 0,
+            };
+
+            settled.push(NewTransactionPayment {
+                method: payment.method,
+                amount,
+                tendered_amount: payment.tendered_amount,
+                change_amount,
+                reference: payment.reference,
+            });
+        }
+
+        let paid: i64 = settled.iter().map(|payment| payment.amount).sum();
+        if paid != total {
+            return Err(AppError::BadRequest(format!("Payments add up to {} but the sale total is {}", paid, total)));
+        }
+
+        Ok(settled)
+    }
+
+    /// The live variant sold, which is mandatory once the product has variants
+    async fn resolve_variant(&self, product_id: i32, variant_id: Option<i32>) -> Result<Option<ProductVariantModel>, AppError> {
+        match variant_id {
+            Some(variant_id) => {
+                let variant = self.product_variant_repository
+                    .find_by_id(variant_id)
+                    .await?
+                    .filter(|variant| variant.product_id == product_id)
+                    .ok_or_else(|| AppError::BadRequest(format!("Variant with id {} not found for product {}", variant_id, product_id)))?;
+                Ok(Some(variant))
+            }
+            None => {
+                if self.product_variant_repository.count_by_product(product_id).await? > 0 {
+                    return Err(AppError::BadRequest("variant_id is required for products with variants".to_string()));
+                }
+                Ok(None)
+            }
+        }
+    }
+
+    /// Unit price of the sale unit: the base price scaled by the unit's factor unless the unit has its own
+    /// selling price, which only applies to products without variants
+    async fn price_line(
+        &self,
+        product: &ProductModel,
+        variant: Option<&ProductVariantModel>,
+        unit: Option<&str>,
+    ) -> Result<(String, Decimal, i32, i32), AppError> {
+        let (hpp_amount, selling_amount) = match variant {
+            Some(variant) => (variant.hpp_amount, variant.selling_amount),
+            None => (product.hpp_amount, product.selling_amount),
+        };
+
+        let unit = match unit.filter(|unit| *unit != product.base_unit) {
+            Some(name) => self.product_unit_repository
+                .find_by_name(product.id, name)
+                .await?
+                .ok_or_else(|| AppError::BadRequest(format!("Unit {} is not configured for product {}", name, product.id)))?,
+            None => return Ok((product.base_unit.clone(), Decimal::ONE, hpp_amount, selling_amount)),
+        };
+
+        let scale = |amount: i32| {
+            i32::try_from(quantity::line_amount(i64::from(amount), unit.factor))
+                .map_err(|_| AppError::BadRequest(format!("Price per {} is too large", unit.name)))
+        };
+        let hpp_amount = scale(hpp_amount)?;
+        let selling_amount = match (variant, unit.selling_amount) {
+            (None, Some(selling_amount)) => selling_amount,
+            _ => scale(selling_amount)?,
+        };
+
+        Ok((unit.name, unit.factor, hpp_amount, selling_amount))
+    }
+
+    /// Stock taken by `qty` base units of the product or variant: its recipe's ingredients, otherwise the item itself
+    async fn stock_usage(&self, product_id: i32, variant_id: Option<i32>, qty: Decimal) -> Result<Vec<(i32, Option<i32>, Decimal)>, AppError> {
+        let recipe_items = self.recipe_item_repository.find_by_product(product_id).await?;
+        if recipe_items.is_empty() {
+            return Ok(vec![(product_id, variant_id, qty)]);
+        }
+
+        Ok(recipe_items
+            .iter()
+            .map(|item| (item.ingredient_id, None, quantity::to_base(qty, item.qty * item.unit_factor)))
+            .collect())
+    }
+
+    /// Take the sold quantity off the shelf, through the components of a bundle
+    async fn deduct_stock(&self, txn: &DatabaseTransaction, transaction: &TransactionModel, actor_id: Option<i32>) -> Result<Vec<StockMovementModel>, AppError> {
+        let bundle_items = self.bundle_item_repository.find_by_bundle(transaction.product_id).await?;
+
+        let qty = quantity::to_base(transaction.qty, transaction.unit_factor);
+
+        let mut usage: Vec<(i32, Option<i32>, Decimal)> = Vec::new();
+        if bundle_items.is_empty() {
+            usage = self.stock_usage(transaction.product_id, transaction.variant_id, qty).await?;
+        }
+        for item in &bundle_items {
+            usage.extend(self.stock_usage(item.component_id, item.variant_id, qty * Decimal::from(item.qty)).await?);
+        }
+
+        let mut movements = Vec::new();
+        for (product_id, variant_id, qty) in usage {
+            let movement = self.stock_repository.record_in(txn, NewStockMovement {
+                product_id,
+                variant_id,
+                qty: -qty,
+                movement_type: MOVEMENT_SALE,
+                reference_type: Some(REFERENCE_TRANSACTION),
+                reference_id: Some(transaction.id),
+                note: None,
+                created_by: actor_id,
+                cost_amount: None,
+                lot: None,
+                lot_id: None,
+                restores: None,
+            }).await?;
+            movements.push(movement);
+        }
+
+        Ok(movements)
+    }
+
+    /// Under FIFO the line's HPP is what the stock it took actually cost, known once the layers are used up
+    async fn book_consumed_cost(&self, txn: &DatabaseTransaction, transaction: TransactionModel, movements: &[StockMovementModel]) -> Result<TransactionModel, AppError> {
+        if self.config.costing.method != COSTING_FIFO {
+            return Ok(transaction);
+        }
+
+        let consumed: i64 = movements.iter().filter_map(|movement| movement.cost_amount).sum();
+        let hpp_amount = costing::unit_cost(consumed, transaction.qty)
+            .and_then(|hpp_amount| i32::try_from(hpp_amount).ok())
+            .ok_or_else(|| AppError::BadRequest(format!("HPP of product {} is too large", transaction.product_id)))?;
+        if hpp_amount == transaction.hpp_amount {
+            return Ok(transaction);
+        }
+
+        self.transaction_repository.set_hpp_in(txn, transaction.id, hpp_amount).await
+    }
+
+    /// Under FIFO the movements may have used up the oldest receipt, so refresh the HPP once they are committed
+    async fn revalue_costs(&self, movements: &[StockMovementModel], actor_id: Option<i32>) -> Result<(), AppError> {
+        for movement in movements {
+            self.product_usecase.revalue_cost(movement.product_id, movement.variant_id, None, actor_id).await?;
+        }
+        Ok(())
+    }
+
+    /// Split a bundle line's revenue by the components' selling prices and its HPP by their costs
+    async fn allocate_bundle(&self, txn: &DatabaseTransaction, transaction: &TransactionModel) -> Result<(), AppError> {
+        let bundle_items = self.bundle_item_repository.find_by_bundle(transaction.product_id).await?;
+
+        let mut revenue_weights: Vec<i64> = Vec::new();
+        let mut hpp_weights: Vec<i64> = Vec::new();
+        for item in &bundle_items {
+            let variant = match item.variant_id {
+                Some(variant_id) => self.product_variant_repository.find_by_id(variant_id).await?,
+                None => None,
+            };
+            let (hpp_amount, selling_amount) = match (variant, self.product_repository.find_by_id(item.component_id).await?) {
+                (Some(variant), _) => (variant.hpp_amount, variant.selling_amount),
+                (None, Some(component)) => (component.hpp_amount, component.selling_amount),
+                (None, None) => (0, 0),
+            };
+            revenue_weights.push(i64::from(selling_amount) * i64::from(item.qty));
+            hpp_weights.push(i64::from(hpp_amount) * i64::from(item.qty));
+        }
+
+        let revenue = quantity::line_amount(i64::from(transaction.selling_amount + transaction.modifier_selling_amount), transaction.qty);
+        let hpp = quantity::line_amount(i64::from(transaction.hpp_amount + transaction.modifier_hpp_amount), transaction.qty);
+        let qty = quantity::to_base(transaction.qty, transaction.unit_factor);
+        let revenue_shares = allocation::allocate(revenue, &revenue_weights);
+        let discount_shares = allocation::allocate(transaction.discount_amount, &revenue_weights);
+        let hpp_shares = allocation::allocate(hpp, &hpp_weights);
+
+        let allocations = bundle_items
+            .iter()
+            .zip(revenue_shares.into_iter().zip(discount_shares).zip(hpp_shares))
+            .map(|(item, ((revenue_amount, discount_amount), hpp_amount))| NewTransactionAllocation {
+                product_id: item.component_id,
+                variant_id: item.variant_id,
+                qty: qty * Decimal::from(item.qty),
+                revenue_amount: revenue_amount - discount_amount,
+                discount_amount,
+                hpp_amount,
+            })
+            .collect();
+
+        self.transaction_allocation_repository.replace(txn, transaction.id, allocations).await
+    }
+
+    /// Net stock change the transaction's own movements still hold per product or variant, negative while sold
+    async fn outstanding_stock(&self, transaction_id: i32) -> Result<Vec<(i32, Option<i32>, Decimal)>, AppError> {
+        let movements = self.stock_repository.find_by_reference(REFERENCE_TRANSACTION, transaction_id).await?;
+
+        let mut outstanding: Vec<(i32, Option<i32>, Decimal)> = Vec::new();
+        for movement in movements {
+            match outstanding
+                .iter_mut()
+                .find(|(product_id, variant_id, _)| *product_id == movement.product_id && *variant_id == movement.variant_id)
+            {
+                Some((_, _, qty)) => *qty += movement.qty,
+                None => outstanding.push((movement.product_id, movement.variant_id, movement.qty)),
+            }
+        }
+
+        Ok(outstanding.into_iter().filter(|(_, _, qty)| !qty.is_zero()).collect())
+    }
+
+    /// The approver of a void: the caller when they are a supervisor or owner, otherwise the one whose PIN was given
+    async fn approve_void(&self, request: &VoidTransactionRequest, actor: &AuthUser) -> Result<i32, AppError> {
+        let approver_roles = [ROLE_OWNER, ROLE_SUPERVISOR];
+        if approver_roles.contains(&actor.role.as_str()) {
+            return Ok(actor.id);
+        }
+
+        let denied = || AppError::AuthorizationError("Voiding needs a supervisor's username and PIN".to_string());
+        let (Some(username), Some(pin)) = (&request.supervisor_username, &request.supervisor_pin) else {
+            return Err(denied());
+        };
+
+        let supervisor = self.user_repository
+            .find_by_username(username)
+            .await?
+            .filter(|user| approver_roles.contains(&user.role.as_str()))
+            .ok_or_else(denied)?;
+        let hashed_pin = supervisor.pin.clone().ok_or_else(denied)?;
+
+        let valid = verify_password(pin.clone(), hashed_pin)
+            .await
+            .map_err(|err| {
+                error!("PIN verification error: {}", err);
+                AppError::InternalError
+            })?;
+        if !valid {
+            return Err(denied());
+        }
+
+        Ok(supervisor.id)
+    }
+
+    /// Undo whatever the transaction still has taken off the shelf, whichever recipe applied at the time
+    async fn return_stock(&self, txn: &DatabaseTransaction, transaction: &TransactionModel, actor_id: Option<i32>) -> Result<Vec<StockMovementModel>, AppError> {
+        let mut movements = Vec::new();
+        for (product_id, variant_id, qty) in self.outstanding_stock(transaction.id).await? {
+            let movement = self.stock_repository.record_in(txn, NewStockMovement {
+                product_id,
+                variant_id,
+                qty: -qty,
+                movement_type: MOVEMENT_SALE_REVERSAL,
+                reference_type: Some(REFERENCE_TRANSACTION),
+                reference_id: Some(transaction.id),
+                note: None,
+                created_by: actor_id,
+                cost_amount: None,
+                lot: None,
+                lot_id: None,
+                restores: Some((REFERENCE_TRANSACTION, transaction.id)),
+            }).await?;
+            movements.push(movement);
+        }
+
+        Ok(movements)
     }
 }
 
 #[async_trait]
 impl TransactionUsecase for TransactionUsecaseImpl {
-    async fn create_transaction(&self, request: CreateTransactionRequest) -> Result<(), AppError> {
+    async fn create_transaction(&self, request: CreateTransactionRequest, actor_id: Option<i32>) -> Result<TransactionResponse, AppError> {
         let product = self.product_repository
             .find_by_id(request.product_id)
             .await?
             .ok_or_else(|| AppError::NotFound(format!("Product with id {} not found", request.product_id)))?;
+
+        // Variants carry their own prices, scaled to the unit sold in
+        let variant = self.resolve_variant(product.id, request.variant_id).await?;
+        let (unit, unit_factor, hpp_amount, selling_amount) = self.price_line(&product, variant.as_ref(), request.unit.as_deref()).await?;
+
+        let modifiers = self.resolve_modifiers(product.id, &request.modifier_ids.unwrap_or_default()).await?;
+        let modifier_hpp_amount: i32 = modifiers.iter().map(|modifier| modifier.hpp_amount).sum();
+        let modifier_selling_amount: i32 = modifiers.iter().map(|modifier| modifier.selling_amount).sum();
+
+        let timezone = filter::parse_timezone(request.timezone.as_deref())?;
+        let unit_price = i64::from(selling_amount + modifier_selling_amount);
+        let (discount_amount, promotion_id) = self.resolve_discount(&product, unit_price, request.qty, request.discount, timezone).await?;
+
+        // Settle the payments before anything is written
+        let total = quantity::line_amount(unit_price, request.qty) - discount_amount;
+        let payments = Self::settle_payments(total, request.payments)?;
+
         // Create active model
         let transaction_active_model = ActiveModel {
             product_id: Set(request.product_id),
-            hpp_amount: Set(product.hpp_amount),
-            selling_amount: Set(product.selling_amount),
+            variant_id: Set(variant.map(|variant| variant.id)),
+            hpp_amount: Set(hpp_amount),
+            selling_amount: Set(selling_amount),
             qty: Set(request.qty),
+            unit: Set(unit),
+            unit_factor: Set(unit_factor),
+            modifier_hpp_amount: Set(modifier_hpp_amount),
+            modifier_selling_amount: Set(modifier_selling_amount),
+            discount_amount: Set(discount_amount),
+            promotion_id: Set(promotion_id),
             created_at: Set(Some(Utc::now())),
             updated_at: Set(Some(Utc::now())),
             deleted_at: Set(None),
             ..Default::default()
         };
 
-        // Save to repository
-        self.transaction_repository.create(transaction_active_model).await?;
+        // Save to repository, along with everything the sale writes
+        let txn = self.transaction_repository.begin().await?;
+        let created_transaction = self.transaction_repository.create_in(&txn, transaction_active_model).await?;
 
-        Ok(())
+        if !modifiers.is_empty() {
+            self.transaction_modifier_repository.replace(&txn, created_transaction.id, &modifiers).await?;
+        }
+        self.transaction_payment_repository.create(&txn, created_transaction.id, payments).await?;
+
+        let movements = self.deduct_stock(&txn, &created_transaction, actor_id).await?;
+        let created_transaction = self.book_consumed_cost(&txn, created_transaction, &movements).await?;
+        self.allocate_bundle(&txn, &created_transaction).await?;
+
+        self.audit_log_repository.record_in(&txn, NewAuditLog {
+            actor_id,
+            entity: ENTITY_TRANSACTION,
+            entity_id: created_transaction.id,
+            action: ACTION_CREATE,
+            before: None,
+            after: Some(json!(created_transaction)),
+        }).await?;
+
+        db::commit(txn).await?;
+        self.revalue_costs(&movements, actor_id).await?;
+
+        self.response_with_details(created_transaction).await
     }
 
     async fn get_transaction(&self, request: GetTransactionRequest) -> Result<TransactionResponse, AppError> {
@@ -73,56 +648,264 @@ impl TransactionUsecase for TransactionUsecaseImpl {
             .await?
             .ok_or_else(|| AppError::NotFound(format!("Transaction with id {} not found", request.id)))?;
 
-        Ok(TransactionResponse::from(transaction))
+        self.response_with_details(transaction).await
     }
 
-    async fn list_transactions(&self, query: GetListTransactionQuery) -> Result<ListTransactionResponse, AppError> {
+    async fn list_transactions(&self, query: GetListTransactionQuery) -> Result<Paginated<TransactionResponse>, AppError> {
         let paginated_result = self.transaction_repository.find_with_pagination(query).await?;
-        Ok(ListTransactionResponse::from(paginated_result))
+        let mut transactions = Paginated::from(paginated_result);
+        self.attach_details(&mut transactions.data).await?;
+        Ok(transactions)
     }
 
-    async fn update_transaction(&self, id: i32, request: UpdateTransactionRequest) -> Result<TransactionResponse, AppError> {
-        // Check if transaction exists
-        self.transaction_repository
+    async fn void_transaction(&self, id: i32, request: VoidTransactionRequest, actor: &AuthUser) -> Result<TransactionResponse, AppError> {
+        let existing_transaction = self.transaction_repository
             .find_by_id(id)
             .await?
             .ok_or_else(|| AppError::NotFound(format!("Transaction with id {} not found", id)))?;
 
-        // Create active model with only changed fields
-        let mut transaction_active_model = ActiveModel {
-            id: Set(id),
-            ..Default::default()
-        };
-
-        if let Some(product_id) = request.product_id {
-            transaction_active_model.product_id = Set(product_id);
+        if existing_transaction.status == STATUS_VOIDED {
+            return Err(AppError::BadRequest(format!("Transaction {} is already voided", id)));
         }
-        if let Some(hpp_amount) = request.hpp_amount {
-            transaction_active_model.hpp_amount = Set(hpp_amount);
+        if !existing_transaction.returned_qty.is_zero() {
+            return Err(AppError::BadRequest(format!("Transaction {} has returns; return the rest instead of voiding", id)));
         }
-        if let Some(selling_amount) = request.selling_amount {
-            transaction_active_model.selling_amount = Set(selling_amount);
+
+        let approved_by = self.approve_void(&request, actor).await?;
+
+        let void = NewVoid {
+            reason: request.reason,
+            voided_by: Some(actor.id),
+            approved_by,
+        };
+        let txn = self.transaction_repository.begin().await?;
+        let voided_transaction = self.transaction_repository
+            .claim_void_in(&txn, id, void)
+            .await?
+            .ok_or_else(|| AppError::BadRequest(format!("Transaction {} can no longer be voided", id)))?;
+
+        self.audit_log_repository.record_in(&txn, NewAuditLog {
+            actor_id: Some(actor.id),
+            entity: ENTITY_TRANSACTION,
+            entity_id: id,
+            action: ACTION_VOID,
+            before: Some(json!(existing_transaction)),
+            after: Some(json!(voided_transaction)),
+        }).await?;
+
+        // Put the sold quantity back on the shelf
+        let movements = self.return_stock(&txn, &voided_transaction, Some(actor.id)).await?;
+
+        db::commit(txn).await?;
+        // The stock went back into its receipts, which may be older than the one the HPP tracks now
+        self.revalue_costs(&movements, Some(actor.id)).await?;
+
+        self.response_with_details(voided_transaction).await
+    }
+
+    async fn purge_transaction(&self, id: i32, actor_id: Option<i32>) -> Result<(), AppError> {
+        // Only soft-deleted rows can be purged
+        let deleted_transaction = self.transaction_repository
+            .find_deleted_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Deleted transaction with id {} not found", id)))?;
+
+        self.transaction_repository.purge(id).await?;
+
+        self.audit_log_repository.record(NewAuditLog {
+            actor_id,
+            entity: ENTITY_TRANSACTION,
+            entity_id: id,
+            action: ACTION_PURGE,
+            before: Some(json!(deleted_transaction)),
+            after: None,
+        }).await?;
+
+        Ok(())
+    }
+
+    async fn return_transaction(&self, id: i32, request: ReturnTransactionRequest, actor_id: Option<i32>) -> Result<TransactionReturnResponse, AppError> {
+        let existing_transaction = self.transaction_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Transaction with id {} not found", id)))?;
+
+        // Amounts for everything returned so far less what was returned before, so the returns add up to the line
+        let returned_before = existing_transaction.returned_qty;
+        let returned_after = returned_before + request.qty;
+        let reversed = |unit_amount: i32| {
+            let unit_amount = i64::from(unit_amount);
+            quantity::line_amount(unit_amount, returned_after) - quantity::line_amount(unit_amount, returned_before)
+        };
+        let discount_amount = quantity::line_amount(existing_transaction.discount_amount, returned_after / existing_transaction.qty)
+            - quantity::line_amount(existing_transaction.discount_amount, returned_before / existing_transaction.qty);
+        let refund_amount = reversed(existing_transaction.selling_amount + existing_transaction.modifier_selling_amount) - discount_amount;
+        let hpp_amount = reversed(existing_transaction.hpp_amount + existing_transaction.modifier_hpp_amount);
+        let nominal = i32::try_from(refund_amount)
+            .map_err(|_| AppError::BadRequest("Refund is too large for a cashflow entry".to_string()))?;
+
+        let txn = self.transaction_repository.begin().await?;
+        if !self.transaction_repository.claim_return(&txn, id, request.qty).await? {
+            return Err(AppError::BadRequest(format!(
+                "Only {} {} of transaction {} can still be returned",
+                (existing_transaction.qty - returned_before).normalize(),
+                existing_transaction.unit,
+                id
+            )));
         }
-        if let Some(qty) = request.qty {
-            transaction_active_model.qty = Set(qty);
+
+        let cashflow = self.cashflow_repository.create_in(&txn, CashflowActiveModel {
+            note: Set(format!("Refund for transaction #{}: {}", id, request.reason)),
+            nominal: Set(nominal),
+            r#type: Set(CASHFLOW_OUT.to_string()),
+            recap_type: Set(RECAP_REFUND.to_string()),
+            created_at: Set(Some(Utc::now())),
+            updated_at: Set(Some(Utc::now())),
+            deleted_at: Set(None),
+            ..Default::default()
+        }).await?;
+
+        let created_return = self.transaction_return_repository.create(&txn, TransactionReturnActiveModel {
+            transaction_id: Set(id),
+            qty: Set(request.qty),
+            reason: Set(request.reason),
+            refund_amount: Set(refund_amount),
+            discount_amount: Set(discount_amount),
+            hpp_amount: Set(hpp_amount),
+            cashflow_id: Set(Some(cashflow.id)),
+            created_by: Set(actor_id),
+            created_at: Set(Some(Utc::now())),
+            ..Default::default()
+        }).await?;
+
+        self.audit_log_repository.record_in(&txn, NewAuditLog {
+            actor_id,
+            entity: ENTITY_TRANSACTION_RETURN,
+            entity_id: created_return.id,
+            action: ACTION_CREATE,
+            before: None,
+            after: Some(json!(created_return)),
+        }).await?;
+
+        // Back on the shelf in proportion to what the sale took, whichever recipe or bundle applied then, into the same layers and lots
+        let share = request.qty / existing_transaction.qty;
+        let mut movements = Vec::new();
+        for (product_id, variant_id, qty) in self.outstanding_stock(id).await? {
+            let restocked = (-qty * share).round_dp(quantity::QTY_SCALE);
+            if restocked <= Decimal::ZERO {
+                continue;
+            }
+
+            let movement = self.stock_repository.record_in(&txn, NewStockMovement {
+                product_id,
+                variant_id,
+                qty: restocked,
+                movement_type: MOVEMENT_RETURN,
+                reference_type: Some(REFERENCE_TRANSACTION_RETURN),
+                reference_id: Some(created_return.id),
+                note: None,
+                created_by: actor_id,
+                cost_amount: None,
+                lot: None,
+                lot_id: None,
+                restores: Some((REFERENCE_TRANSACTION, id)),
+            }).await?;
+            movements.push(movement);
         }
 
-        // Update in repository
-        let updated_transaction = self.transaction_repository.update(id, transaction_active_model).await?;
+        db::commit(txn).await?;
+        self.revalue_costs(&movements, actor_id).await?;
 
-        Ok(TransactionResponse::from(updated_transaction))
+        Ok(TransactionReturnResponse::from(created_return))
     }
 
-    async fn delete_transaction(&self, id: i32) -> Result<(), AppError> {
-        // Check if transaction exists
+    async fn list_returns(&self, id: i32) -> Result<Vec<TransactionReturnResponse>, AppError> {
         self.transaction_repository
             .find_by_id(id)
             .await?
             .ok_or_else(|| AppError::NotFound(format!("Transaction with id {} not found", id)))?;
 
-        // Soft delete
-        self.transaction_repository.delete(id).await?;
+        let returns = self.transaction_return_repository.find_by_transaction(id).await?;
+        Ok(returns.into_iter().map(TransactionReturnResponse::from).collect())
+    }
 
-        Ok(())
+    async fn qris_payload(&self, id: i32) -> Result<String, AppError> {
+        let merchant = self.config.qris
+            .as_ref()
+            .ok_or_else(|| AppError::BadRequest("QRIS is not configured".to_string()))?;
+
+        let transaction = self.transaction_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Transaction with id {} not found", id)))?;
+        if transaction.status != STATUS_COMPLETED {
+            return Err(AppError::BadRequest(format!("Transaction {} is {}", id, transaction.status)));
+        }
+
+        let amount: i64 = self.transaction_payment_repository
+            .find_by_transactions(&[id])
+            .await?
+            .iter()
+            .filter(|payment| payment.method == PAYMENT_QRIS)
+            .map(|payment| payment.amount)
+            .sum();
+        if amount == 0 {
+            return Err(AppError::BadRequest(format!("Transaction {} has no QRIS payment", id)));
+        }
+
+        Ok(qris::payload(merchant, amount, &bill_number(id)))
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::module::transaction::v1::entity::model::transaction_payment::PAYMENT_CARD;
+
+    fn payment(method: &str, amount: Option<i64>, tendered_amount: Option<i64>) -> PaymentRequest {
+        PaymentRequest { method: method.to_string(), amount, tendered_amount, reference: None }
+    }
+
+    #[test]
+    fn defaults_to_exact_cash() {
+        let settled = TransactionUsecaseImpl::settle_payments(25000, Vec::new()).unwrap();
+        assert_eq!(settled.len(), 1);
+        assert_eq!((settled[0].method.as_str(), settled[0].amount, settled[0].change_amount), (PAYMENT_CASH, 25000, 0));
+
+        assert!(TransactionUsecaseImpl::settle_payments(0, Vec::new()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn gives_change_on_cash_only() {
+        let settled = TransactionUsecaseImpl::settle_payments(
+            25000,
+            vec![payment(PAYMENT_CARD, Some(10000), None), payment(PAYMENT_CASH, None, Some(20000))],
+        )
+        .unwrap();
+        assert_eq!(settled[1].amount, 15000);
+        assert_eq!(settled[1].change_amount, 5000);
+
+        assert!(TransactionUsecaseImpl::settle_payments(25000, vec![payment(PAYMENT_CARD, Some(25000), Some(30000))]).is_err());
+        assert!(TransactionUsecaseImpl::settle_payments(25000, vec![payment(PAYMENT_CASH, Some(25000), Some(20000))]).is_err());
+    }
+
+    #[test]
+    fn leaves_at_most_one_cash_amount_open() {
+        let two_open = vec![payment(PAYMENT_CASH, None, None), payment(PAYMENT_CASH, None, None)];
+        assert!(TransactionUsecaseImpl::settle_payments(25000, two_open).is_err());
+
+        let open_card = vec![payment(PAYMENT_CASH, Some(5000), None), payment(PAYMENT_CARD, None, None)];
+        assert!(TransactionUsecaseImpl::settle_payments(25000, open_card).is_err());
+
+        let nothing_left = vec![payment(PAYMENT_CARD, Some(25000), None), payment(PAYMENT_CASH, None, None)];
+        assert!(TransactionUsecaseImpl::settle_payments(25000, nothing_left).is_err());
+    }
+
+    #[test]
+    fn requires_payments_to_add_up_to_the_total() {
+        let short = vec![payment(PAYMENT_CARD, Some(10000), None), payment(PAYMENT_CASH, Some(10000), None)];
+        assert!(TransactionUsecaseImpl::settle_payments(25000, short).is_err());
+
+        let over = vec![payment(PAYMENT_CARD, Some(30000), None)];
+        assert!(TransactionUsecaseImpl::settle_payments(25000, over).is_err());
     }
 }
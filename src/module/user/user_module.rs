@@ -0,0 +1,9 @@
+use axum::Router;
+
+use crate::module::user::v1::user_handler;
+
+pub fn configure() -> Router {
+	Router::new()
+		.nest("/api/v1/auth", user_handler::auth_routes())
+		.nest("/api/v1/user", user_handler::routes())
+}
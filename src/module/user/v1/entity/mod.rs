@@ -0,0 +1,3 @@
+pub mod model;
+pub mod request;
+pub mod response;
\ No newline at end of file
@@ -0,0 +1,29 @@
+use validator::{Validate, ValidationError};
+use serde::{Serialize, Deserialize};
+
+use crate::pkg::auth::ROLES;
+
+#[derive(Debug, Serialize, Deserialize, Validate)]
+pub struct CreateUserRequest {
+    #[validate(length(min = 1, max = 255, message = "Name must be between 1 and 255 characters"))]
+    pub name: String,
+
+    #[validate(length(min = 3, max = 50, message = "Username must be between 3 and 50 characters"))]
+    pub username: String,
+
+    #[validate(length(min = 8, max = 72, message = "Password must be between 8 and 72 characters"))]
+    pub password: String,
+
+    #[validate(custom = "validate_role")]
+    pub role: String,
+}
+
+fn validate_role(role: &str) -> Result<(), ValidationError> {
+    if ROLES.contains(&role) {
+        Ok(())
+    } else {
+        let mut error = ValidationError::new("role");
+        error.message = Some(format!("Role must be one of: {}", ROLES.join(", ")).into());
+        Err(error)
+    }
+}
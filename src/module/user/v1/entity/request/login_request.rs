@@ -0,0 +1,11 @@
+use validator::{Validate};
+use serde::{Serialize, Deserialize};
+
+#[derive(Debug, Serialize, Deserialize, Validate)]
+pub struct LoginRequest {
+    #[validate(length(min = 1, max = 50, message = "Username must be between 1 and 50 characters"))]
+    pub username: String,
+
+    #[validate(length(min = 1, message = "Password is required"))]
+    pub password: String,
+}
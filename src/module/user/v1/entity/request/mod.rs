@@ -0,0 +1,4 @@
+pub mod login_request;
+pub mod create_user_request;
+pub mod list_user_request;
+pub mod set_pin_request;
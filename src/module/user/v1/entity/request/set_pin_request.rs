@@ -0,0 +1,22 @@
+use validator::{Validate, ValidationError};
+use serde::{Serialize, Deserialize};
+
+#[derive(Debug, Serialize, Deserialize, Validate)]
+pub struct SetPinRequest {
+    // Current password, so an unattended session can't change the PIN
+    #[validate(length(min = 1, message = "Password is required"))]
+    pub password: String,
+
+    #[validate(custom = "validate_pin")]
+    pub pin: String,
+}
+
+fn validate_pin(pin: &str) -> Result<(), ValidationError> {
+    if (4..=8).contains(&pin.len()) && pin.chars().all(|c| c.is_ascii_digit()) {
+        Ok(())
+    } else {
+        let mut error = ValidationError::new("pin");
+        error.message = Some("PIN must be 4 to 8 digits".into());
+        Err(error)
+    }
+}
@@ -0,0 +1,9 @@
+use serde::{Serialize, Deserialize};
+
+use crate::module::user::v1::entity::response::user_response::UserResponse;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LoginResponse {
+    pub token: String,
+    pub user: UserResponse,
+}
@@ -0,0 +1,2 @@
+pub mod login_response;
+pub mod user_response;
@@ -0,0 +1,27 @@
+use serde::{Serialize, Deserialize};
+use chrono::{Utc, DateTime};
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct UserResponse {
+    pub id: i32,
+    pub name: String,
+    pub username: String,
+    pub role: String,
+    pub has_pin: bool,
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl From<crate::module::user::v1::entity::model::user::Model> for UserResponse {
+    fn from(model: crate::module::user::v1::entity::model::user::Model) -> Self {
+        Self {
+            id: model.id,
+            name: model.name,
+            username: model.username,
+            role: model.role,
+            has_pin: model.pin.is_some(),
+            created_at: model.created_at,
+            updated_at: model.updated_at,
+        }
+    }
+}
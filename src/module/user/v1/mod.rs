@@ -0,0 +1,3 @@
+pub mod entity;
+pub mod user_usecase;
+pub mod user_handler;
\ No newline at end of file
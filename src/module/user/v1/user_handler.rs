@@ -0,0 +1,122 @@
+use axum::{
+	extract::{Extension, OriginalUri, Query}, 
+    http::StatusCode, 
+    response::IntoResponse, 
+    routing::{get, post, put}, 
+    Json, 
+    Router
+};
+use serde_json::json;
+use validator::Validate;
+use std::sync::Arc;
+
+use crate::module::user::v1::entity::{
+    request::{
+        login_request::LoginRequest,
+        create_user_request::CreateUserRequest,
+        list_user_request::GetListUserQuery,
+        set_pin_request::SetPinRequest,
+    },
+};
+use crate::pkg::auth::{AuthUser, ROLE_OWNER, ROLE_SUPERVISOR};
+use crate::pkg::custom_error::AppError;
+use crate::pkg::response::ApiResponse;
+use crate::di::AppModule;
+
+
+pub fn auth_routes() -> Router {
+	Router::new()
+		.route("/login", post(login))
+		.route("/me", get(me))
+		.route("/me/pin", put(set_pin))
+}
+
+pub fn routes() -> Router {
+	Router::new()
+		.route("/", post(create_user))
+		.route("/", get(list_users))
+}
+
+async fn login(
+    Extension(app_module): Extension<Arc<AppModule>>,
+    Json(payload): Json<LoginRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    payload.validate().map_err(|e| {
+        AppError::BadRequest(e.to_string())
+    })?;
+
+    let login = app_module.user_usecase.login(payload).await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse::success("Login successful", Some(json!(login)), None)),
+    ))
+}
+
+async fn me(
+    Extension(app_module): Extension<Arc<AppModule>>,
+    auth_user: AuthUser,
+) -> Result<impl IntoResponse, AppError> {
+    let user = app_module.user_usecase.get_user(auth_user.id).await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse::success("User retrieved successfully", Some(json!(user)), None)),
+    ))
+}
+
+async fn set_pin(
+    Extension(app_module): Extension<Arc<AppModule>>,
+    auth_user: AuthUser,
+    Json(payload): Json<SetPinRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    // Only approvers need a PIN
+    auth_user.require_role(&[ROLE_OWNER, ROLE_SUPERVISOR])?;
+
+    payload.validate().map_err(|e| {
+        AppError::BadRequest(e.to_string())
+    })?;
+
+    let user = app_module.user_usecase.set_pin(auth_user.id, payload).await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse::success("PIN updated successfully", Some(json!(user)), None)),
+    ))
+}
+
+async fn create_user(
+    Extension(app_module): Extension<Arc<AppModule>>,
+    auth_user: AuthUser,
+    Json(payload): Json<CreateUserRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    auth_user.require_role(&[ROLE_OWNER])?;
+
+    payload.validate().map_err(|e| {
+        AppError::BadRequest(e.to_string())
+    })?;
+
+    let user = app_module.user_usecase.create_user(payload).await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(ApiResponse::success("User created successfully", Some(json!(user)), None)),
+    ))
+}
+
+async fn list_users(
+    Extension(app_module): Extension<Arc<AppModule>>,
+    OriginalUri(uri): OriginalUri,
+    auth_user: AuthUser,
+    Query(query): Query<GetListUserQuery>,
+) -> Result<impl IntoResponse, AppError> {
+    auth_user.require_role(&[ROLE_OWNER])?;
+
+    let users = app_module.user_usecase.list_users(query).await?;
+
+    Ok((
+        StatusCode::OK,
+        users.headers(&uri),
+        Json(ApiResponse::success("Users retrieved successfully", Some(json!(users.data)), Some(json!(users.meta)))),
+    ))
+}
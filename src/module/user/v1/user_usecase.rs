@@ -0,0 +1,180 @@
+use async_trait::async_trait;
+use crate::module::user::v1::entity::{
+    request::{
+        login_request::LoginRequest,
+        create_user_request::CreateUserRequest,
+        list_user_request::GetListUserQuery,
+        set_pin_request::SetPinRequest,
+    },
+    response::{
+        login_response::LoginResponse,
+        user_response::UserResponse,
+    },
+    model::user::ActiveModel,
+};
+use crate::module::repository::user_repository::UserRepository;
+use crate::pkg::auth::ROLE_OWNER;
+use crate::pkg::bcrypt::{hash_password, verify_password};
+use crate::pkg::config::Config;
+use crate::pkg::custom_error::AppError;
+use crate::pkg::jwt::generate_token;
+use crate::pkg::pagination::Paginated;
+use sea_orm::Set;
+use std::sync::Arc;
+use chrono::Utc;
+use tracing::log::{error, info};
+
+#[async_trait]
+pub trait UserUsecase: Send + Sync {
+    async fn login(&self, request: LoginRequest) -> Result<LoginResponse, AppError>;
+    async fn get_user(&self, id: i32) -> Result<UserResponse, AppError>;
+    async fn create_user(&self, request: CreateUserRequest) -> Result<UserResponse, AppError>;
+    async fn list_users(&self, query: GetListUserQuery) -> Result<Paginated<UserResponse>, AppError>;
+    async fn set_pin(&self, id: i32, request: SetPinRequest) -> Result<UserResponse, AppError>;
+    async fn ensure_owner(&self) -> Result<(), AppError>;
+}
+
+pub struct UserUsecaseImpl {
+    user_repository: Arc<dyn UserRepository>,
+    config: Arc<Config>,
+}
+
+impl UserUsecaseImpl {
+    pub fn new(user_repository: Arc<dyn UserRepository>, config: Arc<Config>) -> Self {
+        Self { user_repository, config }
+    }
+}
+
+#[async_trait]
+impl UserUsecase for UserUsecaseImpl {
+    async fn login(&self, request: LoginRequest) -> Result<LoginResponse, AppError> {
+        let invalid = || AppError::AuthenticationError("Invalid username or password".to_string());
+
+        let user = self.user_repository
+            .find_by_username(&request.username)
+            .await?
+            .ok_or_else(invalid)?;
+
+        let valid = verify_password(request.password, user.password.clone())
+            .await
+            .map_err(|err| {
+                error!("Password verification error: {}", err);
+                AppError::InternalError
+            })?;
+        if !valid {
+            return Err(invalid());
+        }
+
+        let token = generate_token(&user.id.to_string(), &user.role, &self.config)
+            .map_err(|err| {
+                error!("Token generation error: {:?}", err);
+                AppError::InternalError
+            })?;
+
+        Ok(LoginResponse {
+            token,
+            user: UserResponse::from(user),
+        })
+    }
+
+    async fn get_user(&self, id: i32) -> Result<UserResponse, AppError> {
+        let user = self.user_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("User with id {} not found", id)))?;
+
+        Ok(UserResponse::from(user))
+    }
+
+    async fn create_user(&self, request: CreateUserRequest) -> Result<UserResponse, AppError> {
+        // Check if username is already taken
+        if self.user_repository.find_by_username(&request.username).await?.is_some() {
+            return Err(AppError::BadRequest("User with this username already exists".to_string()));
+        }
+
+        let password = hash_password(request.password)
+            .await
+            .map_err(|err| {
+                error!("Password hashing error: {}", err);
+                AppError::InternalError
+            })?;
+
+        // Create active model
+        let user_active_model = ActiveModel {
+            name: Set(request.name),
+            username: Set(request.username),
+            password: Set(password),
+            role: Set(request.role),
+            created_at: Set(Some(Utc::now())),
+            updated_at: Set(Some(Utc::now())),
+            deleted_at: Set(None),
+            ..Default::default()
+        };
+
+        let created_user = self.user_repository.create(user_active_model).await?;
+
+        Ok(UserResponse::from(created_user))
+    }
+
+    async fn list_users(&self, query: GetListUserQuery) -> Result<Paginated<UserResponse>, AppError> {
+        let paginated_result = self.user_repository.find_with_pagination(query).await?;
+        Ok(Paginated::from(paginated_result))
+    }
+
+    async fn set_pin(&self, id: i32, request: SetPinRequest) -> Result<UserResponse, AppError> {
+        let user = self.user_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("User with id {} not found", id)))?;
+
+        let valid = verify_password(request.password, user.password.clone())
+            .await
+            .map_err(|err| {
+                error!("Password verification error: {}", err);
+                AppError::InternalError
+            })?;
+        if !valid {
+            return Err(AppError::AuthenticationError("Invalid password".to_string()));
+        }
+
+        let pin = hash_password(request.pin)
+            .await
+            .map_err(|err| {
+                error!("PIN hashing error: {}", err);
+                AppError::InternalError
+            })?;
+
+        let user_active_model = ActiveModel {
+            id: Set(id),
+            pin: Set(Some(pin)),
+            ..Default::default()
+        };
+
+        let updated_user = self.user_repository.update(id, user_active_model).await?;
+
+        Ok(UserResponse::from(updated_user))
+    }
+
+    async fn ensure_owner(&self) -> Result<(), AppError> {
+        // Seed the first owner from OWNER_USERNAME / OWNER_PASSWORD
+        let (Some(username), Some(password)) = (&self.config.app.owner_username, &self.config.app.owner_password) else {
+            return Ok(());
+        };
+
+        if self.user_repository.find_by_username(username).await?.is_some() {
+            return Ok(());
+        }
+
+        self.create_user(CreateUserRequest {
+            name: username.clone(),
+            username: username.clone(),
+            password: password.clone(),
+            role: ROLE_OWNER.to_string(),
+        })
+        .await?;
+
+        info!("Created owner account '{}'", username);
+
+        Ok(())
+    }
+}
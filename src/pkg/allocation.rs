@@ -0,0 +1,56 @@
+/// Split `total` across `weights` in proportion, handing the rounding remainder to the
+/// heaviest weight. All-zero weights split evenly.
+pub fn allocate(total: i64, weights: &[i64]) -> Vec<i64> {
+    if weights.is_empty() {
+        return Vec::new();
+    }
+
+    let weights: Vec<i64> = if weights.iter().all(|weight| *weight <= 0) {
+        vec![1; weights.len()]
+    } else {
+        weights.iter().map(|weight| (*weight).max(0)).collect()
+    };
+    let weight_sum: i128 = weights.iter().map(|weight| i128::from(*weight)).sum();
+
+    let mut shares: Vec<i64> = weights
+        .iter()
+        .map(|weight| (i128::from(total) * i128::from(*weight) / weight_sum) as i64)
+        .collect();
+
+    let remainder = total - shares.iter().sum::<i64>();
+    if let Some(heaviest) = (0..weights.len()).max_by_key(|index| weights[*index]) {
+        shares[heaviest] += remainder;
+    }
+
+    shares
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_in_proportion() {
+        assert_eq!(allocate(10000, &[3000, 2000]), vec![6000, 4000]);
+    }
+
+    #[test]
+    fn hands_the_remainder_to_the_heaviest_weight() {
+        // Ties go to the last of the heaviest
+        assert_eq!(allocate(100, &[1, 1, 1]), vec![33, 33, 34]);
+        assert_eq!(allocate(1000, &[1, 2]), vec![333, 667]);
+    }
+
+    #[test]
+    fn always_adds_up_to_the_total() {
+        let shares = allocate(99999, &[1250, 3300, 715, 0]);
+        assert_eq!(shares.iter().sum::<i64>(), 99999);
+        assert_eq!(shares[3], 0);
+    }
+
+    #[test]
+    fn splits_evenly_without_weights() {
+        assert_eq!(allocate(9, &[0, 0, 0]), vec![3, 3, 3]);
+        assert_eq!(allocate(10, &[0, -5]), vec![5, 5]);
+        assert!(allocate(100, &[]).is_empty());
+    }
+}
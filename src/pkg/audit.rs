@@ -0,0 +1,50 @@
+use serde_json::{Map, Value};
+
+pub const ENTITY_PRODUCT: &str = "product";
+pub const ENTITY_CASHFLOW: &str = "cashflow";
+pub const ENTITY_TRANSACTION: &str = "transaction";
+pub const ENTITY_CATEGORY: &str = "category";
+pub const ENTITY_PRODUCT_VARIANT: &str = "product_variant";
+pub const ENTITY_MODIFIER_GROUP: &str = "modifier_group";
+pub const ENTITY_MODIFIER: &str = "modifier";
+pub const ENTITY_PRODUCT_UNIT: &str = "product_unit";
+pub const ENTITY_SUPPLIER: &str = "supplier";
+pub const ENTITY_PURCHASE_ORDER: &str = "purchase_order";
+pub const ENTITY_STOCK_COUNT: &str = "stock_count";
+pub const ENTITY_TRANSACTION_RETURN: &str = "transaction_return";
+pub const ENTITY_PROMOTION: &str = "promotion";
+/// Bulk purge of expired soft-deleted rows; `entity_id` is always 0
+pub const ENTITY_RETENTION: &str = "retention";
+
+pub const ACTION_CREATE: &str = "create";
+pub const ACTION_UPDATE: &str = "update";
+pub const ACTION_DELETE: &str = "delete";
+pub const ACTION_RESTORE: &str = "restore";
+pub const ACTION_PURGE: &str = "purge";
+pub const ACTION_VOID: &str = "void";
+
+/// Fields that differ between two JSON snapshots as `{ "field": { "before": .., "after": .. } }`.
+/// A missing snapshot counts as every field being null; `None` when nothing changed.
+pub fn diff(before: Option<&Value>, after: Option<&Value>) -> Option<Value> {
+    let empty = Map::new();
+    let before = before.and_then(Value::as_object).unwrap_or(&empty);
+    let after = after.and_then(Value::as_object).unwrap_or(&empty);
+
+    let mut changes = Map::new();
+    for key in before.keys().chain(after.keys().filter(|key| !before.contains_key(*key))) {
+        let old = before.get(key).unwrap_or(&Value::Null);
+        let new = after.get(key).unwrap_or(&Value::Null);
+        if old != new {
+            let mut change = Map::new();
+            change.insert("before".to_string(), old.clone());
+            change.insert("after".to_string(), new.clone());
+            changes.insert(key.clone(), Value::Object(change));
+        }
+    }
+
+    if changes.is_empty() {
+        None
+    } else {
+        Some(Value::Object(changes))
+    }
+}
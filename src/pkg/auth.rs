@@ -0,0 +1,65 @@
+use axum::{
+    async_trait,
+    extract::FromRequestParts,
+    http::{header, request::Parts},
+};
+use std::sync::Arc;
+
+use crate::pkg::config::Config;
+use crate::pkg::custom_error::AppError;
+use crate::pkg::jwt;
+
+pub const ROLE_OWNER: &str = "owner";
+pub const ROLE_SUPERVISOR: &str = "supervisor";
+pub const ROLE_CASHIER: &str = "cashier";
+pub const ROLES: &[&str] = &[ROLE_OWNER, ROLE_SUPERVISOR, ROLE_CASHIER];
+
+/// Caller identified by the `Authorization: Bearer <token>` header.
+/// Use `Option<AuthUser>` for endpoints that stay open to anonymous callers.
+#[derive(Debug, Clone)]
+pub struct AuthUser {
+    pub id: i32,
+    pub role: String,
+}
+
+impl AuthUser {
+    pub fn require_role(&self, roles: &[&str]) -> Result<(), AppError> {
+        if roles.contains(&self.role.as_str()) {
+            Ok(())
+        } else {
+            Err(AppError::AuthorizationError(format!("Requires role: {}", roles.join(" or "))))
+        }
+    }
+}
+
+#[async_trait]
+impl<S> FromRequestParts<S> for AuthUser
+where
+    S: Send + Sync,
+{
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let config = parts
+            .extensions
+            .get::<Arc<Config>>()
+            .cloned()
+            .ok_or_else(|| AppError::ConfigError("Config extension is not registered".to_string()))?;
+
+        let token = parts
+            .headers
+            .get(header::AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "))
+            .ok_or_else(|| AppError::AuthenticationError("Missing bearer token".to_string()))?;
+
+        let token_data = jwt::verify_token(token, &config)
+            .map_err(|_| AppError::AuthenticationError("Invalid or expired token".to_string()))?;
+
+        let id = token_data.claims.sub
+            .parse::<i32>()
+            .map_err(|_| AppError::AuthenticationError("Invalid token subject".to_string()))?;
+
+        Ok(AuthUser { id, role: token_data.claims.role })
+    }
+}
@@ -0,0 +1,65 @@
+use crate::pkg::custom_error::AppError;
+
+/// Validate an EAN-13, UPC-A or EAN-8 code and return its canonical form.
+/// UPC-A is stored as EAN-13 with a leading zero, so a scanner reporting either finds the product.
+pub fn normalize(code: &str) -> Result<String, AppError> {
+    let code = code.trim();
+    let invalid = |reason: &str| AppError::BadRequest(format!("Invalid barcode {}: {}", code, reason));
+
+    if !code.chars().all(|c| c.is_ascii_digit()) {
+        return Err(invalid("only digits are allowed"));
+    }
+
+    let code = match code.len() {
+        8 | 13 => code.to_string(),
+        12 => format!("0{}", code),
+        _ => return Err(invalid("expected 8 (EAN-8), 12 (UPC-A) or 13 (EAN-13) digits")),
+    };
+
+    if !has_valid_check_digit(&code) {
+        return Err(invalid("check digit does not match"));
+    }
+
+    Ok(code)
+}
+
+/// GS1 mod-10: digits are weighted 3, 1, 3, ... starting next to the check digit
+fn has_valid_check_digit(code: &str) -> bool {
+    let digits: Vec<u32> = code.chars().filter_map(|c| c.to_digit(10)).collect();
+    let Some((check, payload)) = digits.split_last() else {
+        return false;
+    };
+
+    let sum: u32 = payload
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(i, digit)| if i % 2 == 0 { digit * 3 } else { *digit })
+        .sum();
+
+    (10 - sum % 10) % 10 == *check
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_valid_check_digits() {
+        assert_eq!(normalize("4006381333931").unwrap(), "4006381333931");
+        assert_eq!(normalize("96385074").unwrap(), "96385074");
+    }
+
+    #[test]
+    fn stores_upc_a_as_ean_13() {
+        assert_eq!(normalize(" 036000291452 ").unwrap(), "0036000291452");
+    }
+
+    #[test]
+    fn rejects_bad_codes() {
+        assert!(normalize("4006381333932").is_err());
+        assert!(normalize("96385075").is_err());
+        assert!(normalize("40063813339").is_err());
+        assert!(normalize("400638133393A").is_err());
+    }
+}
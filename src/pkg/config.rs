@@ -2,11 +2,18 @@ use dotenvy::dotenv;
 use std::env;
 use std::sync::{Arc, OnceLock};
 
+use crate::pkg::costing::{COSTING_AVERAGE, COSTING_METHODS};
+
 #[derive(Debug, Clone)]
 pub struct Config {
     pub db: DbConfig,
     pub app: AppConfig,
     pub jwt: Jwt,
+    pub retention: RetentionConfig,
+    pub costing: CostingConfig,
+    pub reorder: ReorderConfig,
+    /// Merchant data for QRIS payments, unset until the merchant's NMID and PAN are configured
+    pub qris: Option<QrisConfig>,
 }
 
 #[derive(Debug, Clone)]
@@ -23,6 +30,8 @@ pub struct DbConfig {
 #[derive(Debug, Clone)]
 pub struct AppConfig {
     pub port: u16,
+    pub owner_username: Option<String>,
+    pub owner_password: Option<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -31,6 +40,44 @@ pub struct Jwt {
     pub expiration: i64,
 }
 
+#[derive(Debug, Clone)]
+pub struct RetentionConfig {
+    /// Soft-deleted rows older than this many days are purged, 0 disables the job
+    pub days: i64,
+    pub interval_seconds: u64,
+    /// Only log what would be purged
+    pub dry_run: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct CostingConfig {
+    /// How HPP follows purchase costs: "average" or "fifo"
+    pub method: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct ReorderConfig {
+    /// Days of sales averaged for reorder suggestions when the request gives none
+    pub lookback_days: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct QrisConfig {
+    /// Reverse domain of the merchant's acquirer, e.g. "ID.CO.BANKNAME.WWW"
+    pub acquirer_domain: String,
+    /// Merchant PAN issued by the acquirer
+    pub merchant_pan: String,
+    pub merchant_id: String,
+    /// National merchant ID registered with QRIS
+    pub nmid: String,
+    /// UMI, UKE, UME or UBE
+    pub merchant_criteria: String,
+    pub merchant_category_code: String,
+    pub merchant_name: String,
+    pub merchant_city: String,
+    pub postal_code: Option<String>,
+}
+
 static CONFIG: OnceLock<Arc<Config>> = OnceLock::new();
 
 pub fn init_config() -> Arc<Config> {
@@ -44,6 +91,8 @@ pub fn init_config() -> Arc<Config> {
                         .unwrap_or_else(|_| "3001".to_string()) 
                         .parse() 
                         .unwrap_or(3001),
+                    owner_username: env::var("OWNER_USERNAME").ok(),
+                    owner_password: env::var("OWNER_PASSWORD").ok(),
                 },
                 db: DbConfig {
                     host: env::var("DB_HOST").unwrap_or_else(|_| "localhost".to_string()),
@@ -64,6 +113,47 @@ pub fn init_config() -> Arc<Config> {
                         .parse()
                         .unwrap_or(3600),
                 },
+                retention: RetentionConfig {
+                    days: env::var("RETENTION_DAYS")
+                        .unwrap_or_else(|_| "0".to_string())
+                        .parse()
+                        .unwrap_or(0),
+                    interval_seconds: env::var("RETENTION_INTERVAL_SECONDS")
+                        .unwrap_or_else(|_| "86400".to_string())
+                        .parse()
+                        .unwrap_or(86400),
+                    dry_run: env::var("RETENTION_DRY_RUN")
+                        .map(|value| value == "true" || value == "1")
+                        .unwrap_or(false),
+                },
+                costing: CostingConfig {
+                    method: env::var("COSTING_METHOD")
+                        .ok()
+                        .filter(|method| COSTING_METHODS.contains(&method.as_str()))
+                        .unwrap_or_else(|| COSTING_AVERAGE.to_string()),
+                },
+                reorder: ReorderConfig {
+                    lookback_days: env::var("REORDER_LOOKBACK_DAYS")
+                        .unwrap_or_else(|_| "30".to_string())
+                        .parse()
+                        .ok()
+                        .filter(|days| *days > 0)
+                        .unwrap_or(30),
+                },
+                qris: match (env::var("QRIS_NMID"), env::var("QRIS_MERCHANT_PAN")) {
+                    (Ok(nmid), Ok(merchant_pan)) => Some(QrisConfig {
+                        acquirer_domain: env::var("QRIS_ACQUIRER_DOMAIN").unwrap_or_else(|_| "ID.CO.QRIS.WWW".to_string()),
+                        merchant_id: env::var("QRIS_MERCHANT_ID").unwrap_or_else(|_| nmid.clone()),
+                        merchant_pan,
+                        nmid,
+                        merchant_criteria: env::var("QRIS_MERCHANT_CRITERIA").unwrap_or_else(|_| "UMI".to_string()),
+                        merchant_category_code: env::var("QRIS_MERCHANT_CATEGORY_CODE").unwrap_or_else(|_| "5812".to_string()),
+                        merchant_name: env::var("QRIS_MERCHANT_NAME").unwrap_or_else(|_| "MERCHANT".to_string()),
+                        merchant_city: env::var("QRIS_MERCHANT_CITY").unwrap_or_else(|_| "JAKARTA".to_string()),
+                        postal_code: env::var("QRIS_POSTAL_CODE").ok(),
+                    }),
+                    _ => None,
+                },
             })
         })
         .clone()
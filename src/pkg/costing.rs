@@ -0,0 +1,74 @@
+use rust_decimal::{prelude::ToPrimitive, Decimal, RoundingStrategy};
+
+/// HPP is the moving weighted average of stock on hand and each receipt
+pub const COSTING_AVERAGE: &str = "average";
+/// HPP is the unit cost of the oldest receipt still in stock
+pub const COSTING_FIFO: &str = "fifo";
+pub const COSTING_METHODS: &[&str] = &[COSTING_AVERAGE, COSTING_FIFO];
+
+/// Stock received at a known total cost, in base units
+pub struct CostReceipt {
+    pub qty: Decimal,
+    pub cost_amount: i64,
+}
+
+/// Cost of one base unit out of `qty` units costing `cost_amount`, rounded half away from zero
+pub fn unit_cost(cost_amount: i64, qty: Decimal) -> Option<i64> {
+    if qty <= Decimal::ZERO {
+        return None;
+    }
+
+    (Decimal::from(cost_amount) / qty)
+        .round_dp_with_strategy(0, RoundingStrategy::MidpointAwayFromZero)
+        .to_i64()
+}
+
+/// HPP after `receipt` joins `stock` units costing `hpp_amount` each; stock at or below zero carries no cost
+pub fn moving_average(stock: Decimal, hpp_amount: i32, receipt: &CostReceipt) -> Option<i64> {
+    if stock <= Decimal::ZERO {
+        return unit_cost(receipt.cost_amount, receipt.qty);
+    }
+
+    let value = stock * Decimal::from(hpp_amount) + Decimal::from(receipt.cost_amount);
+    (value / (stock + receipt.qty))
+        .round_dp_with_strategy(0, RoundingStrategy::MidpointAwayFromZero)
+        .to_i64()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn receipt(qty: i64, cost_amount: i64) -> CostReceipt {
+        CostReceipt { qty: Decimal::from(qty), cost_amount }
+    }
+
+    #[test]
+    fn rounds_unit_costs_half_away_from_zero() {
+        assert_eq!(unit_cost(10000, Decimal::from(4)), Some(2500));
+        // 10,000 over 3 is 3,333.33; 5 over 2 is 2.5
+        assert_eq!(unit_cost(10000, Decimal::from(3)), Some(3333));
+        assert_eq!(unit_cost(5, Decimal::from(2)), Some(3));
+        assert_eq!(unit_cost(1000, Decimal::new(25, 1)), Some(400));
+    }
+
+    #[test]
+    fn has_no_unit_cost_without_quantity() {
+        assert_eq!(unit_cost(1000, Decimal::ZERO), None);
+        assert_eq!(unit_cost(1000, Decimal::from(-2)), None);
+    }
+
+    #[test]
+    fn averages_stock_on_hand_with_the_receipt() {
+        // 10 at 1,000 plus 10 costing 30,000 is 20 at 2,000
+        assert_eq!(moving_average(Decimal::from(10), 1000, &receipt(10, 30000)), Some(2000));
+        // 3 at 1,000 plus 4 costing 5,000 is 8,000 over 7
+        assert_eq!(moving_average(Decimal::from(3), 1000, &receipt(4, 5000)), Some(1143));
+    }
+
+    #[test]
+    fn takes_the_receipt_cost_when_out_of_stock() {
+        assert_eq!(moving_average(Decimal::ZERO, 1000, &receipt(5, 7500)), Some(1500));
+        assert_eq!(moving_average(Decimal::from(-2), 1000, &receipt(5, 7500)), Some(1500));
+    }
+}
@@ -15,7 +15,7 @@ pub enum AppError {
     NotFound(String),
     InternalError,
     AuthenticationError(String),
-    // AuthorizationError(String),
+    AuthorizationError(String),
     BadRequest(String),
 }
 
@@ -52,10 +52,10 @@ impl IntoResponse for AppError {
                 StatusCode::UNAUTHORIZED,
                 json!({ "error": "authentication", "message": message }),
             ),
-            // AppError::AuthorizationError(message) => (
-            //         StatusCode::FORBIDDEN,
-            //         json!({ "error": "authorization", "message": message }),
-            // ),
+            AppError::AuthorizationError(message) => (
+                    StatusCode::FORBIDDEN,
+                    json!({ "error": "authorization", "message": message }),
+            ),
             AppError::BadRequest(message) => {
                 (
                     StatusCode::BAD_REQUEST,
@@ -1,8 +1,10 @@
-use sea_orm::{ConnectOptions as SeaOrmConnectOptions, Database, DatabaseConnection};
+use sea_orm::{ConnectOptions as SeaOrmConnectOptions, Database, DatabaseConnection, DatabaseTransaction};
 use crate::pkg::config::Config;
+use crate::pkg::custom_error::AppError;
 use std::sync::Arc;
 use std::time::Duration;
 use log::LevelFilter;
+use tracing::log::error;
 
 pub struct DBConnection {
     pub db: Arc<DatabaseConnection>,
@@ -42,4 +44,12 @@ impl DBConnection {
             db: Arc::new(db),
         }
     }
+}
+
+/// Commit a DB transaction opened by a repository's `begin`
+pub async fn commit(txn: DatabaseTransaction) -> Result<(), AppError> {
+    txn.commit().await.map_err(|err| {
+        error!("DB error: {:?}", err);
+        AppError::InternalError
+    })
 }
\ No newline at end of file
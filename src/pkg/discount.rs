@@ -0,0 +1,75 @@
+use rust_decimal::Decimal;
+
+use crate::pkg::quantity;
+
+/// `value` percent off
+pub const KIND_PERCENTAGE: &str = "percentage";
+/// `value` rupiah off
+pub const KIND_FIXED: &str = "fixed";
+/// Of every `buy_qty` + `get_qty` units, `get_qty` are free
+pub const KIND_BUY_X_GET_Y: &str = "buy_x_get_y";
+pub const KINDS: &[&str] = &[KIND_PERCENTAGE, KIND_FIXED, KIND_BUY_X_GET_Y];
+
+/// Percentage or fixed discount on `qty` units at `unit_price`, never more than the line is worth.
+/// Discounts are per line only: a fixed discount comes off each unit, there is no basket-wide discount
+pub fn amount(kind: &str, value: i64, unit_price: i64, qty: Decimal) -> i64 {
+    let gross = quantity::line_amount(unit_price, qty);
+    let discount = match kind {
+        KIND_PERCENTAGE => quantity::line_amount(gross, Decimal::from(value) / Decimal::ONE_HUNDRED),
+        KIND_FIXED => quantity::line_amount(value, qty),
+        _ => 0,
+    };
+
+    discount.clamp(0, gross.max(0))
+}
+
+/// Free units' worth when buying `buy_qty` gets `get_qty` more, counting only whole groups
+pub fn buy_x_get_y(buy_qty: i32, get_qty: i32, unit_price: i64, qty: Decimal) -> i64 {
+    let group = Decimal::from(buy_qty + get_qty);
+    if group <= Decimal::ZERO || get_qty <= 0 {
+        return 0;
+    }
+
+    let free = (qty / group).floor() * Decimal::from(get_qty);
+    quantity::line_amount(unit_price, free)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rounds_percentages_half_away_from_zero() {
+        // 15% of 3,333 is 499.95
+        assert_eq!(amount(KIND_PERCENTAGE, 15, 3333, Decimal::ONE), 500);
+        // 10% of 3 x 335 is 100.5
+        assert_eq!(amount(KIND_PERCENTAGE, 10, 335, Decimal::from(3)), 101);
+        assert_eq!(amount(KIND_PERCENTAGE, 100, 4500, Decimal::new(15, 1)), 6750);
+    }
+
+    #[test]
+    fn applies_fixed_discounts_per_unit() {
+        assert_eq!(amount(KIND_FIXED, 500, 3000, Decimal::from(2)), 1000);
+        assert_eq!(amount(KIND_FIXED, 500, 3000, Decimal::new(15, 1)), 750);
+    }
+
+    #[test]
+    fn never_exceeds_the_line() {
+        assert_eq!(amount(KIND_FIXED, 5000, 3000, Decimal::from(2)), 6000);
+        assert_eq!(amount(KIND_PERCENTAGE, 150, 3000, Decimal::ONE), 3000);
+    }
+
+    #[test]
+    fn gives_free_units_for_whole_groups_only() {
+        // Buy 2 get 1: 7 units make 2 groups of 3
+        assert_eq!(buy_x_get_y(2, 1, 10000, Decimal::from(7)), 20000);
+        assert_eq!(buy_x_get_y(2, 1, 10000, Decimal::from(2)), 0);
+        assert_eq!(buy_x_get_y(2, 1, 10000, Decimal::new(35, 1)), 10000);
+    }
+
+    #[test]
+    fn ignores_empty_buy_x_get_y_rules() {
+        assert_eq!(buy_x_get_y(2, 0, 10000, Decimal::from(9)), 0);
+        assert_eq!(buy_x_get_y(0, 0, 10000, Decimal::from(9)), 0);
+    }
+}
@@ -0,0 +1,135 @@
+use chrono::{DateTime, Duration, FixedOffset, NaiveDate, TimeZone, Utc};
+use std::str::FromStr;
+
+use crate::pkg::custom_error::AppError;
+
+/// Parse a comma separated query value (`1,2,3`), rejecting entries that don't parse
+pub fn parse_list<T: FromStr>(name: &str, raw: Option<&str>) -> Result<Vec<T>, AppError> {
+    raw.unwrap_or_default()
+        .split(',')
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+        .map(|item| item.parse::<T>().map_err(|_| AppError::BadRequest(format!("Invalid {}", name))))
+        .collect()
+}
+
+/// Parse an optional numeric query value such as an ID or a day count
+pub fn parse_number<T: FromStr>(name: &str, raw: Option<&str>) -> Result<Option<T>, AppError> {
+    match raw.map(str::trim) {
+        Some(value) if !value.is_empty() => value
+            .parse::<T>()
+            .map(Some)
+            .map_err(|_| AppError::BadRequest(format!("Invalid {}", name))),
+        _ => Ok(None),
+    }
+}
+
+/// Parse an optional money filter such as `min_amount`
+pub fn parse_amount(name: &str, raw: Option<&str>) -> Result<Option<i64>, AppError> {
+    parse_number::<i64>(name, raw)
+}
+
+/// Parse a UTC offset such as `+07:00` (defaults to UTC).
+/// A leading `+` decoded from the query string as a space is restored.
+pub fn parse_timezone(raw: Option<&str>) -> Result<FixedOffset, AppError> {
+    let value = raw.map(str::trim).unwrap_or_default();
+
+    match value {
+        "" | "Z" | "UTC" | "utc" => Ok(FixedOffset::east_opt(0).expect("valid offset")),
+        _ => {
+            let value = if value.starts_with(|c: char| c.is_ascii_digit()) {
+                format!("+{}", value)
+            } else {
+                value.to_string()
+            };
+
+            FixedOffset::from_str(&value)
+                .map_err(|_| AppError::BadRequest("Invalid timezone, expected an offset like +07:00".to_string()))
+        }
+    }
+}
+
+/// Inclusive lower bound: an RFC 3339 timestamp, or the start of a `YYYY-MM-DD` day in `timezone`
+pub fn parse_date_from(name: &str, raw: &str, timezone: FixedOffset) -> Result<DateTime<Utc>, AppError> {
+    if let Ok(datetime) = DateTime::parse_from_rfc3339(raw.trim()) {
+        return Ok(datetime.with_timezone(&Utc));
+    }
+
+    start_of_day(name, raw, timezone, 0)
+}
+
+/// Exclusive upper bound: just after an RFC 3339 timestamp, or the start of the day
+/// following a `YYYY-MM-DD` day in `timezone`, so `created_to=2024-01-31` covers that whole day
+pub fn parse_date_to(name: &str, raw: &str, timezone: FixedOffset) -> Result<DateTime<Utc>, AppError> {
+    if let Ok(datetime) = DateTime::parse_from_rfc3339(raw.trim()) {
+        // timestamptz has microsecond precision
+        return Ok(datetime.with_timezone(&Utc) + Duration::microseconds(1));
+    }
+
+    start_of_day(name, raw, timezone, 1)
+}
+
+fn start_of_day(name: &str, raw: &str, timezone: FixedOffset, add_days: i64) -> Result<DateTime<Utc>, AppError> {
+    let invalid = || AppError::BadRequest(format!("Invalid {}, expected YYYY-MM-DD or RFC 3339", name));
+
+    let date = NaiveDate::parse_from_str(raw.trim(), "%Y-%m-%d").map_err(|_| invalid())?;
+    let start = (date + Duration::days(add_days))
+        .and_hms_opt(0, 0, 0)
+        .ok_or_else(invalid)?;
+
+    timezone
+        .from_local_datetime(&start)
+        .single()
+        .map(|datetime| datetime.with_timezone(&Utc))
+        .ok_or_else(invalid)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wib() -> FixedOffset {
+        FixedOffset::east_opt(7 * 3600).unwrap()
+    }
+
+    #[test]
+    fn restores_a_plus_decoded_as_a_space() {
+        assert_eq!(parse_timezone(Some(" 07:00")).unwrap(), wib());
+        assert_eq!(parse_timezone(Some("+07:00")).unwrap(), wib());
+        assert_eq!(parse_timezone(Some("-03:30")).unwrap(), FixedOffset::west_opt(3 * 3600 + 1800).unwrap());
+        assert_eq!(parse_timezone(None).unwrap(), FixedOffset::east_opt(0).unwrap());
+        assert!(parse_timezone(Some("Asia/Jakarta")).is_err());
+    }
+
+    #[test]
+    fn bounds_whole_days_in_the_timezone() {
+        let from = parse_date_from("created_from", "2024-01-31", wib()).unwrap();
+        let to = parse_date_to("created_to", "2024-01-31", wib()).unwrap();
+
+        assert_eq!(from, Utc.with_ymd_and_hms(2024, 1, 30, 17, 0, 0).unwrap());
+        // Exclusive: the start of the next local day
+        assert_eq!(to, Utc.with_ymd_and_hms(2024, 1, 31, 17, 0, 0).unwrap());
+    }
+
+    #[test]
+    fn keeps_the_given_timestamp_inside_the_upper_bound() {
+        let to = parse_date_to("created_to", "2024-01-31T10:00:00Z", wib()).unwrap();
+        assert_eq!(to, Utc.with_ymd_and_hms(2024, 1, 31, 10, 0, 0).unwrap() + Duration::microseconds(1));
+
+        let from = parse_date_from("created_from", "2024-01-31T10:00:00+07:00", wib()).unwrap();
+        assert_eq!(from, Utc.with_ymd_and_hms(2024, 1, 31, 3, 0, 0).unwrap());
+    }
+
+    #[test]
+    fn rejects_malformed_dates() {
+        assert!(parse_date_to("created_to", "31-01-2024", wib()).is_err());
+        assert!(parse_date_from("created_from", "", wib()).is_err());
+    }
+
+    #[test]
+    fn parses_lists_strictly() {
+        assert_eq!(parse_list::<i32>("product_id", Some("1, 2,,3")).unwrap(), vec![1, 2, 3]);
+        assert!(parse_list::<i32>("product_id", Some("1,x")).is_err());
+        assert!(parse_list::<i32>("product_id", None).unwrap().is_empty());
+    }
+}
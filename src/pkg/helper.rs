@@ -1,3 +1,15 @@
+use serde::{Deserialize, Deserializer};
+
+/// Use with `#[serde(default, deserialize_with = "deserialize_some")]` on an `Option<Option<T>>`
+/// field to tell an explicit `null` (`Some(None)`) apart from a missing field (`None`)
+pub fn deserialize_some<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    T: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    Deserialize::deserialize(deserializer).map(Some)
+}
+
 // use std::collections::HashMap;
 // use validator::ValidationErrors;
 
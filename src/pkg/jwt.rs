@@ -1,4 +1,4 @@
-use jsonwebtoken::{encode, Header, EncodingKey, errors::Error};
+use jsonwebtoken::{decode, encode, Header, EncodingKey, DecodingKey, TokenData, Validation, errors::Error};
 use serde::{Serialize, Deserialize};
 use chrono::{Utc, Duration};
 use crate::pkg::config::Config;
@@ -6,10 +6,11 @@ use crate::pkg::config::Config;
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Claims {
     pub sub: String,
+    pub role: String,
     pub exp: usize,
 }
 
-pub fn generate_token(user_id: &str, config: &Config) -> Result<String, Error> {
+pub fn generate_token(user_id: &str, role: &str, config: &Config) -> Result<String, Error> {
     let expiration = Utc::now()
         .checked_add_signed(Duration::hours(config.jwt.expiration))
         .expect("valid timestamp")
@@ -17,12 +18,13 @@ pub fn generate_token(user_id: &str, config: &Config) -> Result<String, Error> {
 
     let claims = Claims {
         sub: user_id.to_owned(),
+        role: role.to_owned(),
         exp: expiration,
     };
 
     encode(&Header::default(), &claims, &EncodingKey::from_secret(config.jwt.secret.as_bytes()))
 }
 
-// pub fn verify_token(token: &str, config: &Config) -> Result<TokenData<Claims>, Error> {
-//     decode::<Claims>(token, &DecodingKey::from_secret(config.jwt.secret.as_bytes()), &Validation::default())
-// }
\ No newline at end of file
+pub fn verify_token(token: &str, config: &Config) -> Result<TokenData<Claims>, Error> {
+    decode::<Claims>(token, &DecodingKey::from_secret(config.jwt.secret.as_bytes()), &Validation::default())
+}
@@ -6,4 +6,14 @@ pub mod bcrypt;
 pub mod jwt;
 pub mod helper;
 pub mod pagination;
-pub mod health;
\ No newline at end of file
+pub mod health;
+pub mod filter;
+
+pub mod auth;
+pub mod audit;
+pub mod barcode;
+pub mod allocation;
+pub mod quantity;
+pub mod costing;
+pub mod qris;
+pub mod discount;
\ No newline at end of file
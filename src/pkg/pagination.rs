@@ -1,8 +1,359 @@
+use axum::http::{header, HeaderMap, HeaderValue, Uri};
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use chrono::{DateTime, Utc};
+use sea_orm::{
+    prelude::Decimal, sea_query::ColumnType, ColumnTrait, Condition, DatabaseConnection, DbErr, EntityTrait, Order,
+    PaginatorTrait, QuerySelect, Select, Value,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::pkg::custom_error::AppError;
+
 #[derive(Debug)]
 pub struct PaginatedResult<T> {
     pub data: Vec<T>,
-    pub total: u64,
-    pub page: u64,
+    pub total: Option<u64>,
+    pub page: Option<u64>,
     pub limit: u64,
-    pub total_pages: u64,
-}
\ No newline at end of file
+    pub total_pages: Option<u64>,
+    pub has_next: bool,
+    pub next_cursor: Option<String>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct PaginationMeta {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<u64>,
+    pub limit: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_pages: Option<u64>,
+    pub has_next: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
+}
+
+/// List response: items go to `data`, paging info goes to `meta`
+#[derive(Debug)]
+pub struct Paginated<T> {
+    pub data: Vec<T>,
+    pub meta: PaginationMeta,
+}
+
+impl<M, T> From<PaginatedResult<M>> for Paginated<T>
+where
+    T: From<M>,
+{
+    fn from(paginated_result: PaginatedResult<M>) -> Self {
+        Self {
+            data: paginated_result.data.into_iter().map(T::from).collect(),
+            meta: PaginationMeta {
+                total: paginated_result.total,
+                page: paginated_result.page,
+                limit: paginated_result.limit,
+                total_pages: paginated_result.total_pages,
+                has_next: paginated_result.has_next,
+                next_cursor: paginated_result.next_cursor,
+            },
+        }
+    }
+}
+
+impl<T> Paginated<T> {
+    /// Build RFC 8288 `Link` header relative to the request URI.
+    /// Offset pages get first/prev/next/last (last only when the total is known),
+    /// cursor pages get first/next.
+    pub fn headers(&self, uri: &Uri) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        let mut links = Vec::new();
+
+        match self.meta.page {
+            Some(page) => {
+                links.push((with_param(uri, "page", "1"), "first"));
+                if page > 1 {
+                    let prev = match self.meta.total_pages {
+                        Some(total_pages) => (page - 1).min(total_pages.max(1)),
+                        None => page - 1,
+                    };
+                    links.push((with_param(uri, "page", &prev.to_string()), "prev"));
+                }
+                if self.meta.has_next {
+                    links.push((with_param(uri, "page", &(page + 1).to_string()), "next"));
+                }
+                if let Some(total_pages) = self.meta.total_pages {
+                    links.push((with_param(uri, "page", &total_pages.max(1).to_string()), "last"));
+                }
+            }
+            None => {
+                links.push((with_param(uri, "cursor", ""), "first"));
+                if let Some(next_cursor) = &self.meta.next_cursor {
+                    links.push((with_param(uri, "cursor", next_cursor), "next"));
+                }
+            }
+        }
+
+        let value = links
+            .into_iter()
+            .map(|(link, rel)| format!("<{}>; rel=\"{}\"", link, rel))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        if let Ok(value) = HeaderValue::from_str(&value) {
+            headers.insert(header::LINK, value);
+        }
+
+        headers
+    }
+}
+
+/// Same path and query as `uri`, with `name` set to `value`
+fn with_param(uri: &Uri, name: &str, value: &str) -> String {
+    let prefix = format!("{}=", name);
+    let mut params: Vec<String> = uri
+        .query()
+        .unwrap_or_default()
+        .split('&')
+        .filter(|pair| !pair.is_empty() && *pair != name && !pair.starts_with(&prefix))
+        .map(|pair| pair.to_string())
+        .collect();
+    params.push(format!("{}{}", prefix, value));
+
+    format!("{}?{}", uri.path(), params.join("&"))
+}
+
+/// Opaque keyset cursor: the sort key, its value on the last row of a page and that row's id
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Cursor {
+    pub key: String,
+    pub value: Option<String>,
+    pub id: i32,
+}
+
+impl Cursor {
+    pub fn new(key: &str, value: Value, id: i32) -> Self {
+        let value = match value {
+            Value::Int(v) => v.map(|v| v.to_string()),
+            Value::Decimal(v) => v.map(|v| v.to_string()),
+            Value::String(v) => v.map(|v| *v),
+            Value::ChronoDateTimeUtc(v) => v.map(|v| v.to_rfc3339()),
+            _ => None,
+        };
+
+        Self { key: key.to_string(), value, id }
+    }
+
+    pub fn encode(&self) -> String {
+        URL_SAFE_NO_PAD.encode(serde_json::to_vec(self).unwrap_or_default())
+    }
+
+    pub fn decode(raw: &str) -> Result<Self, AppError> {
+        URL_SAFE_NO_PAD
+            .decode(raw)
+            .ok()
+            .and_then(|bytes| serde_json::from_slice(&bytes).ok())
+            .ok_or_else(|| AppError::BadRequest("Invalid cursor".to_string()))
+    }
+
+    /// Rows strictly after this cursor for `ORDER BY column, id_column` in `direction`.
+    /// Postgres sorts NULLs last ascending and first descending, which the NULL branches follow.
+    pub fn condition<C: ColumnTrait>(&self, column: C, id_column: C, direction: Order) -> Result<Condition, AppError> {
+        let value = match &self.value {
+            Some(raw) => Some(parse_value(column.def().get_column_type(), raw)?),
+            None => None,
+        };
+
+        let condition = match (direction, value) {
+            (Order::Desc, Some(value)) => Condition::any()
+                .add(column.lt(value.clone()))
+                .add(Condition::all().add(column.eq(value)).add(id_column.lt(self.id))),
+            (Order::Desc, None) => Condition::any()
+                .add(column.is_not_null())
+                .add(Condition::all().add(column.is_null()).add(id_column.lt(self.id))),
+            (_, Some(value)) => Condition::any()
+                .add(column.gt(value.clone()))
+                .add(Condition::all().add(column.eq(value)).add(id_column.gt(self.id)))
+                .add(column.is_null()),
+            (_, None) => Condition::all()
+                .add(column.is_null())
+                .add(id_column.gt(self.id)),
+        };
+
+        Ok(condition)
+    }
+}
+
+fn parse_value(column_type: &ColumnType, raw: &str) -> Result<Value, AppError> {
+    let invalid = || AppError::BadRequest("Invalid cursor".to_string());
+
+    match column_type {
+        ColumnType::Integer => raw.parse::<i32>().map(Value::from).map_err(|_| invalid()),
+        ColumnType::Decimal(_) => raw.parse::<Decimal>().map(Value::from).map_err(|_| invalid()),
+        ColumnType::TimestampWithTimeZone => DateTime::parse_from_rfc3339(raw)
+            .map(|dt| Value::from(dt.with_timezone(&Utc)))
+            .map_err(|_| invalid()),
+        ColumnType::String(_) | ColumnType::Text => Ok(Value::from(raw.to_string())),
+        _ => Err(invalid()),
+    }
+}
+
+/// Paging parameters shared by every list query
+#[derive(Debug, Default)]
+pub struct PageQuery {
+    pub limit: Option<String>,
+    pub page: Option<String>,
+    pub search: Option<String>,
+    pub order_by: Option<String>,
+    pub direction: Option<String>,
+    pub cursor: Option<String>,
+    pub include_total: Option<String>,
+}
+
+impl PageQuery {
+    /// Requested page (1-based) and page size, capped at 100
+    pub fn page_and_limit(&self) -> (u64, u64) {
+        let page = self.page
+            .as_deref()
+            .and_then(|p| p.parse::<u64>().ok())
+            .unwrap_or(1)
+            .max(1);
+
+        let limit = self.limit
+            .as_deref()
+            .and_then(|l| l.parse::<u64>().ok())
+            .unwrap_or(10)
+            .min(100);
+
+        (page, limit)
+    }
+
+    /// False when the caller passed `include_total=false`
+    pub fn include_total(&self) -> bool {
+        !matches!(self.include_total.as_deref(), Some("false") | Some("0"))
+    }
+}
+
+/// Offset page of an already filtered and ordered `select`,
+/// for append-only tables (logs, ledgers) that have no soft delete
+pub async fn paginate_select<E>(
+    db: &DatabaseConnection,
+    select: Select<E>,
+    page_query: &PageQuery,
+) -> Result<PaginatedResult<E::Model>, DbErr>
+where
+    E: EntityTrait,
+    E::Model: Sync,
+{
+    let (page, limit) = page_query.page_and_limit();
+
+    let total = if page_query.include_total() {
+        Some(select.clone().count(db).await?)
+    } else {
+        None
+    };
+
+    // One extra row tells whether a next page exists
+    let mut data = select
+        .offset((page - 1) * limit)
+        .limit(limit + 1)
+        .all(db)
+        .await?;
+
+    let has_next = data.len() as u64 > limit;
+    data.truncate(limit as usize);
+
+    let total_pages = total.map(|total| (total as f64 / limit as f64).ceil() as u64);
+
+    Ok(PaginatedResult {
+        data,
+        total,
+        page: Some(page),
+        limit,
+        total_pages,
+        has_next,
+        next_cursor: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn page(page: Option<u64>, total_pages: Option<u64>, has_next: bool, next_cursor: Option<&str>) -> Paginated<()> {
+        Paginated {
+            data: Vec::new(),
+            meta: PaginationMeta {
+                total: None,
+                page,
+                limit: 10,
+                total_pages,
+                has_next,
+                next_cursor: next_cursor.map(str::to_string),
+            },
+        }
+    }
+
+    fn link(paginated: &Paginated<()>, uri: &str) -> String {
+        let headers = paginated.headers(&uri.parse().unwrap());
+        headers[header::LINK].to_str().unwrap().to_string()
+    }
+
+    #[test]
+    fn links_offset_pages_keeping_other_params() {
+        assert_eq!(
+            link(&page(Some(2), Some(3), true, None), "/transactions?page=2&limit=10"),
+            "</transactions?limit=10&page=1>; rel=\"first\", </transactions?limit=10&page=1>; rel=\"prev\", \
+             </transactions?limit=10&page=3>; rel=\"next\", </transactions?limit=10&page=3>; rel=\"last\"",
+        );
+    }
+
+    #[test]
+    fn points_prev_back_into_range_past_the_last_page() {
+        assert_eq!(
+            link(&page(Some(5), Some(3), false, None), "/cashflows?page=5"),
+            "</cashflows?page=1>; rel=\"first\", </cashflows?page=3>; rel=\"prev\", </cashflows?page=3>; rel=\"last\"",
+        );
+    }
+
+    #[test]
+    fn omits_last_without_a_total() {
+        assert_eq!(
+            link(&page(Some(1), None, true, None), "/transactions"),
+            "</transactions?page=1>; rel=\"first\", </transactions?page=2>; rel=\"next\"",
+        );
+    }
+
+    #[test]
+    fn links_cursor_pages_from_the_start() {
+        assert_eq!(
+            link(&page(None, None, true, Some("abc")), "/transactions?cursor=xyz&limit=10"),
+            "</transactions?limit=10&cursor=>; rel=\"first\", </transactions?limit=10&cursor=abc>; rel=\"next\"",
+        );
+    }
+
+    #[test]
+    fn round_trips_cursors() {
+        let created_at = Utc.with_ymd_and_hms(2024, 1, 31, 17, 0, 0).unwrap();
+        let encoded = Cursor::new("created_at", Value::from(created_at), 42).encode();
+        assert!(!encoded.contains(['+', '/', '=']));
+
+        let cursor = Cursor::decode(&encoded).unwrap();
+        assert_eq!(cursor.key, "created_at");
+        assert_eq!(cursor.value.as_deref(), Some("2024-01-31T17:00:00+00:00"));
+        assert_eq!(cursor.id, 42);
+    }
+
+    #[test]
+    fn keeps_null_sort_values() {
+        let cursor = Cursor::decode(&Cursor::new("amount", Value::Decimal(None), 7).encode()).unwrap();
+        assert_eq!(cursor.value, None);
+        assert_eq!(cursor.id, 7);
+    }
+
+    #[test]
+    fn rejects_malformed_cursors() {
+        assert!(Cursor::decode("not a cursor").is_err());
+        assert!(Cursor::decode(&URL_SAFE_NO_PAD.encode(b"{\"key\":\"id\"}")).is_err());
+    }
+}
@@ -0,0 +1,155 @@
+use std::io::Cursor;
+
+use image::{ImageFormat, Luma};
+use qrcode::{render::svg, QrCode};
+use tracing::log::error;
+
+use crate::pkg::config::QrisConfig;
+use crate::pkg::custom_error::AppError;
+
+pub const FORMAT_PNG: &str = "png";
+pub const FORMAT_SVG: &str = "svg";
+
+const QR_SIZE: u32 = 320;
+
+/// Dynamic QRIS (EMVCo merchant-presented) payload for `amount` rupiah, labelled with `bill_number`
+pub fn payload(merchant: &QrisConfig, amount: i64, bill_number: &str) -> String {
+    let acquirer = [
+        tlv("00", &merchant.acquirer_domain),
+        tlv("01", &merchant.merchant_pan),
+        tlv("02", &merchant.merchant_id),
+        tlv("03", &merchant.merchant_criteria),
+    ]
+    .concat();
+    let national = [
+        tlv("00", "ID.CO.QRIS.WWW"),
+        tlv("02", &merchant.nmid),
+        tlv("03", &merchant.merchant_criteria),
+    ]
+    .concat();
+
+    let mut payload = [
+        tlv("00", "01"),
+        // 12 marks a dynamic code, good for one amount
+        tlv("01", "12"),
+        tlv("26", &acquirer),
+        tlv("51", &national),
+        tlv("52", &merchant.merchant_category_code),
+        tlv("53", "360"),
+        tlv("54", &amount.to_string()),
+        tlv("58", "ID"),
+        tlv("59", &truncate(&merchant.merchant_name, 25)),
+        tlv("60", &truncate(&merchant.merchant_city, 15)),
+    ]
+    .concat();
+    if let Some(postal_code) = &merchant.postal_code {
+        payload.push_str(&tlv("61", postal_code));
+    }
+    payload.push_str(&tlv("62", &tlv("01", &truncate(bill_number, 25))));
+
+    // The checksum covers everything up to and including its own tag and length
+    payload.push_str("6304");
+    let checksum = crc16(payload.as_bytes());
+    format!("{}{:04X}", payload, checksum)
+}
+
+/// The payload as a QR image, returning its content type and bytes
+pub fn render(payload: &str, format: &str) -> Result<(&'static str, Vec<u8>), AppError> {
+    let code = QrCode::new(payload.as_bytes()).map_err(|err| {
+        error!("QR encoding error: {}", err);
+        AppError::InternalError
+    })?;
+
+    match format {
+        FORMAT_PNG => {
+            let image = code.render::<Luma<u8>>().min_dimensions(QR_SIZE, QR_SIZE).build();
+            let mut bytes = Vec::new();
+            image.write_to(&mut Cursor::new(&mut bytes), ImageFormat::Png).map_err(|err| {
+                error!("PNG encoding error: {}", err);
+                AppError::InternalError
+            })?;
+            Ok(("image/png", bytes))
+        }
+        FORMAT_SVG => {
+            let image = code.render::<svg::Color>().min_dimensions(QR_SIZE, QR_SIZE).build();
+            Ok(("image/svg+xml", image.into_bytes()))
+        }
+        _ => Err(AppError::BadRequest(format!("Format must be one of: {}, {}", FORMAT_PNG, FORMAT_SVG))),
+    }
+}
+
+/// Tag, two-digit length and value
+fn tlv(tag: &str, value: &str) -> String {
+    format!("{}{:02}{}", tag, value.len(), value)
+}
+
+fn truncate(value: &str, max_len: usize) -> String {
+    value.chars().filter(|c| c.is_ascii()).take(max_len).collect()
+}
+
+/// CRC-16/CCITT-FALSE as required by EMVCo: polynomial 0x1021, initial value 0xFFFF
+fn crc16(data: &[u8]) -> u16 {
+    let mut crc: u16 = 0xFFFF;
+    for byte in data {
+        crc ^= u16::from(*byte) << 8;
+        for _ in 0..8 {
+            crc = if crc & 0x8000 != 0 { (crc << 1) ^ 0x1021 } else { crc << 1 };
+        }
+    }
+    crc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn merchant() -> QrisConfig {
+        QrisConfig {
+            acquirer_domain: "ID.CO.BANKMANDIRI.WWW".to_string(),
+            merchant_pan: "936000080000012345".to_string(),
+            merchant_id: "000012345".to_string(),
+            nmid: "ID1023123456789".to_string(),
+            merchant_criteria: "UMI".to_string(),
+            merchant_category_code: "5812".to_string(),
+            merchant_name: "WARUNG MAKAN SEDERHANA".to_string(),
+            merchant_city: "JAKARTA".to_string(),
+            postal_code: Some("10110".to_string()),
+        }
+    }
+
+    #[test]
+    fn computes_crc16_ccitt_false() {
+        assert_eq!(crc16(b"123456789"), 0x29B1);
+        assert_eq!(crc16(b""), 0xFFFF);
+    }
+
+    #[test]
+    fn prefixes_values_with_two_digit_lengths() {
+        assert_eq!(tlv("53", "360"), "5303360");
+        assert_eq!(tlv("62", &tlv("01", "TRX42")), "62090105TRX42");
+    }
+
+    #[test]
+    fn builds_a_dynamic_payload() {
+        assert_eq!(
+            payload(&merchant(), 25000, "TRX42"),
+            "00020101021226670021ID.CO.BANKMANDIRI.WWW011893600008000001234502090000123450303UMI\
+             51440014ID.CO.QRIS.WWW0215ID10231234567890303UMI5204581253033605405250005802ID\
+             5922WARUNG MAKAN SEDERHANA6007JAKARTA61051011062090105TRX426304DEA4"
+        );
+    }
+
+    #[test]
+    fn ends_with_a_checksum_over_the_rest() {
+        let mut merchant = merchant();
+        merchant.postal_code = None;
+        merchant.merchant_name = "TOKO SERBA ADA SEJAHTERA SELALU".to_string();
+
+        let payload = payload(&merchant, 1500, "TRX7");
+        let (body, checksum) = payload.split_at(payload.len() - 4);
+        assert!(body.ends_with("6304"));
+        assert_eq!(checksum, format!("{:04X}", crc16(body.as_bytes())));
+        assert!(!body.contains("6105"));
+        assert!(body.contains("5925TOKO SERBA ADA SEJAHTERA 6007JAKARTA"));
+    }
+}
@@ -0,0 +1,65 @@
+use rust_decimal::{prelude::ToPrimitive, Decimal, RoundingStrategy};
+use validator::ValidationError;
+
+/// Decimal places kept for quantities and stock, matching the NUMERIC(14,3) columns
+pub const QTY_SCALE: u32 = 3;
+
+/// Decimal places kept for unit conversion factors, matching NUMERIC(14,4)
+pub const FACTOR_SCALE: u32 = 4;
+
+/// `amount` per unit times `qty`, rounded half away from zero to a whole amount
+pub fn line_amount(amount: i64, qty: Decimal) -> i64 {
+    (Decimal::from(amount) * qty)
+        .round_dp_with_strategy(0, RoundingStrategy::MidpointAwayFromZero)
+        .to_i64()
+        .unwrap_or_default()
+}
+
+/// Validator for quantities that must be above zero and fit the stored precision
+pub fn validate_positive_qty(qty: &Decimal) -> Result<(), ValidationError> {
+    if qty.is_sign_positive() && !qty.is_zero() && qty.scale() <= QTY_SCALE {
+        Ok(())
+    } else {
+        let mut error = ValidationError::new("qty");
+        error.message = Some(format!("Quantity must be positive with at most {} decimal places", QTY_SCALE).into());
+        Err(error)
+    }
+}
+
+/// Validator for quantities that may be zero, such as counted stock
+pub fn validate_non_negative_qty(qty: &Decimal) -> Result<(), ValidationError> {
+    if !qty.is_sign_negative() && qty.scale() <= QTY_SCALE {
+        Ok(())
+    } else {
+        let mut error = ValidationError::new("qty");
+        error.message = Some(format!("Quantity must not be negative and have at most {} decimal places", QTY_SCALE).into());
+        Err(error)
+    }
+}
+
+/// Validator for signed quantities such as stock adjustments
+pub fn validate_nonzero_qty(qty: &Decimal) -> Result<(), ValidationError> {
+    if !qty.is_zero() && qty.scale() <= QTY_SCALE {
+        Ok(())
+    } else {
+        let mut error = ValidationError::new("qty");
+        error.message = Some(format!("Quantity must not be zero and have at most {} decimal places", QTY_SCALE).into());
+        Err(error)
+    }
+}
+
+/// Validator for unit conversion factors
+pub fn validate_factor(factor: &Decimal) -> Result<(), ValidationError> {
+    if factor.is_sign_positive() && !factor.is_zero() && factor.scale() <= FACTOR_SCALE {
+        Ok(())
+    } else {
+        let mut error = ValidationError::new("factor");
+        error.message = Some(format!("Factor must be positive with at most {} decimal places", FACTOR_SCALE).into());
+        Err(error)
+    }
+}
+
+/// `qty` of a unit holding `factor` base units, in base units
+pub fn to_base(qty: Decimal, factor: Decimal) -> Decimal {
+    (qty * factor).round_dp(QTY_SCALE).normalize()
+}